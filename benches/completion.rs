@@ -226,6 +226,40 @@ fn generate_large_file(line_count: usize) -> String {
     src
 }
 
+/// Generate a realistic large PHP file: `classes` classes with
+/// `methods_per_class` methods each, every method padded with
+/// `body_lines` statements.  The file ends with a `->` completion
+/// trigger on the last class, whose position is returned alongside the
+/// source as `(line, character)`.
+fn generate_synthetic_project_file(
+    classes: usize,
+    methods_per_class: usize,
+    body_lines: usize,
+) -> (String, u32, u32) {
+    let mut src = String::from("<?php\n");
+    for i in 0..classes {
+        let extends = if i > 0 {
+            format!(" extends Synthetic{}", i - 1)
+        } else {
+            String::new()
+        };
+        src.push_str(&format!("class Synthetic{i}{extends} {{\n"));
+        for m in 0..methods_per_class {
+            src.push_str(&format!("    public function op{i}_{m}(int $n): int {{\n"));
+            for l in 0..body_lines.saturating_sub(3) {
+                src.push_str(&format!("        $v{l} = $n * {l} + strlen('x{i}_{m}');\n"));
+            }
+            src.push_str("        return $n;\n    }\n");
+        }
+        src.push_str("}\n");
+    }
+    let last = classes.saturating_sub(1);
+    src.push_str(&format!("$obj = new Synthetic{last}();\n"));
+    let line = src.lines().count() as u32;
+    src.push_str("$obj->\n");
+    (src, line, 6)
+}
+
 /// Generate a PHP class body with `count` local variables, ending with a
 /// bare `$` to trigger variable-name completion.
 fn generate_many_variables(count: usize) -> String {
@@ -574,6 +608,60 @@ fn bench_completion_short_file(c: &mut Criterion) {
     });
 }
 
+/// Synthetic 10,000-line file: 50 classes in a single inheritance
+/// chain, 500 methods in total.  Unlike the warm-start benchmarks above,
+/// each iteration includes the document sync, so these measure the
+/// latency a user sees from opening (or editing) a large file to the
+/// first completion list.
+fn bench_completion_synthetic_large_file(c: &mut Criterion) {
+    let runtime = rt();
+    let (source, line, character) = generate_synthetic_project_file(50, 10, 20);
+    let mut group = c.benchmark_group("completion_10k_lines");
+
+    {
+        let backend = Backend::new_test();
+        group.bench_function("after_open", |b| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    let uri = open_file(&backend, "file:///bench_10k_open.php", &source).await;
+                    fire_completion(&backend, &uri, line, character).await;
+                })
+            })
+        });
+    }
+
+    {
+        let backend = Backend::new_test();
+        let uri = runtime.block_on(open_file(&backend, "file:///bench_10k_change.php", &source));
+        // Insert a statement above the trigger line so every change
+        // shifts the cursor's byte offset.
+        let edited = source.replacen("$obj = new", "$unused = 1;\n$obj = new", 1);
+        let mut version = 2;
+        group.bench_function("after_change", |b| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    let change_params = DidChangeTextDocumentParams {
+                        text_document: VersionedTextDocumentIdentifier {
+                            uri: uri.clone(),
+                            version,
+                        },
+                        content_changes: vec![TextDocumentContentChangeEvent {
+                            range: None,
+                            range_length: None,
+                            text: edited.clone(),
+                        }],
+                    };
+                    backend.did_change(change_params).await;
+                    fire_completion(&backend, &uri, line + 1, character).await;
+                });
+                version += 1;
+            })
+        });
+    }
+
+    group.finish();
+}
+
 // ─── Variable completion benchmarks ────────────────────────────────────────
 //
 // These trigger completion on a bare `$` (variable-name completion) rather
@@ -791,6 +879,7 @@ criterion_group!(
     bench_completion_yii_hierarchy,
     bench_completion_large_file,
     bench_completion_short_file,
+    bench_completion_synthetic_large_file,
     bench_variable_completion,
     bench_hover,
     bench_definition,
@@ -0,0 +1,207 @@
+//! Workspace indexing benchmarks.
+//!
+//! Run with: `cargo bench --bench indexing`
+//!
+//! A synthetic Composer project with 500 PSR-4 classes (~50,000 lines)
+//! is written to a temporary directory once, outside the measurement
+//! loop.  Each iteration starts from a fresh backend so the numbers
+//! cover the full cold path a user waits for after opening a project:
+//!
+//! - `workspace_scan`: `initialize` + `initialized`, which reads
+//!   `composer.json`, builds the PSR-4 mappings and scans the project
+//!   for class names.
+//! - `workspace_scan_and_parse`: the same, followed by a full parse of
+//!   every file into `ast_map` (what find-references and workspace
+//!   diagnostics trigger on first use).
+//! - `references_parallel` / `references_sequential`: the same scan,
+//!   followed by a find-references request that parses every file
+//!   through the worker threads, with `[indexing] parallel` switched on
+//!   and off.  The ratio between the two is the speedup from parsing
+//!   on multiple cores.
+//!
+//! The target is well under one second for `workspace_scan_and_parse`
+//! on a current desktop CPU.
+
+use std::path::{Path, PathBuf};
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use phpantom_lsp::Backend;
+use phpantom_lsp::config::Config;
+use tower_lsp::LanguageServer;
+use tower_lsp::lsp_types::*;
+
+const FILE_COUNT: usize = 500;
+const FILES_PER_NAMESPACE: usize = 50;
+const METHODS_PER_CLASS: usize = 7;
+
+// ─── Helpers ────────────────────────────────────────────────────────────────
+
+/// Build a tokio runtime for async benchmarks.
+fn rt() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+/// Generate one ~100-line PSR-4 class that type-hints a neighbour in
+/// another namespace, so indexing sees `use` imports and cross-file
+/// references rather than isolated classes.
+fn generate_class(index: usize) -> String {
+    let namespace = index / FILES_PER_NAMESPACE;
+    let neighbour = (index + FILES_PER_NAMESPACE) % FILE_COUNT;
+    let neighbour_namespace = neighbour / FILES_PER_NAMESPACE;
+
+    let mut src = format!(
+        "<?php\n\nnamespace App\\Module{namespace};\n\n\
+         use App\\Module{neighbour_namespace}\\Service{neighbour};\n\n\
+         class Service{index}\n{{\n    private ?Service{neighbour} $next = null;\n"
+    );
+    for m in 0..METHODS_PER_CLASS {
+        src.push_str("\n    /**\n     * @param list<int> $values\n     */\n");
+        src.push_str(&format!(
+            "    public function step{m}(array $values, Service{neighbour} $other): int\n    {{\n"
+        ));
+        src.push_str("        $total = 0;\n");
+        src.push_str("        foreach ($values as $value) {\n");
+        src.push_str(&format!("            $total += $value * {m};\n"));
+        src.push_str("        }\n");
+        src.push_str("        $this->next = $other;\n");
+        src.push_str("        return $total;\n    }\n");
+    }
+    src.push_str("}\n");
+    src
+}
+
+/// Write the synthetic project and return its root along with the
+/// paths of every generated class file.
+fn create_project() -> (tempfile::TempDir, Vec<PathBuf>) {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("composer.json"),
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+    )
+    .unwrap();
+
+    let mut files = Vec::with_capacity(FILE_COUNT);
+    for i in 0..FILE_COUNT {
+        let module_dir = dir
+            .path()
+            .join("src")
+            .join(format!("Module{}", i / FILES_PER_NAMESPACE));
+        std::fs::create_dir_all(&module_dir).unwrap();
+        let path = module_dir.join(format!("Service{i}.php"));
+        std::fs::write(&path, generate_class(i)).unwrap();
+        files.push(path);
+    }
+    (dir, files)
+}
+
+/// Run the LSP handshake against `root`, which indexes the workspace.
+async fn index_workspace(root: &Path) -> Backend {
+    let backend = Backend::new_headless();
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(vec![WorkspaceFolder {
+                uri: Url::from_directory_path(root).unwrap(),
+                name: "bench".to_string(),
+            }]),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+    backend.initialized(InitializedParams {}).await;
+    backend
+}
+
+/// Parse every project file into the backend's `ast_map`.
+fn parse_all(backend: &Backend, files: &[PathBuf]) {
+    for path in files {
+        let content = std::fs::read_to_string(path).unwrap();
+        let uri = Url::from_file_path(path).unwrap();
+        backend.update_ast(uri.as_str(), &content);
+    }
+}
+
+/// Find references to the first generated class, which makes the
+/// backend parse every workspace file on `parallel` worker threads or
+/// one at a time.
+async fn find_references(backend: &Backend, files: &[PathBuf], parallel: bool) {
+    let mut config = Config::default();
+    config.indexing.parallel = Some(parallel);
+    backend.set_config(config);
+
+    let uri = Url::from_file_path(&files[0]).unwrap();
+    let references = backend
+        .references(ReferenceParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                // `class Service0` on line 6.
+                position: Position::new(6, 8),
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: ReferenceContext {
+                include_declaration: false,
+            },
+        })
+        .await
+        .unwrap();
+    black_box(references);
+}
+
+// ─── Benchmarks ─────────────────────────────────────────────────────────────
+
+fn bench_index_500_files(c: &mut Criterion) {
+    let runtime = rt();
+    let (dir, files) = create_project();
+    let root = dir.path();
+
+    // Sanity check outside the timed loop: composer.json must be picked up.
+    runtime.block_on(async {
+        let backend = index_workspace(root).await;
+        assert!(
+            backend
+                .psr4_mappings()
+                .read()
+                .iter()
+                .any(|m| m.prefix == "App\\"),
+            "synthetic project was not indexed"
+        );
+    });
+
+    let mut group = c.benchmark_group("index_500_files");
+    group.sample_size(10);
+
+    group.bench_function("workspace_scan", |b| {
+        b.iter(|| black_box(runtime.block_on(index_workspace(root))))
+    });
+
+    group.bench_function("workspace_scan_and_parse", |b| {
+        b.iter(|| {
+            let backend = runtime.block_on(index_workspace(root));
+            parse_all(&backend, &files);
+            black_box(backend)
+        })
+    });
+
+    for (name, parallel) in [
+        ("references_parallel", true),
+        ("references_sequential", false),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    let backend = index_workspace(root).await;
+                    find_references(&backend, &files, parallel).await;
+                    black_box(backend)
+                })
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_index_500_files);
+criterion_main!(benches);
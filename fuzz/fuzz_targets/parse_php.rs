@@ -0,0 +1,29 @@
+//! Fuzz target for [`Backend::parse_php`].
+//!
+//! The parser runs mago inside `catch_unwind`, so a panic in the parser
+//! itself is absorbed and reported as an empty result.  This target
+//! checks that nothing escapes that boundary: any panic that reaches
+//! the fuzzer is a crash.
+
+#![no_main]
+
+use std::sync::LazyLock;
+
+use libfuzzer_sys::fuzz_target;
+use phpantom_lsp::Backend;
+
+static BACKEND: LazyLock<Backend> = LazyLock::new(Backend::new_test);
+
+fuzz_target!(
+    init: {
+        // libfuzzer-sys installs a hook that aborts on *any* panic,
+        // including the ones `catch_unwind` recovers from.  Restore the
+        // default hook so only escaping panics count as crashes.
+        let _ = std::panic::take_hook();
+        LazyLock::force(&BACKEND);
+    },
+    |data: &[u8]| {
+        let input = String::from_utf8_lossy(data);
+        let _ = BACKEND.parse_php(&input);
+    }
+);
@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+# Seed the parse_php fuzz corpus with the PHP snippets used by the test
+# suite: the source half of every `.fixture` file plus the PHPStan and
+# Psalm assertion files.
+set -euo pipefail
+
+root="$(cd "$(dirname "$0")/.." && pwd)"
+corpus="$root/fuzz/corpus/parse_php"
+mkdir -p "$corpus"
+
+while IFS= read -r -d '' fixture; do
+    name="$(basename "$(dirname "$fixture")")_$(basename "$fixture" .fixture).php"
+    # Everything after the `---` separator, with the `<>` cursor marker removed.
+    sed '1,/^---$/d; s/<>//g' "$fixture" >"$corpus/$name"
+done < <(find "$root/tests/fixtures" -name '*.fixture' -print0)
+
+while IFS= read -r -d '' php; do
+    cp "$php" "$corpus/$(basename "$(dirname "$php")")_$(basename "$php")"
+done < <(find "$root/tests/phpstan_nsrt" "$root/tests/psalm_assertions" "$root/examples" \
+    -name '*.php' -print0)
+
+echo "Seeded $(find "$corpus" -type f | wc -l) files into $corpus"
@@ -339,6 +339,7 @@ pub async fn run(options: AnalyseOptions) -> i32 {
                         let _callable_guard =
                             crate::completion::call_resolution::with_callable_target_cache();
                         let _body_infer_guard = backend.activate_body_return_inferrer();
+                        let _service_map_guard = backend.activate_symfony_service_map();
 
                         // ── Forward-walked diagnostic scope cache ───
                         // Walk every function/method body once with the
@@ -0,0 +1,463 @@
+//! Call hierarchy support (`textDocument/prepareCallHierarchy`,
+//! `callHierarchy/incomingCalls`, `callHierarchy/outgoingCalls`).
+//!
+//! Items are methods and standalone functions.  Incoming calls reuse
+//! the find-references machinery and group each reference by the
+//! callable whose body contains it.  Outgoing calls walk the symbol
+//! map spans inside the item's body and resolve every method or
+//! function call through go-to-definition, and every `new Foo()` to
+//! the constructor it runs.
+//!
+//! No extra state is carried in `CallHierarchyItem::data`: the item's
+//! URI and selection range point at the declaration's name token,
+//! which is enough to find the callable again.
+
+use std::collections::HashMap;
+
+use mago_syntax::ast::class_like::member::ClassLikeMember;
+use mago_syntax::ast::class_like::method::MethodBody;
+use mago_syntax::ast::*;
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::symbol_map::{ClassRefContext, SymbolKind as MapSymbolKind};
+use crate::util::{build_fqn, offset_to_position, position_to_offset};
+
+/// A method or function declared in a single file, together with the
+/// brace-delimited body it owns (if any).
+#[derive(Debug, Clone)]
+struct CallableDecl {
+    name: String,
+    kind: SymbolKind,
+    /// FQN of the declaring class for methods, or the namespace for
+    /// namespaced functions.
+    detail: Option<String>,
+    name_offset: u32,
+    /// Body braces `(start, end)` taken from the declaration's AST
+    /// node.  `None` for abstract and interface methods.
+    body: Option<(u32, u32)>,
+}
+
+impl Backend {
+    /// Prepare the call hierarchy for the symbol under the cursor.
+    ///
+    /// Works both on a method/function declaration name and on a call
+    /// site, in which case the call is resolved to its declaration.
+    pub(crate) fn prepare_call_hierarchy_impl(
+        &self,
+        uri: &str,
+        content: &str,
+        position: Position,
+    ) -> Option<Vec<CallHierarchyItem>> {
+        let span = self.lookup_symbol_at_position(uri, content, position)?;
+
+        let (decl_uri, decl_content, decl) = match &span.kind {
+            MapSymbolKind::MemberDeclaration { .. }
+            | MapSymbolKind::FunctionCall {
+                is_definition: true,
+                ..
+            } => {
+                let decl = self.callable_at(uri, content, span.start)?;
+                (uri.to_string(), content.to_string(), decl)
+            }
+            MapSymbolKind::MemberAccess {
+                is_method_call: true,
+                ..
+            }
+            | MapSymbolKind::FunctionCall {
+                is_definition: false,
+                ..
+            } => self.resolve_call_hierarchy_target(uri, content, position)?,
+            _ => return None,
+        };
+
+        Some(vec![build_call_hierarchy_item(
+            &decl_uri,
+            &decl_content,
+            &decl,
+        )?])
+    }
+
+    /// Return the callables that call the given item, each with the
+    /// ranges of the individual call sites.
+    ///
+    /// References made from top-level code (outside any method or
+    /// function body) have no caller item and are skipped.
+    pub(crate) fn incoming_calls_impl(
+        &self,
+        item: &CallHierarchyItem,
+    ) -> Option<Vec<CallHierarchyIncomingCall>> {
+        let uri = item.uri.to_string();
+        let content = self.get_file_content(&uri)?;
+        let locations = self.find_references(&uri, &content, item.selection_range.start, false)?;
+
+        // Content and callables are computed once per referencing file,
+        // not once per reference.
+        let mut files: HashMap<String, Option<(String, Vec<CallableDecl>)>> = HashMap::new();
+        let mut grouped: Vec<((String, u32), CallHierarchyIncomingCall)> = Vec::new();
+
+        for location in locations {
+            if self.is_request_cancelled() {
+                return None;
+            }
+            let loc_uri = location.uri.to_string();
+            let Some((loc_content, callables)) =
+                files.entry(loc_uri.clone()).or_insert_with(|| {
+                    let content = self.get_file_content(&loc_uri)?;
+                    let callables = self.file_callables(&loc_uri, &content);
+                    Some((content, callables))
+                })
+            else {
+                continue;
+            };
+            let offset = position_to_offset(loc_content, location.range.start);
+            let Some(caller) = enclosing_callable(callables, offset) else {
+                continue;
+            };
+
+            let key = (loc_uri.clone(), caller.name_offset);
+            if let Some((_, call)) = grouped.iter_mut().find(|(k, _)| *k == key) {
+                call.from_ranges.push(location.range);
+                continue;
+            }
+            let Some(from) = build_call_hierarchy_item(&loc_uri, loc_content, caller) else {
+                continue;
+            };
+            grouped.push((
+                key,
+                CallHierarchyIncomingCall {
+                    from,
+                    from_ranges: vec![location.range],
+                },
+            ));
+        }
+
+        Some(grouped.into_iter().map(|(_, call)| call).collect())
+    }
+
+    /// Return the callables invoked from the body of the given item,
+    /// each with the ranges of the individual call sites.
+    ///
+    /// `new Foo()` counts as a call to `Foo`'s constructor (declared on
+    /// the class itself or inherited from a parent).
+    pub(crate) fn outgoing_calls_impl(
+        &self,
+        item: &CallHierarchyItem,
+    ) -> Option<Vec<CallHierarchyOutgoingCall>> {
+        let uri = item.uri.to_string();
+        let content = self.get_file_content(&uri)?;
+        let offset = position_to_offset(&content, item.selection_range.start);
+        let decl = self.callable_at(&uri, &content, offset)?;
+        let (body_start, body_end) = decl.body?;
+
+        let call_spans: Vec<(u32, u32, MapSymbolKind)> = {
+            let maps = self.symbol_maps.read();
+            let map = maps.get(&uri)?;
+            map.spans
+                .iter()
+                .filter(|s| s.start >= body_start && s.end <= body_end)
+                .filter(|s| {
+                    matches!(
+                        s.kind,
+                        MapSymbolKind::MemberAccess {
+                            is_method_call: true,
+                            ..
+                        } | MapSymbolKind::FunctionCall {
+                            is_definition: false,
+                            ..
+                        } | MapSymbolKind::ClassReference {
+                            context: ClassRefContext::New,
+                            ..
+                        }
+                    )
+                })
+                .map(|s| (s.start, s.end, s.kind.clone()))
+                .collect()
+        };
+
+        let mut grouped: Vec<((String, u32), CallHierarchyOutgoingCall)> = Vec::new();
+
+        for (start, end, kind) in call_spans {
+            if self.is_request_cancelled() {
+                return None;
+            }
+            let position = offset_to_position(&content, start as usize);
+            let target = match kind {
+                MapSymbolKind::ClassReference { name, is_fqn, .. } => {
+                    self.resolve_constructor_target(&uri, &name, is_fqn, start)
+                }
+                _ => self.resolve_call_hierarchy_target(&uri, &content, position),
+            };
+            let Some((target_uri, target_content, target)) = target else {
+                continue;
+            };
+            let range = Range {
+                start: position,
+                end: offset_to_position(&content, end as usize),
+            };
+
+            let key = (target_uri.clone(), target.name_offset);
+            if let Some((_, call)) = grouped.iter_mut().find(|(k, _)| *k == key) {
+                call.from_ranges.push(range);
+                continue;
+            }
+            let Some(to) = build_call_hierarchy_item(&target_uri, &target_content, &target) else {
+                continue;
+            };
+            grouped.push((
+                key,
+                CallHierarchyOutgoingCall {
+                    to,
+                    from_ranges: vec![range],
+                },
+            ));
+        }
+
+        Some(grouped.into_iter().map(|(_, call)| call).collect())
+    }
+
+    /// Resolve the call at `position` to the callable it invokes.
+    ///
+    /// Returns the declaring file's URI and content alongside the
+    /// callable so the caller can build an item from it.
+    fn resolve_call_hierarchy_target(
+        &self,
+        uri: &str,
+        content: &str,
+        position: Position,
+    ) -> Option<(String, String, CallableDecl)> {
+        let location = self
+            .resolve_definition(uri, content, position)
+            .into_iter()
+            .next()?;
+        let decl_uri = location.uri.to_string();
+        let decl_content = if decl_uri == uri {
+            content.to_string()
+        } else {
+            self.get_file_content(&decl_uri)?
+        };
+        let offset = position_to_offset(&decl_content, location.range.start);
+        let decl = self.callable_at(&decl_uri, &decl_content, offset)?;
+        Some((decl_uri, decl_content, decl))
+    }
+
+    /// Resolve `new Name` to the constructor it runs: the class's own
+    /// `__construct`, or the nearest one up the parent chain.
+    ///
+    /// Returns `None` when no class in the chain declares a
+    /// constructor or the declaring file is not available.
+    fn resolve_constructor_target(
+        &self,
+        uri: &str,
+        name: &str,
+        is_fqn: bool,
+        offset: u32,
+    ) -> Option<(String, String, CallableDecl)> {
+        let mut fqn = if is_fqn {
+            name.trim_start_matches('\\').to_string()
+        } else {
+            self.file_context(uri).resolve_name_at(name, offset)
+        };
+
+        // Parent names are resolved to FQNs during post-processing.
+        // The depth cap guards against `extends` cycles.
+        for _ in 0..32 {
+            let class = self.find_or_load_class(&fqn)?;
+            if let Some(ctor) = class
+                .methods
+                .iter()
+                .find(|m| m.name.eq_ignore_ascii_case("__construct"))
+            {
+                let (decl_uri, decl_content) = self.find_class_file_content(&fqn, "", "")?;
+                let decl = self.callable_at(&decl_uri, &decl_content, ctor.name_offset)?;
+                return Some((decl_uri, decl_content, decl));
+            }
+            fqn = class.parent_class.as_ref()?.to_string();
+        }
+        None
+    }
+
+    /// Find the callable whose name token starts at `name_offset`.
+    fn callable_at(&self, uri: &str, content: &str, name_offset: u32) -> Option<CallableDecl> {
+        self.file_callables(uri, content)
+            .into_iter()
+            .find(|c| c.name_offset == name_offset)
+    }
+
+    /// Collect every method and standalone function declared in `uri`,
+    /// sorted by name offset, with body spans attached.
+    fn file_callables(&self, uri: &str, content: &str) -> Vec<CallableDecl> {
+        let mut decls = Vec::new();
+
+        if let Some(classes) = self.ast_map.read().get(uri) {
+            for class in classes {
+                let fqn = build_fqn(&class.name, class.file_namespace.as_deref());
+                for method in &class.methods {
+                    if method.name_offset == 0 {
+                        continue;
+                    }
+                    let kind = if method.name.eq_ignore_ascii_case("__construct") {
+                        SymbolKind::CONSTRUCTOR
+                    } else {
+                        SymbolKind::METHOD
+                    };
+                    decls.push(CallableDecl {
+                        name: method.name.to_string(),
+                        kind,
+                        detail: Some(fqn.clone()),
+                        name_offset: method.name_offset,
+                        body: None,
+                    });
+                }
+            }
+        }
+
+        for (func_uri, func) in self.global_functions.read().values() {
+            if func_uri != uri || func.name_offset == 0 {
+                continue;
+            }
+            if decls.iter().any(|d| d.name_offset == func.name_offset) {
+                continue;
+            }
+            decls.push(CallableDecl {
+                name: func.name.to_string(),
+                kind: SymbolKind::FUNCTION,
+                detail: func.namespace.clone(),
+                name_offset: func.name_offset,
+                body: None,
+            });
+        }
+
+        decls.sort_by_key(|d| d.name_offset);
+
+        let bodies = crate::parser::with_parsed_program(content, "call_hierarchy", |program, _| {
+            let mut bodies = HashMap::new();
+            collect_callable_bodies(program.statements.iter(), &mut bodies);
+            bodies
+        });
+        for decl in &mut decls {
+            decl.body = bodies.get(&decl.name_offset).copied();
+        }
+
+        decls
+    }
+}
+
+/// Find the innermost callable whose body contains `offset`.
+fn enclosing_callable(callables: &[CallableDecl], offset: u32) -> Option<&CallableDecl> {
+    callables
+        .iter()
+        .filter(|c| {
+            c.body
+                .is_some_and(|(start, end)| offset >= start && offset <= end)
+        })
+        .min_by_key(|c| c.body.map_or(u32::MAX, |(start, end)| end - start))
+}
+
+/// Record the brace span of every concrete method and function body,
+/// keyed by the offset of the declaration's name token.
+///
+/// Descends into namespaces, blocks, `if` branches and function or
+/// method bodies so that conditionally declared functions and nested
+/// declarations are found too.
+fn collect_callable_bodies<'a>(
+    statements: impl Iterator<Item = &'a Statement<'a>>,
+    bodies: &mut HashMap<u32, (u32, u32)>,
+) {
+    for stmt in statements {
+        match stmt {
+            Statement::Namespace(ns) => {
+                collect_callable_bodies(ns.statements().iter(), bodies);
+            }
+            Statement::Class(class) => collect_method_bodies(class.members.iter(), bodies),
+            Statement::Interface(iface) => collect_method_bodies(iface.members.iter(), bodies),
+            Statement::Trait(trait_def) => collect_method_bodies(trait_def.members.iter(), bodies),
+            Statement::Enum(enum_def) => collect_method_bodies(enum_def.members.iter(), bodies),
+            Statement::Function(func) => {
+                bodies.insert(
+                    func.name.span.start.offset,
+                    (
+                        func.body.left_brace.start.offset,
+                        func.body.right_brace.end.offset,
+                    ),
+                );
+                collect_callable_bodies(func.body.statements.iter(), bodies);
+            }
+            Statement::Block(block) => collect_callable_bodies(block.statements.iter(), bodies),
+            Statement::If(if_stmt) => match &if_stmt.body {
+                IfBody::Statement(body) => {
+                    collect_callable_bodies(std::iter::once(body.statement), bodies);
+                    for else_if in body.else_if_clauses.iter() {
+                        collect_callable_bodies(std::iter::once(else_if.statement), bodies);
+                    }
+                    if let Some(ref else_clause) = body.else_clause {
+                        collect_callable_bodies(std::iter::once(else_clause.statement), bodies);
+                    }
+                }
+                IfBody::ColonDelimited(body) => {
+                    collect_callable_bodies(body.statements.iter(), bodies);
+                    for else_if in body.else_if_clauses.iter() {
+                        collect_callable_bodies(else_if.statements.iter(), bodies);
+                    }
+                    if let Some(ref else_clause) = body.else_clause {
+                        collect_callable_bodies(else_clause.statements.iter(), bodies);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Record the bodies of the concrete methods among `members`.
+fn collect_method_bodies<'a>(
+    members: impl Iterator<Item = &'a ClassLikeMember<'a>>,
+    bodies: &mut HashMap<u32, (u32, u32)>,
+) {
+    for member in members {
+        if let ClassLikeMember::Method(method) = member
+            && let MethodBody::Concrete(block) = &method.body
+        {
+            bodies.insert(
+                method.name.span.start.offset,
+                (block.left_brace.start.offset, block.right_brace.end.offset),
+            );
+            collect_callable_bodies(block.statements.iter(), bodies);
+        }
+    }
+}
+
+/// Build a `CallHierarchyItem` for a callable declared in `uri`.
+///
+/// The selection range covers the name token; the full range extends
+/// to the end of the body when there is one.
+fn build_call_hierarchy_item(
+    uri: &str,
+    content: &str,
+    decl: &CallableDecl,
+) -> Option<CallHierarchyItem> {
+    let parsed_uri = Url::parse(uri).ok()?;
+    let name_start = offset_to_position(content, decl.name_offset as usize);
+    let name_end = offset_to_position(content, decl.name_offset as usize + decl.name.len());
+    let end = match decl.body {
+        Some((_, body_end)) => offset_to_position(content, body_end as usize),
+        None => name_end,
+    };
+
+    Some(CallHierarchyItem {
+        name: decl.name.clone(),
+        kind: decl.kind,
+        tags: None,
+        detail: decl.detail.clone(),
+        uri: parsed_uri,
+        range: Range {
+            start: name_start,
+            end,
+        },
+        selection_range: Range {
+            start: name_start,
+            end: name_end,
+        },
+        data: None,
+    })
+}
@@ -1654,7 +1654,7 @@ fn analyse_returns(
 
     // Check whether any statement in the selection contains a return
     // (at any nesting level).
-    let any_return = selected.iter().any(|s| selection_stmt_contains_return(s));
+    let any_return = selected.iter().any(|s| stmt_contains_return(s));
 
     if !any_return {
         return ReturnStrategy::None;
@@ -1673,91 +1673,60 @@ fn analyse_returns(
 }
 
 /// Check whether a statement is or contains a `return` at any depth.
-fn selection_stmt_contains_return(stmt: &Statement<'_>) -> bool {
+pub(crate) fn stmt_contains_return(stmt: &Statement<'_>) -> bool {
     match stmt {
         Statement::Return(_) => true,
         Statement::If(if_stmt) => match &if_stmt.body {
             IfBody::Statement(body) => {
-                selection_stmt_contains_return(body.statement)
+                stmt_contains_return(body.statement)
                     || body
                         .else_if_clauses
                         .iter()
-                        .any(|c| selection_stmt_contains_return(c.statement))
+                        .any(|c| stmt_contains_return(c.statement))
                     || body
                         .else_clause
                         .as_ref()
-                        .is_some_and(|c| selection_stmt_contains_return(c.statement))
+                        .is_some_and(|c| stmt_contains_return(c.statement))
             }
             IfBody::ColonDelimited(body) => {
-                body.statements
-                    .iter()
-                    .any(|s| selection_stmt_contains_return(s))
-                    || body.else_if_clauses.iter().any(|c| {
-                        c.statements
-                            .iter()
-                            .any(|s| selection_stmt_contains_return(s))
-                    })
-                    || body.else_clause.as_ref().is_some_and(|c| {
-                        c.statements
-                            .iter()
-                            .any(|s| selection_stmt_contains_return(s))
-                    })
+                body.statements.iter().any(|s| stmt_contains_return(s))
+                    || body
+                        .else_if_clauses
+                        .iter()
+                        .any(|c| c.statements.iter().any(|s| stmt_contains_return(s)))
+                    || body
+                        .else_clause
+                        .as_ref()
+                        .is_some_and(|c| c.statements.iter().any(|s| stmt_contains_return(s)))
             }
         },
         Statement::Foreach(f) => match &f.body {
-            ForeachBody::Statement(s) => selection_stmt_contains_return(s),
-            ForeachBody::ColonDelimited(b) => b
-                .statements
-                .iter()
-                .any(|s| selection_stmt_contains_return(s)),
+            ForeachBody::Statement(s) => stmt_contains_return(s),
+            ForeachBody::ColonDelimited(b) => b.statements.iter().any(|s| stmt_contains_return(s)),
         },
         Statement::While(w) => match &w.body {
-            WhileBody::Statement(s) => selection_stmt_contains_return(s),
-            WhileBody::ColonDelimited(b) => b
-                .statements
-                .iter()
-                .any(|s| selection_stmt_contains_return(s)),
+            WhileBody::Statement(s) => stmt_contains_return(s),
+            WhileBody::ColonDelimited(b) => b.statements.iter().any(|s| stmt_contains_return(s)),
         },
-        Statement::DoWhile(dw) => selection_stmt_contains_return(dw.statement),
+        Statement::DoWhile(dw) => stmt_contains_return(dw.statement),
         Statement::For(f) => match &f.body {
-            ForBody::Statement(s) => selection_stmt_contains_return(s),
-            ForBody::ColonDelimited(b) => b
-                .statements
-                .iter()
-                .any(|s| selection_stmt_contains_return(s)),
+            ForBody::Statement(s) => stmt_contains_return(s),
+            ForBody::ColonDelimited(b) => b.statements.iter().any(|s| stmt_contains_return(s)),
         },
         Statement::Switch(sw) => sw.body.cases().iter().any(|c| match c {
-            SwitchCase::Expression(e) => e
-                .statements
-                .iter()
-                .any(|s| selection_stmt_contains_return(s)),
-            SwitchCase::Default(d) => d
-                .statements
-                .iter()
-                .any(|s| selection_stmt_contains_return(s)),
+            SwitchCase::Expression(e) => e.statements.iter().any(|s| stmt_contains_return(s)),
+            SwitchCase::Default(d) => d.statements.iter().any(|s| stmt_contains_return(s)),
         }),
         Statement::Try(t) => {
-            t.block
-                .statements
-                .iter()
-                .any(|s| selection_stmt_contains_return(s))
-                || t.catch_clauses.iter().any(|c| {
-                    c.block
-                        .statements
-                        .iter()
-                        .any(|s| selection_stmt_contains_return(s))
-                })
-                || t.finally_clause.as_ref().is_some_and(|f| {
-                    f.block
-                        .statements
-                        .iter()
-                        .any(|s| selection_stmt_contains_return(s))
-                })
+            t.block.statements.iter().any(|s| stmt_contains_return(s))
+                || t.catch_clauses
+                    .iter()
+                    .any(|c| c.block.statements.iter().any(|s| stmt_contains_return(s)))
+                || t.finally_clause
+                    .as_ref()
+                    .is_some_and(|f| f.block.statements.iter().any(|s| stmt_contains_return(s)))
         }
-        Statement::Block(b) => b
-            .statements
-            .iter()
-            .any(|s| selection_stmt_contains_return(s)),
+        Statement::Block(b) => b.statements.iter().any(|s| stmt_contains_return(s)),
         _ => false,
     }
 }
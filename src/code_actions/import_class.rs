@@ -172,11 +172,7 @@ impl Backend {
                     } else {
                         Some(matching_diagnostics.clone())
                     },
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(changes),
-                        document_changes: None,
-                        change_annotations: None,
-                    }),
+                    edit: Some(self.build_workspace_edit(changes)),
                     command: None,
                     is_preferred: if candidates.len() == 1 {
                         Some(true)
@@ -308,11 +304,7 @@ impl Backend {
                     title,
                     kind: Some(CodeActionKind::QUICKFIX),
                     diagnostics: None,
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(changes),
-                        document_changes: None,
-                        change_annotations: None,
-                    }),
+                    edit: Some(self.build_workspace_edit(changes)),
                     command: None,
                     is_preferred: if candidates.len() == 1 {
                         Some(true)
@@ -743,11 +735,7 @@ impl Backend {
         let mut changes = HashMap::new();
         changes.insert(doc_uri, all_edits);
 
-        Some(WorkspaceEdit {
-            changes: Some(changes),
-            document_changes: None,
-            change_annotations: None,
-        })
+        Some(self.build_workspace_edit(changes))
     }
 
     /// Find all unresolved class names in a file.
@@ -77,6 +77,7 @@ mod convert_to_instance_variable;
 pub(crate) mod cursor_context;
 mod extract_constant;
 mod extract_function;
+pub(crate) use extract_function::stmt_contains_return;
 mod extract_variable;
 mod generate_constructor;
 mod generate_getter_setter;
@@ -3,14 +3,38 @@
 //! Shows clickable annotations above methods that override a parent
 //! method or implement an interface method, linking to the prototype
 //! declaration.
+//!
+//! Public methods also get an "N references" lens.  Counting requires
+//! a workspace-wide reference search, so these lenses are returned
+//! without a command and filled in by `codeLens/resolve`, which
+//! clients only send for lenses that are actually on screen.
 
+use serde::{Deserialize, Serialize};
 use tower_lsp::lsp_types::*;
 
 use crate::Backend;
 use crate::definition::member::MemberKind;
-use crate::types::{ClassInfo, ClassLikeKind, MAX_INHERITANCE_DEPTH};
+use crate::types::{ClassInfo, ClassLikeKind, MAX_INHERITANCE_DEPTH, Visibility};
 use crate::util::offset_to_position;
 
+/// Client-side command attached to resolved reference-count lenses.
+///
+/// Its arguments are the document URI and the method name position.
+/// The VS Code extension maps it to `editor.action.findReferences`,
+/// which issues a `textDocument/references` request.  It is only
+/// attached when the client lists it in `experimental.commands.commands`
+/// during `initialize`; other clients get a title-only lens.
+pub(crate) const SHOW_REFERENCES_COMMAND: &str = "phpantom.showReferences";
+
+/// Data attached to an unresolved reference-count lens.
+#[derive(Debug, Serialize, Deserialize)]
+struct ReferenceLensData {
+    /// The file URI containing the method.
+    uri: String,
+    /// Position of the method name.
+    position: Position,
+}
+
 /// Information about a prototype (ancestor) method that a local method
 /// overrides or implements.
 struct Prototype {
@@ -111,6 +135,107 @@ impl Backend {
         }
     }
 
+    /// Return an unresolved "N references" lens for each public method
+    /// declared in the file.
+    ///
+    /// The lens carries its method position in `data`; the count and
+    /// command are filled in by [`resolve_code_lens`](Self::resolve_code_lens).
+    pub fn reference_code_lenses(&self, uri: &str, content: &str) -> Vec<CodeLens> {
+        let classes = match self.ast_map.read().get(uri) {
+            Some(classes) => classes.clone(),
+            None => return Vec::new(),
+        };
+
+        let mut lenses = Vec::new();
+        for class in &classes {
+            if class.name.starts_with("__anonymous@") {
+                continue;
+            }
+            for method in &class.methods {
+                if method.name_offset == 0
+                    || method.is_virtual
+                    || method.visibility != Visibility::Public
+                {
+                    continue;
+                }
+                let position = offset_to_position(content, method.name_offset as usize);
+                let line_start = Position {
+                    line: position.line,
+                    character: 0,
+                };
+                let data = ReferenceLensData {
+                    uri: uri.to_string(),
+                    position,
+                };
+                lenses.push(CodeLens {
+                    range: Range {
+                        start: line_start,
+                        end: line_start,
+                    },
+                    command: None,
+                    data: serde_json::to_value(data).ok(),
+                });
+            }
+        }
+        lenses
+    }
+
+    /// Handle a `codeLens/resolve` request.
+    ///
+    /// Counts the references to the method named in a reference lens's
+    /// `data` (excluding its declaration) and attaches a
+    /// [`SHOW_REFERENCES_COMMAND`] command.  When the client has not
+    /// advertised that command, the command id is left empty so the
+    /// count renders as plain text.  Lenses without reference data are
+    /// returned unchanged.
+    pub fn resolve_code_lens(&self, mut lens: CodeLens) -> CodeLens {
+        if lens.command.is_some() {
+            return lens;
+        }
+        let Some(data) = lens
+            .data
+            .clone()
+            .and_then(|v| serde_json::from_value::<ReferenceLensData>(v).ok())
+        else {
+            return lens;
+        };
+        let Some(content) = self.get_file_content(&data.uri) else {
+            return lens;
+        };
+
+        let count = self
+            .find_references(&data.uri, &content, data.position, false)
+            .map_or(0, |locations| locations.len());
+        let title = if count == 1 {
+            "1 reference".to_string()
+        } else {
+            format!("{} references", count)
+        };
+
+        lens.command = Some(
+            if self
+                .supports_show_references_command
+                .load(std::sync::atomic::Ordering::Acquire)
+            {
+                Command {
+                    title,
+                    command: SHOW_REFERENCES_COMMAND.to_string(),
+                    arguments: Some(vec![
+                        serde_json::json!(data.uri),
+                        serde_json::json!(data.position),
+                    ]),
+                }
+            } else {
+                Command {
+                    title,
+                    command: String::new(),
+                    arguments: None,
+                }
+            },
+        );
+        lens
+    }
+
     /// Search the inheritance hierarchy for the closest ancestor that
     /// declares a method with the given name.
     ///
@@ -22,6 +22,7 @@ use std::sync::Arc;
 use tower_lsp::lsp_types::*;
 
 use super::resolve::CompletionItemData;
+use crate::atom::Atom;
 use crate::types::Visibility;
 use crate::types::*;
 
@@ -287,16 +288,29 @@ pub(crate) fn build_method_label(method: &MethodInfo) -> String {
 /// (e.g. `self::__construct()`, `static::__construct()`,
 /// `parent::__construct()`, `ClassName::__construct()` from within a
 /// subclass).  When `false`, magic methods are suppressed entirely.
+///
+/// `sources` records which class declares each member (see
+/// [`MemberSources`]).  When the same member is produced more than once
+/// (e.g. a virtual `@method` shadowing a trait method), only the entry
+/// from the most specific source is kept.  Items are ordered own members
+/// first, then trait members, then inherited members, and each group is
+/// sorted by kind and name.
+///
+/// Methods marked `@internal` are omitted when `current_namespace`
+/// differs from the namespace of the class that declares the method
+/// (see [`is_hidden_internal`]).
 pub(crate) fn build_completion_items(
     target_class: &ClassInfo,
     access_kind: AccessKind,
     current_class_name: Option<&str>,
     is_self_or_ancestor: bool,
+    sources: &MemberSources,
     uri: &str,
+    current_namespace: Option<&str>,
 ) -> Vec<CompletionItem> {
     // Determine whether we are inside the same class as the target.
     let same_class = current_class_name.is_some_and(|name| name == target_class.name);
-    let mut items: Vec<CompletionItem> = Vec::new();
+    let mut items: Vec<(MemberSource, CompletionItem)> = Vec::new();
 
     // Methods — filtered by static / instance, excluding magic methods
     for method in &target_class.methods {
@@ -327,6 +341,13 @@ pub(crate) fn build_completion_items(
         if method.visibility == Visibility::Protected && !same_class && !is_self_or_ancestor {
             continue;
         }
+        if is_hidden_internal(
+            method.is_internal,
+            sources.method_namespace(&method.name),
+            current_namespace,
+        ) {
+            continue;
+        }
 
         let include = match access_kind {
             AccessKind::Arrow => !method.is_static,
@@ -355,6 +376,17 @@ pub(crate) fn build_completion_items(
             .or(method.native_return_type.as_ref())
             .map(shorten_php_type);
 
+        let (source, source_class) = sources.method(&method.name);
+        // Interface methods the class has not implemented yet name the
+        // interface they come from.
+        let detail = if source == MemberSource::Interface {
+            Some(match return_type {
+                Some(rt) => format!("{} [interface {}]", rt, source_class),
+                None => format!("[interface {}]", source_class),
+            })
+        } else {
+            return_type
+        };
         let data = serde_json::to_value(CompletionItemData {
             class_name: target_class.name.to_string(),
             member_name: method.name.to_string(),
@@ -364,22 +396,27 @@ pub(crate) fn build_completion_items(
         })
         .ok();
         let class_description = Some(display_class_name(&target_class.name).to_string());
-        items.push(CompletionItem {
-            label,
-            label_details: Some(CompletionItemLabelDetails {
-                detail: None,
-                description: class_description,
-            }),
-            kind: Some(CompletionItemKind::METHOD),
-            detail: return_type,
-            insert_text: Some(build_callable_snippet(&method.name, &method.parameters)),
-            insert_text_format: Some(InsertTextFormat::SNIPPET),
-            filter_text: Some(method.name.to_string()),
-            tags: deprecation_tag(method.deprecation_message.is_some()),
-            commit_characters: Some(METHOD_COMMIT_CHARS.iter().map(|s| s.to_string()).collect()),
-            data,
-            ..CompletionItem::default()
-        });
+        items.push((
+            source,
+            CompletionItem {
+                label,
+                label_details: Some(CompletionItemLabelDetails {
+                    detail: None,
+                    description: class_description,
+                }),
+                kind: Some(CompletionItemKind::METHOD),
+                detail,
+                insert_text: Some(build_callable_snippet(&method.name, &method.parameters)),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                filter_text: Some(method.name.to_string()),
+                tags: deprecation_tag(method.deprecation_message.is_some()),
+                commit_characters: Some(
+                    METHOD_COMMIT_CHARS.iter().map(|s| s.to_string()).collect(),
+                ),
+                data,
+                ..CompletionItem::default()
+            },
+        ));
     }
 
     // Properties — filtered by static / instance
@@ -413,6 +450,17 @@ pub(crate) fn build_completion_items(
 
         let detail = property.type_hint.as_ref().map(shorten_php_type);
 
+        // Eloquent columns typed by `$casts` / `casts()` are marked so
+        // they are easy to tell apart from declared properties.
+        let is_cast = property.is_virtual
+            && target_class.laravel().is_some_and(|laravel| {
+                laravel
+                    .casts_definitions
+                    .iter()
+                    .any(|(column, _)| column == property.name.as_str())
+            });
+
+        let (source, _) = sources.property(&property.name);
         let data = serde_json::to_value(CompletionItemData {
             class_name: target_class.name.to_string(),
             member_name: property.name.to_string(),
@@ -422,20 +470,23 @@ pub(crate) fn build_completion_items(
         })
         .ok();
         let class_description = Some(display_class_name(&target_class.name).to_string());
-        items.push(CompletionItem {
-            label: display_name.clone(),
-            label_details: Some(CompletionItemLabelDetails {
-                detail: None,
-                description: class_description,
-            }),
-            kind: Some(CompletionItemKind::PROPERTY),
-            detail,
-            insert_text: Some(display_name.clone()),
-            filter_text: Some(display_name),
-            tags: deprecation_tag(property.deprecation_message.is_some()),
-            data,
-            ..CompletionItem::default()
-        });
+        items.push((
+            source,
+            CompletionItem {
+                label: display_name.clone(),
+                label_details: Some(CompletionItemLabelDetails {
+                    detail: is_cast.then(|| " [cast]".to_string()),
+                    description: class_description,
+                }),
+                kind: Some(CompletionItemKind::PROPERTY),
+                detail,
+                insert_text: Some(display_name.clone()),
+                filter_text: Some(display_name),
+                tags: deprecation_tag(property.deprecation_message.is_some()),
+                data,
+                ..CompletionItem::default()
+            },
+        ));
     }
 
     // Constants — only for `::`, `parent::`, or unqualified access
@@ -451,11 +502,15 @@ pub(crate) fn build_completion_items(
                 continue;
             }
 
-            let detail = constant
-                .value
-                .clone()
-                .or_else(|| constant.type_hint.as_ref().map(shorten_php_type));
+            // Typed constants (PHP 8.3) show both, mirroring the hover
+            // signature: `string = 'hello'`.
+            let type_hint = constant.type_hint.as_ref().map(shorten_php_type);
+            let detail = match (type_hint, constant.value.as_ref()) {
+                (Some(hint), Some(value)) => Some(format!("{} = {}", hint, value)),
+                (hint, value) => value.cloned().or(hint),
+            };
 
+            let (source, _) = sources.constant(&constant.name);
             let data = serde_json::to_value(CompletionItemData {
                 class_name: target_class.name.to_string(),
                 member_name: constant.name.to_string(),
@@ -465,55 +520,316 @@ pub(crate) fn build_completion_items(
             })
             .ok();
             let class_description = Some(display_class_name(&target_class.name).to_string());
-            items.push(CompletionItem {
-                label: constant.name.to_string(),
-                label_details: Some(CompletionItemLabelDetails {
-                    detail: None,
-                    description: class_description,
-                }),
-                kind: Some(CompletionItemKind::CONSTANT),
-                detail,
-                insert_text: Some(constant.name.to_string()),
-                filter_text: Some(constant.name.to_string()),
-                tags: deprecation_tag(constant.deprecation_message.is_some()),
-                data,
-                ..CompletionItem::default()
-            });
+            items.push((
+                source,
+                CompletionItem {
+                    label: constant.name.to_string(),
+                    label_details: Some(CompletionItemLabelDetails {
+                        detail: None,
+                        description: class_description,
+                    }),
+                    kind: Some(CompletionItemKind::CONSTANT),
+                    detail,
+                    insert_text: Some(constant.name.to_string()),
+                    filter_text: Some(constant.name.to_string()),
+                    tags: deprecation_tag(constant.deprecation_message.is_some()),
+                    data,
+                    ..CompletionItem::default()
+                },
+            ));
         }
     }
 
     // `::class` keyword — returns the fully qualified class name as a string.
     // Available on any class, interface, or enum via `::` access.
     if access_kind == AccessKind::DoubleColon || access_kind == AccessKind::ParentDoubleColon {
-        items.push(CompletionItem {
-            label: "class".to_string(),
-            kind: Some(CompletionItemKind::KEYWORD),
-            detail: Some("class-string".to_string()),
-            insert_text: Some("class".to_string()),
-            filter_text: Some("class".to_string()),
-            ..CompletionItem::default()
-        });
+        items.push((
+            MemberSource::Own,
+            CompletionItem {
+                label: "class".to_string(),
+                kind: Some(CompletionItemKind::KEYWORD),
+                detail: Some("class-string".to_string()),
+                insert_text: Some("class".to_string()),
+                filter_text: Some("class".to_string()),
+                ..CompletionItem::default()
+            },
+        ));
     }
 
-    // Sort by member kind (constants → properties → methods) then
-    // alphabetically within each kind group.
-    items.sort_by(|a, b| {
-        let ka = kind_sort_tier(a.kind);
-        let kb = kind_sort_tier(b.kind);
-        ka.cmp(&kb).then_with(|| {
-            a.filter_text
-                .as_deref()
-                .unwrap_or(&a.label)
-                .to_lowercase()
-                .cmp(&b.filter_text.as_deref().unwrap_or(&b.label).to_lowercase())
-        })
+    let mut items = dedup_by_source(items);
+
+    // Sort by origin (own → trait → inherited), then by member kind
+    // (constants → properties → methods), then alphabetically within
+    // each group.
+    items.sort_by(|(sa, a), (sb, b)| {
+        sa.cmp(sb)
+            .then_with(|| kind_sort_tier(a.kind).cmp(&kind_sort_tier(b.kind)))
+            .then_with(|| {
+                a.filter_text
+                    .as_deref()
+                    .unwrap_or(&a.label)
+                    .to_lowercase()
+                    .cmp(&b.filter_text.as_deref().unwrap_or(&b.label).to_lowercase())
+            })
     });
 
-    for (i, item) in items.iter_mut().enumerate() {
-        item.sort_text = Some(format!("{:05}", i));
+    // The origin tier digit leads the sort_text so that clients which
+    // re-sort by sort_text keep own members above inherited ones.
+    // `merge_union_completion_items` reads it back via `origin_tier`.
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(i, (source, mut item))| {
+            item.sort_text = Some(format!("{}{:05}", source.sort_tier(), i));
+            item
+        })
+        .collect()
+}
+
+/// Drop duplicate member items, keeping the one from the most specific
+/// [`MemberSource`].
+///
+/// Two items are duplicates when they have the same kind and the same
+/// `filter_text`.  Method names are compared case-insensitively, as in
+/// PHP.  Among duplicates from the same source the first one wins, so
+/// the declaration order of the merged class is preserved.
+fn dedup_by_source(
+    items: Vec<(MemberSource, CompletionItem)>,
+) -> Vec<(MemberSource, CompletionItem)> {
+    let mut kept: Vec<(MemberSource, CompletionItem)> = Vec::with_capacity(items.len());
+    let mut index: HashMap<(u8, String), usize> = HashMap::new();
+
+    for (source, item) in items {
+        let name = item.filter_text.as_deref().unwrap_or(&item.label);
+        let name = if item.kind == Some(CompletionItemKind::METHOD) {
+            name.to_ascii_lowercase()
+        } else {
+            name.to_string()
+        };
+        match index.entry((kind_sort_tier(item.kind), name)) {
+            std::collections::hash_map::Entry::Occupied(slot) => {
+                let existing = &mut kept[*slot.get()];
+                if source < existing.0 {
+                    *existing = (source, item);
+                }
+            }
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(kept.len());
+                kept.push((source, item));
+            }
+        }
     }
 
-    items
+    kept
+}
+
+// ─── Member sources ─────────────────────────────────────────────────────────
+
+/// Where a completed member is declared, relative to the class being
+/// completed on.
+///
+/// Variants are ordered from most to least specific.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum MemberSource {
+    /// Declared on the target class itself.  Virtual members that no
+    /// walked class declares (e.g. `@property` tags or Laravel
+    /// relationship properties) are attributed here as well.
+    Own,
+    /// Declared on a trait used by the target class.
+    Trait,
+    /// Declared on a parent class (or one of its traits) or on a
+    /// `@mixin` class.
+    Inherited,
+    /// Declared only on an interface implemented by the target class or
+    /// one of its parents, i.e. not (yet) implemented in the hierarchy.
+    Interface,
+}
+
+impl MemberSource {
+    /// Leading digit of the `sort_text` for items from this source.
+    fn sort_tier(self) -> u8 {
+        match self {
+            MemberSource::Own => 0,
+            MemberSource::Trait => 1,
+            MemberSource::Inherited => 2,
+            MemberSource::Interface => 3,
+        }
+    }
+}
+
+/// Member name → declaring class lookup for one completion target.
+///
+/// Built once per candidate class by walking the raw (unmerged) class,
+/// its traits, its parent chain, and its `@mixin` classes — the same
+/// walk that [`crate::hover::find_declaring_class`] performs for a
+/// single member.  The first class that declares a name wins, so the
+/// walk order doubles as the PHP precedence order.  Interfaces come
+/// last, so only members that nothing in the hierarchy implements are
+/// attributed to them.
+#[derive(Debug)]
+pub(crate) struct MemberSources {
+    /// Keyed by lowercased method name.
+    methods: HashMap<String, (MemberSource, Atom)>,
+    properties: HashMap<String, (MemberSource, Atom)>,
+    constants: HashMap<String, (MemberSource, Atom)>,
+    /// Namespace of the class declaring each method, keyed like
+    /// `methods`.  Used to scope `@internal` methods to their package.
+    method_namespaces: HashMap<String, Option<Atom>>,
+    /// Short name of the target class, returned for members that no
+    /// walked class declares.
+    owner: Atom,
+    /// Namespace of the target class, for members that no walked class
+    /// declares.
+    owner_namespace: Option<Atom>,
+}
+
+impl MemberSources {
+    /// Walk `target` and its ancestors and record where each member is
+    /// declared.
+    ///
+    /// `target` may already be merged; the raw class is re-loaded via
+    /// `class_loader` so that inherited members are not mistaken for
+    /// own declarations.  When the loader does not know the class
+    /// (e.g. an anonymous class), `target` itself is used.
+    pub(crate) fn collect(
+        target: &ClassInfo,
+        class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+    ) -> Self {
+        let mut sources = MemberSources {
+            methods: HashMap::new(),
+            properties: HashMap::new(),
+            constants: HashMap::new(),
+            method_namespaces: HashMap::new(),
+            owner: target.name,
+            owner_namespace: target.file_namespace,
+        };
+
+        let loaded = class_loader(&target.fqn());
+        let raw: &ClassInfo = loaded.as_deref().unwrap_or(target);
+
+        sources.add_class(raw, MemberSource::Own);
+        sources.add_traits(raw, MemberSource::Trait, class_loader, 0);
+
+        let mut iface_names: Vec<Atom> = raw.interfaces.clone();
+        let mut ancestor_name = raw.parent_class;
+        let mut depth = 0u32;
+        while let Some(name) = ancestor_name {
+            depth += 1;
+            if depth > MAX_INHERITANCE_DEPTH {
+                break;
+            }
+            let Some(ancestor) = class_loader(&name) else {
+                break;
+            };
+            sources.add_class(&ancestor, MemberSource::Inherited);
+            sources.add_traits(&ancestor, MemberSource::Inherited, class_loader, 0);
+            iface_names.extend(ancestor.interfaces.iter().copied());
+            ancestor_name = ancestor.parent_class;
+        }
+
+        for mixin_name in &raw.mixins {
+            if let Some(mixin) = class_loader(mixin_name) {
+                sources.add_class(&mixin, MemberSource::Inherited);
+            }
+        }
+
+        // Interfaces of the class and its parents, then the interfaces
+        // those extend, breadth-first.  An interface implements nothing,
+        // so the members it gets from the interfaces it extends are
+        // plain inherited members rather than unimplemented ones.
+        let iface_source = if raw.kind == ClassLikeKind::Interface {
+            MemberSource::Inherited
+        } else {
+            MemberSource::Interface
+        };
+        let mut idx = 0;
+        while idx < iface_names.len() {
+            let name = iface_names[idx];
+            idx += 1;
+            if iface_names[..idx - 1].contains(&name) {
+                continue;
+            }
+            if let Some(iface) = class_loader(&name) {
+                sources.add_class(&iface, iface_source);
+                iface_names.extend(iface.interfaces.iter().copied());
+            }
+        }
+
+        sources
+    }
+
+    /// Record the members declared directly on `class`.
+    fn add_class(&mut self, class: &ClassInfo, source: MemberSource) {
+        for method in &class.methods {
+            let key = method.name.to_ascii_lowercase();
+            self.method_namespaces
+                .entry(key.clone())
+                .or_insert(class.file_namespace);
+            self.methods.entry(key).or_insert((source, class.name));
+        }
+        for property in &class.properties {
+            self.properties
+                .entry(property.name.to_string())
+                .or_insert((source, class.name));
+        }
+        for constant in &class.constants {
+            self.constants
+                .entry(constant.name.to_string())
+                .or_insert((source, class.name));
+        }
+    }
+
+    /// Record the members of every trait used by `class`, recursing
+    /// into traits that use other traits.
+    fn add_traits(
+        &mut self,
+        class: &ClassInfo,
+        source: MemberSource,
+        class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+        depth: u32,
+    ) {
+        if depth > MAX_TRAIT_DEPTH {
+            return;
+        }
+        for trait_name in &class.used_traits {
+            if let Some(trait_class) = class_loader(trait_name) {
+                self.add_class(&trait_class, source);
+                self.add_traits(&trait_class, source, class_loader, depth + 1);
+            }
+        }
+    }
+
+    /// Source and declaring class of a method.
+    pub(crate) fn method(&self, name: &str) -> (MemberSource, Atom) {
+        self.methods
+            .get(&name.to_ascii_lowercase())
+            .copied()
+            .unwrap_or((MemberSource::Own, self.owner))
+    }
+
+    /// Namespace of the class that declares a method.
+    pub(crate) fn method_namespace(&self, name: &str) -> Option<&str> {
+        self.method_namespaces
+            .get(&name.to_ascii_lowercase())
+            .unwrap_or(&self.owner_namespace)
+            .as_deref()
+    }
+
+    /// Source and declaring class of a property.
+    pub(crate) fn property(&self, name: &str) -> (MemberSource, Atom) {
+        self.properties
+            .get(name)
+            .copied()
+            .unwrap_or((MemberSource::Own, self.owner))
+    }
+
+    /// Source and declaring class of a constant.
+    pub(crate) fn constant(&self, name: &str) -> (MemberSource, Atom) {
+        self.constants
+            .get(name)
+            .copied()
+            .unwrap_or((MemberSource::Own, self.owner))
+    }
 }
 
 // ─── Helpers ────────────────────────────────────────────────────────────────
@@ -548,6 +864,18 @@ pub(crate) fn deprecation_tag(is_deprecated: bool) -> Option<Vec<CompletionItemT
     }
 }
 
+/// Whether an `@internal` symbol should be hidden from completion.
+///
+/// Internal symbols are private to their package, so they are only
+/// offered in files whose namespace matches the declaring namespace.
+pub(crate) fn is_hidden_internal(
+    is_internal: bool,
+    declaring_namespace: Option<&str>,
+    current_namespace: Option<&str>,
+) -> bool {
+    is_internal && declaring_namespace.unwrap_or("") != current_namespace.unwrap_or("")
+}
+
 // ─── Union-merge pipeline ───────────────────────────────────────────────────
 
 /// Check whether `target_class` is the same class as, or an ancestor of,
@@ -611,6 +939,7 @@ pub(crate) fn build_union_completion_items(
     class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
     cache: &crate::virtual_members::ResolvedClassCache,
     uri: &str,
+    current_namespace: Option<&str>,
 ) -> Vec<CompletionItem> {
     let current_class_name = current_class.map(|cc| cc.name.as_str());
     let num_candidates = candidates.len();
@@ -649,13 +978,16 @@ pub(crate) fn build_union_completion_items(
         };
 
         let self_or_ancestor = is_ancestor_of(current_class, target_class, class_loader);
+        let sources = MemberSources::collect(target_class, class_loader);
 
         let items = build_completion_items(
             &merged,
             effective_access,
             current_class_name,
             self_or_ancestor,
+            &sources,
             uri,
+            current_namespace,
         );
 
         for item in items {
@@ -717,6 +1049,17 @@ fn class_names_from_data(item: &CompletionItem) -> Option<String> {
     Some(names.join("|"))
 }
 
+/// Origin tier of a member item, read from the leading digit of the
+/// `sort_text` assigned by [`build_completion_items`].  Items without
+/// one count as own members.
+fn origin_tier(item: &CompletionItem) -> u8 {
+    item.sort_text
+        .as_deref()
+        .and_then(|s| s.chars().next())
+        .and_then(|c| c.to_digit(10))
+        .map_or(MemberSource::Own.sort_tier(), |d| d as u8)
+}
+
 /// Partition and sort completion items by union membership.
 ///
 /// When a variable has a union type (`num_candidates > 1`), members
@@ -725,9 +1068,11 @@ fn class_names_from_data(item: &CompletionItem) -> Option<String> {
 ///
 /// 1. Partitions items into intersection and branch-only based on
 ///    `occurrence_count` vs `num_candidates`.
-/// 2. Sorts each partition alphabetically by `filter_text` / `label`.
-/// 3. Assigns `sort_text` prefixes (`"0_"` for intersection, `"1_"` for
-///    branch-only) so intersection members appear first in the popup.
+/// 2. Sorts each partition by origin tier (own → trait → inherited, see
+///    [`origin_tier`]), then by kind and `filter_text` / `label`.
+/// 3. Assigns `sort_text` keys `{partition}_{tier}_{index}`, where the
+///    partition is `0` for intersection and `1` for branch-only, so
+///    intersection members appear first in the popup.
 /// 4. Adds `label_details` to branch-only items showing which class(es)
 ///    provide them.
 ///
@@ -742,8 +1087,9 @@ pub(crate) fn merge_union_completion_items(
         return items;
     }
 
-    let sort_key = |item: &CompletionItem| -> (u8, String) {
+    let sort_key = |item: &CompletionItem| -> (u8, u8, String) {
         (
+            origin_tier(item),
             kind_sort_tier(item.kind),
             item.filter_text
                 .as_deref()
@@ -767,12 +1113,12 @@ pub(crate) fn merge_union_completion_items(
     intersection.sort_by_key(|item| sort_key(item));
     branch_only.sort_by_key(|item| sort_key(item));
 
-    // Assign sort_text: "0_NNNNN" for intersection, "1_NNNNN" for
-    // branch-only.
+    // Assign sort_text: "0_T_NNNNN" for intersection, "1_T_NNNNN" for
+    // branch-only, where T is the origin tier.
     let mut result = Vec::with_capacity(intersection.len() + branch_only.len());
 
     for (i, mut item) in intersection.into_iter().enumerate() {
-        item.sort_text = Some(format!("0_{:05}", i));
+        item.sort_text = Some(format!("0_{}_{:05}", origin_tier(&item), i));
         // Update description to show all contributing class names
         // (the initial description only has the first candidate).
         if let Some(class_names) = class_names_from_data(&item) {
@@ -789,7 +1135,7 @@ pub(crate) fn merge_union_completion_items(
     }
 
     for (i, mut item) in branch_only.into_iter().enumerate() {
-        item.sort_text = Some(format!("1_{:05}", i));
+        item.sort_text = Some(format!("1_{}_{:05}", origin_tier(&item), i));
         // Add label_details showing the originating class(es) so the
         // user can tell at a glance which branch provides this member.
         // Merge into existing label_details (which may already have a
@@ -906,6 +1252,41 @@ mod tests {
         assert!(result[2].sort_text.as_deref().unwrap().starts_with("1_"));
     }
 
+    #[test]
+    fn union_merge_keeps_origin_tier_order() {
+        let with_tier = |label: &str, tier: u8| CompletionItem {
+            sort_text: Some(format!("{}{:05}", tier, 0)),
+            ..item(label, "A")
+        };
+        let items = vec![
+            with_tier("alpha_inherited", MemberSource::Inherited.sort_tier()),
+            with_tier("beta_trait", MemberSource::Trait.sort_tier()),
+            with_tier("gamma_own", MemberSource::Own.sort_tier()),
+            with_tier("delta_inherited", MemberSource::Inherited.sort_tier()),
+            with_tier("zeta_own", MemberSource::Own.sort_tier()),
+        ];
+        let mut counts = std::collections::HashMap::new();
+        for label in ["alpha_inherited", "beta_trait", "gamma_own"] {
+            counts.insert(label.to_string(), 2);
+        }
+
+        let mut result = merge_union_completion_items(items, counts, 2);
+        result.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        let labels: Vec<&str> = result.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "gamma_own",
+                "beta_trait",
+                "alpha_inherited",
+                "zeta_own",
+                "delta_inherited",
+            ]
+        );
+        assert_eq!(result[0].sort_text.as_deref(), Some("0_0_00000"));
+        assert_eq!(result[3].sort_text.as_deref(), Some("1_0_00000"));
+    }
+
     #[test]
     fn branch_only_items_get_label_details() {
         let items = vec![item("only_a", "A")];
@@ -1042,6 +1423,184 @@ mod tests {
         assert!(is_ancestor_of(Some(&child), &parent_target, &loader));
     }
 
+    // ── MemberSources ───────────────────────────────────────────────────
+
+    #[test]
+    fn member_sources_own_trait_and_parent() {
+        use crate::test_fixtures::{make_class, make_method, make_property};
+
+        let mut child = make_class("Child");
+        child.parent_class = Some(atom("Base"));
+        child.used_traits = vec![atom("Greets")];
+        child.methods.push(Arc::new(make_method("own", None)));
+
+        let loader = |name: &str| -> Option<Arc<ClassInfo>> {
+            match name {
+                "Greets" => {
+                    let mut t = make_class("Greets");
+                    t.methods.push(Arc::new(make_method("greet", None)));
+                    t.methods.push(Arc::new(make_method("own", None)));
+                    Some(Arc::new(t))
+                }
+                "Base" => {
+                    let mut b = make_class("Base");
+                    b.methods.push(Arc::new(make_method("greet", None)));
+                    b.methods.push(Arc::new(make_method("inherited", None)));
+                    b.properties.push(make_property("name", None));
+                    Some(Arc::new(b))
+                }
+                _ => None,
+            }
+        };
+
+        let sources = MemberSources::collect(&child, &loader);
+        assert_eq!(sources.method("own"), (MemberSource::Own, atom("Child")));
+        assert_eq!(
+            sources.method("GREET"),
+            (MemberSource::Trait, atom("Greets"))
+        );
+        assert_eq!(
+            sources.method("inherited"),
+            (MemberSource::Inherited, atom("Base"))
+        );
+        assert_eq!(
+            sources.property("name"),
+            (MemberSource::Inherited, atom("Base"))
+        );
+        // Unknown (virtual) members are attributed to the owner.
+        assert_eq!(
+            sources.property("magic"),
+            (MemberSource::Own, atom("Child"))
+        );
+    }
+
+    #[test]
+    fn member_sources_unimplemented_interface_methods() {
+        use crate::test_fixtures::{make_class, make_method};
+
+        let mut child = make_class("Child");
+        child.parent_class = Some(atom("Base"));
+        child.interfaces = vec![atom("Contract")];
+        child.methods.push(Arc::new(make_method("handle", None)));
+
+        let loader = |name: &str| -> Option<Arc<ClassInfo>> {
+            match name {
+                "Base" => {
+                    let mut b = make_class("Base");
+                    b.interfaces = vec![atom("Named")];
+                    b.methods.push(Arc::new(make_method("getName", None)));
+                    Some(Arc::new(b))
+                }
+                "Contract" => {
+                    let mut c = make_class("Contract");
+                    c.interfaces = vec![atom("Parent")];
+                    c.methods.push(Arc::new(make_method("handle", None)));
+                    c.methods.push(Arc::new(make_method("describe", None)));
+                    Some(Arc::new(c))
+                }
+                "Named" => {
+                    let mut n = make_class("Named");
+                    n.methods.push(Arc::new(make_method("getName", None)));
+                    Some(Arc::new(n))
+                }
+                "Parent" => {
+                    let mut p = make_class("Parent");
+                    p.methods.push(Arc::new(make_method("boot", None)));
+                    Some(Arc::new(p))
+                }
+                _ => None,
+            }
+        };
+
+        let sources = MemberSources::collect(&child, &loader);
+        // Implemented in the hierarchy: attributed to the implementation.
+        assert_eq!(sources.method("handle"), (MemberSource::Own, atom("Child")));
+        assert_eq!(
+            sources.method("getName"),
+            (MemberSource::Inherited, atom("Base"))
+        );
+        // Only declared on an interface, including an extended one.
+        assert_eq!(
+            sources.method("describe"),
+            (MemberSource::Interface, atom("Contract"))
+        );
+        assert_eq!(
+            sources.method("boot"),
+            (MemberSource::Interface, atom("Parent"))
+        );
+    }
+
+    #[test]
+    fn member_sources_interface_extending_interface_is_not_attributed_as_interface() {
+        use crate::test_fixtures::{make_class, make_method};
+
+        let mut child = make_class("Repository");
+        child.kind = ClassLikeKind::Interface;
+        child.interfaces = vec![atom("Countable")];
+        child.methods.push(Arc::new(make_method("find", None)));
+
+        let loader = |name: &str| -> Option<Arc<ClassInfo>> {
+            match name {
+                "Countable" => {
+                    let mut c = make_class("Countable");
+                    c.kind = ClassLikeKind::Interface;
+                    c.methods.push(Arc::new(make_method("count", None)));
+                    Some(Arc::new(c))
+                }
+                _ => None,
+            }
+        };
+
+        let sources = MemberSources::collect(&child, &loader);
+        assert_eq!(
+            sources.method("find"),
+            (MemberSource::Own, atom("Repository"))
+        );
+        assert_eq!(
+            sources.method("count"),
+            (MemberSource::Inherited, atom("Countable"))
+        );
+    }
+
+    // ── dedup_by_source ─────────────────────────────────────────────────
+
+    #[test]
+    fn dedup_keeps_most_specific_source() {
+        let items = vec![
+            (
+                MemberSource::Inherited,
+                item_with_kind("save", "Base", CompletionItemKind::METHOD),
+            ),
+            (
+                MemberSource::Own,
+                item_with_kind("Save", "Child", CompletionItemKind::METHOD),
+            ),
+            (
+                MemberSource::Trait,
+                item_with_kind("save", "Saves", CompletionItemKind::METHOD),
+            ),
+        ];
+        let result = dedup_by_source(items);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, MemberSource::Own);
+        assert_eq!(result[0].1.label, "Save");
+    }
+
+    #[test]
+    fn dedup_keeps_same_name_of_different_kinds() {
+        let items = vec![
+            (
+                MemberSource::Own,
+                item_with_kind("name", "A", CompletionItemKind::METHOD),
+            ),
+            (
+                MemberSource::Own,
+                item_with_kind("name", "A", CompletionItemKind::PROPERTY),
+            ),
+        ];
+        assert_eq!(dedup_by_source(items).len(), 2);
+    }
+
     // ── kind_sort_tier ──────────────────────────────────────────────────
 
     #[test]
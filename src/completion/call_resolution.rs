@@ -602,6 +602,39 @@ impl Backend {
         }
     }
 
+    /// Build a [`ResolvedCallableTarget`] from a typed callable such as
+    /// `Closure(Foo, int): Bar`.
+    ///
+    /// Callable types carry no parameter names, so positional names
+    /// (`$arg1`, `$arg2`, …) are synthesized for the signature label.
+    /// Returns `None` for bare `callable` / `Closure` without a
+    /// parameter list.
+    fn callable_type_to_callable(ty: &PhpType) -> Option<ResolvedCallableTarget> {
+        let params = ty.callable_param_types()?;
+        if params.is_empty() && ty.callable_return_type().is_none() {
+            return None;
+        }
+        let parameters = params
+            .iter()
+            .enumerate()
+            .map(|(i, p)| ParameterInfo {
+                name: atom(&format!("$arg{}", i + 1)),
+                is_required: !p.optional && !p.variadic,
+                type_hint: Some(p.type_hint.clone()),
+                native_type_hint: Some(p.type_hint.clone()),
+                description: None,
+                default_value: None,
+                is_variadic: p.variadic,
+                is_reference: false,
+                closure_this_type: None,
+            })
+            .collect();
+        Some(ResolvedCallableTarget {
+            parameters,
+            return_type: ty.callable_return_type().cloned(),
+        })
+    }
+
     /// Like [`Self::function_to_callable`] but resolves function-level
     /// `@template` parameters from call-site argument text before
     /// building the callable target.  Without this, functions like
@@ -817,16 +850,30 @@ impl Backend {
 
             // ── Variable used as a callable target: `$fn(…)` ────────
             // Check for a first-class callable assignment and recurse.
+            // Otherwise fall back to the variable's declared type, which
+            // covers `@param Closure(Foo, int): Bar $callback`.
             SubjectExpr::Variable(var_name) => {
-                let callable_target =
-                    Self::extract_callable_target_from_variable(var_name, content, cursor_offset)?;
-                self.resolve_callable_target_with_args(
-                    &callable_target,
+                if let Some(callable_target) =
+                    Self::extract_callable_target_from_variable(var_name, content, cursor_offset)
+                {
+                    return self.resolve_callable_target_with_args(
+                        &callable_target,
+                        content,
+                        position,
+                        file_ctx,
+                        call_args_text,
+                    );
+                }
+                let var_type = crate::completion::variable::resolution::resolve_variable_php_type(
+                    var_name,
                     content,
-                    position,
-                    file_ctx,
-                    call_args_text,
-                )
+                    cursor_offset,
+                    current_class,
+                    &file_ctx.classes,
+                    &class_loader,
+                    Loaders::with_function(Some(&function_loader_cl)),
+                )?;
+                Self::callable_type_to_callable(&var_type)
             }
 
             // ── Bare class name used as a function name ─────────────
@@ -1605,6 +1652,16 @@ impl Backend {
         let class_loader = mr_ctx.class_loader;
         let template_subs = mr_ctx.template_subs;
         let var_resolver = mr_ctx.var_resolver;
+
+        // `$container->get('app.mailer')` — a Symfony service fetched by
+        // ID.  The container declares `?object`, so look the ID up in
+        // the workspace's service definitions instead.
+        if let Some(service) =
+            crate::symfony::resolve_container_get(class_info, method_name, text_args, class_loader)
+        {
+            return vec![service];
+        }
+
         // Helper: try to resolve a method's conditional return type, falling
         // back to template-substituted return type, then plain return type.
         let resolve_method = |method: &MethodInfo| -> Vec<Arc<ClassInfo>> {
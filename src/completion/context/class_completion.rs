@@ -1708,6 +1708,9 @@ impl Backend {
                 if !seen_fqns.insert(fqn.clone()) {
                     continue;
                 }
+                if self.is_foreign_internal_class(fqn, file_namespace.as_deref()) {
+                    continue;
+                }
                 // Apply context-aware filtering for loaded classes.
                 // Unloaded classes pass through (demoted below).
                 let ctx_match = if context.is_class_only() {
@@ -1769,6 +1772,9 @@ impl Backend {
                 if !seen_fqns.insert(fqn.clone()) {
                     continue;
                 }
+                if self.is_foreign_internal_class(fqn, file_namespace.as_deref()) {
+                    continue;
+                }
                 // Apply context-aware filtering for loaded classes.
                 // Unloaded classes pass through (demoted below).
                 let ctx_match = if context.is_class_only() {
@@ -2055,6 +2061,21 @@ impl Backend {
         false
     }
 
+    /// Whether `fqn` is a loaded `@internal` class from a namespace other
+    /// than `current_namespace`.
+    ///
+    /// Only consults `fqn_index`, so classes that have not been parsed
+    /// yet are never hidden.
+    fn is_foreign_internal_class(&self, fqn: &str, current_namespace: Option<&str>) -> bool {
+        self.fqn_index.read().get(fqn).is_some_and(|cls| {
+            crate::completion::builder::is_hidden_internal(
+                cls.is_internal,
+                cls.file_namespace.as_deref(),
+                current_namespace,
+            )
+        })
+    }
+
     /// Check whether a class matches the given `ClassNameContext`, or
     /// allow it through if not loaded.
     ///
@@ -0,0 +1,266 @@
+//! Doctrine `Column` mapping completion.
+//!
+//! Argument names inside `#[Column(` / `#[ORM\Column(` come from the
+//! `Doctrine\ORM\Mapping\Column` class through normal named-argument
+//! completion; PHPantom bundles a stub of that class so this works even
+//! before doctrine/orm is installed.  This module adds the two pieces
+//! named-argument completion cannot provide:
+//!
+//! - Doctrine type names inside the string value of `type:` (attribute)
+//!   or `type=` (docblock annotation).
+//! - Argument names inside a docblock `@ORM\Column(` annotation, which
+//!   are written `name=` and are drawn from the same constructor.
+//!
+//! The enclosing call is found with a backward scan that skips balanced
+//! parentheses, and its name must resolve to the Doctrine `Column` class
+//! through the file's use statements.
+
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::completion::named_args::{
+    extract_call_expression, position_to_char_offset, split_args_top_level,
+};
+use crate::types::FileContext;
+use crate::util::resolve_to_fqn;
+
+/// Fully-qualified name of the Doctrine column mapping attribute.
+const COLUMN_FQN: &str = "Doctrine\\ORM\\Mapping\\Column";
+
+/// Doctrine DBAL type names accepted by the `type` argument.
+const COLUMN_TYPES: &[&str] = &[
+    "string",
+    "integer",
+    "boolean",
+    "datetime",
+    "datetime_immutable",
+    "text",
+    "float",
+    "decimal",
+    "smallint",
+    "bigint",
+    "date",
+    "date_immutable",
+    "time",
+    "time_immutable",
+    "datetimetz",
+    "datetimetz_immutable",
+    "dateinterval",
+    "json",
+    "simple_array",
+    "guid",
+    "ascii_string",
+    "binary",
+    "blob",
+];
+
+/// How the mapping is written: a PHP 8 attribute or a docblock annotation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColumnSyntax {
+    /// `#[ORM\Column(type: 'string')]` — named arguments use `:`.
+    Attribute,
+    /// `@ORM\Column(type="string")` — arguments use `=`.
+    Annotation,
+}
+
+impl ColumnSyntax {
+    fn separator(self) -> char {
+        match self {
+            ColumnSyntax::Attribute => ':',
+            ColumnSyntax::Annotation => '=',
+        }
+    }
+}
+
+impl Backend {
+    /// Build completions for the argument under the cursor in a Doctrine
+    /// `Column` mapping.
+    ///
+    /// Offers Doctrine type names inside the `type` string of both the
+    /// attribute and the annotation form, and argument names at an
+    /// argument position of the annotation form.  Returns `None` when the
+    /// cursor is not inside a `Column(` call that resolves to Doctrine.
+    pub(crate) fn complete_doctrine_column(
+        &self,
+        content: &str,
+        position: Position,
+        ctx: &FileContext,
+    ) -> Option<Vec<CompletionItem>> {
+        let chars: Vec<char> = content.chars().collect();
+        let cursor = position_to_char_offset(&chars, position)?;
+        let (syntax, name, open) = enclosing_call(&chars, cursor)?;
+        if !resolve_to_fqn(&name, &ctx.use_map, &ctx.namespace).eq_ignore_ascii_case(COLUMN_FQN) {
+            return None;
+        }
+
+        let args_text: String = chars[open + 1..cursor].iter().collect();
+        let mut args = split_args_top_level(&args_text);
+        let current = if args.is_empty() || args_text.trim_end().ends_with(',') {
+            String::new()
+        } else {
+            args.pop().unwrap_or_default()
+        };
+
+        if let Some(typed) = type_value_prefix(&current, syntax.separator()) {
+            return Some(type_items(position, typed));
+        }
+
+        if syntax == ColumnSyntax::Annotation {
+            let prefix = current.trim_start();
+            if !prefix.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return None;
+            }
+            let existing: Vec<&str> = args
+                .iter()
+                .filter_map(|arg| arg.split_once('=').map(|(name, _)| name.trim()))
+                .collect();
+            return Some(self.annotation_argument_items(prefix, &existing));
+        }
+
+        None
+    }
+
+    /// Build `name=` items for the annotation form from the parameters of
+    /// the `Column` constructor (the project's class, or the bundled stub).
+    fn annotation_argument_items(&self, prefix: &str, existing: &[&str]) -> Vec<CompletionItem> {
+        let Some(column) = self.find_or_load_class(COLUMN_FQN) else {
+            return Vec::new();
+        };
+        let Some(ctor) = column.get_method("__construct") else {
+            return Vec::new();
+        };
+        let prefix_lower = prefix.to_lowercase();
+
+        ctor.parameters
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, param)| {
+                let bare_name = param.name.strip_prefix('$').unwrap_or(&param.name);
+                if existing.contains(&bare_name)
+                    || !bare_name.to_lowercase().starts_with(&prefix_lower)
+                {
+                    return None;
+                }
+                let label = match param.type_hint {
+                    Some(ref th) => format!("{}={}", bare_name, th),
+                    None => format!("{}=", bare_name),
+                };
+                Some(CompletionItem {
+                    label,
+                    kind: Some(CompletionItemKind::VARIABLE),
+                    detail: Some("Annotation argument".to_string()),
+                    insert_text: Some(format!("{}=", bare_name)),
+                    filter_text: Some(bare_name.to_string()),
+                    sort_text: Some(format!("0_{:03}", idx)),
+                    ..CompletionItem::default()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Find the innermost still-open call around `cursor` and return its
+/// syntax, its name as written, and the offset of its `(`.
+fn enclosing_call(chars: &[char], cursor: usize) -> Option<(ColumnSyntax, String, usize)> {
+    let mut depth = 0u32;
+    for open in (0..cursor).rev() {
+        match chars[open] {
+            ')' => depth += 1,
+            '(' if depth > 0 => depth -= 1,
+            '(' => {
+                let name = extract_call_expression(chars, open)?;
+                let mut start = open;
+                while start > 0 && chars[start - 1] == ' ' {
+                    start -= 1;
+                }
+                start -= name.chars().count().min(start);
+                let syntax = if start > 0 && chars[start - 1] == '@' {
+                    ColumnSyntax::Annotation
+                } else {
+                    ColumnSyntax::Attribute
+                };
+                return Some((syntax, name, open));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// When `arg` is a `type` argument whose string value is still open,
+/// return the number of characters typed since the opening quote.
+fn type_value_prefix(arg: &str, separator: char) -> Option<usize> {
+    let rest = arg.trim_start().strip_prefix("type")?;
+    let rest = rest.trim_start().strip_prefix(separator)?;
+    let mut value = rest.trim_start().chars();
+    let quote = value.next().filter(|c| *c == '\'' || *c == '"')?;
+    let typed: Vec<char> = value.collect();
+    if typed.iter().any(|c| *c == quote || *c == '\\') {
+        return None;
+    }
+    Some(typed.len())
+}
+
+/// Build one value item per Doctrine type, replacing the `typed`
+/// characters before the cursor.
+fn type_items(position: Position, typed: usize) -> Vec<CompletionItem> {
+    let replace_range = Range {
+        start: Position {
+            line: position.line,
+            character: position.character.saturating_sub(typed as u32),
+        },
+        end: position,
+    };
+
+    COLUMN_TYPES
+        .iter()
+        .enumerate()
+        .map(|(idx, ty)| CompletionItem {
+            label: ty.to_string(),
+            kind: Some(CompletionItemKind::VALUE),
+            detail: Some("Doctrine type".to_string()),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: replace_range,
+                new_text: ty.to_string(),
+            })),
+            filter_text: Some(ty.to_string()),
+            sort_text: Some(format!("{:03}", idx)),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_attribute_and_annotation_syntax() {
+        let attr: Vec<char> = "#[ORM\\Column(type: 'st".chars().collect();
+        let (syntax, name, open) = enclosing_call(&attr, attr.len()).unwrap();
+        assert!(syntax == ColumnSyntax::Attribute);
+        assert_eq!(name, "ORM\\Column");
+        assert_eq!(open, 12);
+
+        let annotation: Vec<char> = " * @ORM\\Column(length=10, ".chars().collect();
+        let (syntax, name, _) = enclosing_call(&annotation, annotation.len()).unwrap();
+        assert!(syntax == ColumnSyntax::Annotation);
+        assert_eq!(name, "ORM\\Column");
+    }
+
+    #[test]
+    fn skips_closed_inner_calls() {
+        let chars: Vec<char> = "#[Column(length: max(1, 2), ".chars().collect();
+        let (_, name, _) = enclosing_call(&chars, chars.len()).unwrap();
+        assert_eq!(name, "Column");
+    }
+
+    #[test]
+    fn type_value_prefix_requires_open_type_string() {
+        assert_eq!(type_value_prefix(" type: 'da", ':'), Some(2));
+        assert_eq!(type_value_prefix("type=\"", '='), Some(0));
+        assert_eq!(type_value_prefix("type: 'date'", ':'), None);
+        assert_eq!(type_value_prefix("name: 'da", ':'), None);
+        assert_eq!(type_value_prefix("type= 'da", ':'), None);
+    }
+}
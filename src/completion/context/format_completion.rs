@@ -0,0 +1,193 @@
+//! `printf`-style format specifier completion.
+//!
+//! When the cursor is inside the format string of `sprintf`, `printf`,
+//! `fprintf`, `sscanf` (or one of the `v*printf` variants), this module
+//! offers the common conversion specifiers (`%s`, `%d`, `%f`, …) as
+//! snippet items.
+//!
+//! The enclosing call is located with the same backward scan that
+//! powers named-argument completion ([`extract_call_expression`] and
+//! [`split_args_top_level`]), so `\sprintf(` and calls nested inside
+//! other expressions are recognised.  Completion only fires when the
+//! cursor sits inside a still-open string literal that is the call's
+//! format argument.
+
+use tower_lsp::lsp_types::*;
+
+use crate::completion::named_args::{
+    extract_call_expression, position_to_char_offset, split_args_top_level,
+};
+
+/// Format functions and the zero-based index of their format argument.
+const FORMAT_FUNCTIONS: &[(&str, usize)] = &[
+    ("sprintf", 0),
+    ("printf", 0),
+    ("vsprintf", 0),
+    ("vprintf", 0),
+    ("fprintf", 1),
+    ("vfprintf", 1),
+    ("sscanf", 1),
+];
+
+/// Conversion specifiers offered inside a format string, with a short
+/// description shown as the item detail.
+const FORMAT_SPECIFIERS: &[(&str, &str)] = &[
+    ("%s", "String"),
+    ("%d", "Signed decimal integer"),
+    ("%f", "Floating-point number (locale aware)"),
+    ("%b", "Binary integer"),
+    ("%x", "Hexadecimal integer (lowercase)"),
+    ("%o", "Octal integer"),
+    ("%c", "Character with the given ASCII code"),
+    ("%e", "Scientific notation (lowercase)"),
+    ("%%", "Literal percent sign"),
+];
+
+/// Build format specifier completions when the cursor is inside the
+/// format string of a `printf`-family call.
+///
+/// A `%` (optionally followed by one letter) directly before the cursor
+/// is replaced by the chosen specifier.  Returns `None` when the cursor
+/// is not inside a format string or does not follow a `%`.
+pub(crate) fn complete_format_specifier(
+    content: &str,
+    position: Position,
+) -> Option<Vec<CompletionItem>> {
+    let chars: Vec<char> = content.chars().collect();
+    let cursor = position_to_char_offset(&chars, position)?;
+    if !is_in_format_string(&chars, cursor) {
+        return None;
+    }
+
+    let typed = if cursor >= 1 && chars[cursor - 1] == '%' {
+        1
+    } else if cursor >= 2 && chars[cursor - 2] == '%' && chars[cursor - 1].is_ascii_alphabetic() {
+        2
+    } else {
+        return None;
+    };
+    let replace_range = Range {
+        start: Position {
+            line: position.line,
+            character: position.character.saturating_sub(typed as u32),
+        },
+        end: position,
+    };
+
+    let items = FORMAT_SPECIFIERS
+        .iter()
+        .enumerate()
+        .map(|(i, (spec, description))| CompletionItem {
+            label: spec.to_string(),
+            kind: Some(CompletionItemKind::SNIPPET),
+            detail: Some(description.to_string()),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: replace_range,
+                new_text: spec.to_string(),
+            })),
+            filter_text: Some(spec.to_string()),
+            sort_text: Some(format!("{:02}", i)),
+            ..CompletionItem::default()
+        })
+        .collect();
+    Some(items)
+}
+
+/// Whether `cursor` sits inside the still-open format string literal of
+/// the innermost enclosing `printf`-family call.
+fn is_in_format_string(chars: &[char], cursor: usize) -> bool {
+    for open in (0..cursor).rev() {
+        if chars[open] != '(' {
+            continue;
+        }
+        let Some(call) = extract_call_expression(chars, open) else {
+            continue;
+        };
+        let name = call.trim_start_matches('\\');
+        let Some(&(_, format_index)) = FORMAT_FUNCTIONS
+            .iter()
+            .find(|(f, _)| f.eq_ignore_ascii_case(name))
+        else {
+            continue;
+        };
+
+        let args_text: String = chars[open + 1..cursor].iter().collect();
+        let args = split_args_top_level(&args_text);
+        return args.len() == format_index + 1
+            && args
+                .last()
+                .is_some_and(|arg| is_open_string(arg.trim_start()));
+    }
+    false
+}
+
+/// Whether `arg` starts with a quote that is never closed.
+fn is_open_string(arg: &str) -> bool {
+    let mut chars = arg.chars();
+    let Some(quote) = chars.next().filter(|c| *c == '\'' || *c == '"') else {
+        return false;
+    };
+    let mut escaped = false;
+    for c in chars {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels_at(content: &str, line: u32, character: u32) -> Option<Vec<String>> {
+        complete_format_specifier(content, Position { line, character })
+            .map(|items| items.into_iter().map(|i| i.label).collect())
+    }
+
+    #[test]
+    fn offers_specifiers_in_sprintf_format() {
+        let content = "<?php\n$s = sprintf('Hello %";
+        let items = complete_format_specifier(content, Position::new(1, 21)).unwrap();
+        assert_eq!(items.len(), FORMAT_SPECIFIERS.len());
+        assert_eq!(items[0].label, "%s");
+        assert_eq!(items[0].kind, Some(CompletionItemKind::SNIPPET));
+        assert_eq!(items[0].detail.as_deref(), Some("String"));
+        let Some(CompletionTextEdit::Edit(edit)) = &items[0].text_edit else {
+            panic!("expected a text edit");
+        };
+        assert_eq!(edit.range.start, Position::new(1, 20));
+    }
+
+    #[test]
+    fn uses_second_argument_for_fprintf_and_sscanf() {
+        assert!(labels_at("<?php\nfprintf($fh, \"%", 1, 15).is_some());
+        assert!(labels_at("<?php\nsscanf($line, '%", 1, 16).is_some());
+        assert!(labels_at("<?php\nfprintf('%", 1, 10).is_none());
+    }
+
+    #[test]
+    fn ignores_other_arguments_and_calls() {
+        // Cursor in the second argument of sprintf.
+        assert!(labels_at("<?php\nsprintf('%s', '%", 1, 16).is_none());
+        // Cursor after the format string has been closed.
+        assert!(labels_at("<?php\nsprintf('%s' . ", 1, 15).is_none());
+        // Not a format function.
+        assert!(labels_at("<?php\nstrlen('%", 1, 9).is_none());
+    }
+
+    #[test]
+    fn requires_percent_before_cursor() {
+        assert!(labels_at("<?php\nsprintf('Hello ", 1, 15).is_none());
+        assert!(labels_at("<?php\nsprintf('Hello %d", 1, 17).is_some());
+    }
+
+    #[test]
+    fn recognises_fully_qualified_call() {
+        assert!(labels_at("<?php\necho \\sprintf(\"Total: %", 1, 23).is_some());
+    }
+}
@@ -0,0 +1,253 @@
+//! WordPress hook name completion.
+//!
+//! When the cursor is inside the hook name string of `add_action`,
+//! `add_filter`, `do_action`, `apply_filters` (or one of their
+//! `remove_*` / `has_*` siblings), this module offers the names of
+//! common WordPress core hooks as value items.  Action functions offer
+//! action hooks and filter functions offer filter hooks.
+//!
+//! The enclosing call is located with the same backward scan used by
+//! format specifier completion, so `\add_action(` and calls nested
+//! inside other expressions are recognised.  Completion only fires when
+//! the cursor sits inside a still-open string literal that is the
+//! call's first argument.
+
+use tower_lsp::lsp_types::*;
+
+use crate::completion::named_args::{
+    extract_call_expression, position_to_char_offset, split_args_top_level,
+};
+
+/// Which registry a hook function draws its names from.
+#[derive(Clone, Copy)]
+enum HookKind {
+    Action,
+    Filter,
+}
+
+/// Hook functions whose first argument is a hook name.
+const HOOK_FUNCTIONS: &[(&str, HookKind)] = &[
+    ("add_action", HookKind::Action),
+    ("remove_action", HookKind::Action),
+    ("has_action", HookKind::Action),
+    ("do_action", HookKind::Action),
+    ("do_action_ref_array", HookKind::Action),
+    ("did_action", HookKind::Action),
+    ("remove_all_actions", HookKind::Action),
+    ("add_filter", HookKind::Filter),
+    ("remove_filter", HookKind::Filter),
+    ("has_filter", HookKind::Filter),
+    ("apply_filters", HookKind::Filter),
+    ("apply_filters_ref_array", HookKind::Filter),
+    ("remove_all_filters", HookKind::Filter),
+];
+
+/// Commonly used WordPress core action hooks.
+const ACTION_HOOKS: &[&str] = &[
+    "muplugins_loaded",
+    "plugins_loaded",
+    "setup_theme",
+    "after_setup_theme",
+    "init",
+    "widgets_init",
+    "wp_loaded",
+    "parse_request",
+    "send_headers",
+    "wp",
+    "template_redirect",
+    "pre_get_posts",
+    "wp_enqueue_scripts",
+    "wp_head",
+    "wp_body_open",
+    "wp_footer",
+    "admin_init",
+    "admin_menu",
+    "admin_notices",
+    "admin_enqueue_scripts",
+    "admin_head",
+    "admin_footer",
+    "login_enqueue_scripts",
+    "enqueue_block_editor_assets",
+    "add_meta_boxes",
+    "save_post",
+    "wp_insert_post",
+    "delete_post",
+    "transition_post_status",
+    "comment_post",
+    "user_register",
+    "profile_update",
+    "wp_login",
+    "wp_logout",
+    "rest_api_init",
+    "customize_register",
+    "wp_dashboard_setup",
+    "activated_plugin",
+    "deactivated_plugin",
+    "switch_theme",
+    "shutdown",
+];
+
+/// Commonly used WordPress core filter hooks.
+const FILTER_HOOKS: &[&str] = &[
+    "the_content",
+    "the_title",
+    "the_excerpt",
+    "excerpt_length",
+    "excerpt_more",
+    "the_permalink",
+    "post_link",
+    "body_class",
+    "post_class",
+    "document_title_parts",
+    "wp_title",
+    "template_include",
+    "query_vars",
+    "rewrite_rules_array",
+    "wp_nav_menu_items",
+    "nav_menu_css_class",
+    "script_loader_tag",
+    "style_loader_tag",
+    "upload_mimes",
+    "sanitize_file_name",
+    "wp_insert_post_data",
+    "comment_text",
+    "widget_text",
+    "get_avatar",
+    "login_redirect",
+    "authenticate",
+    "wp_mail",
+    "wp_mail_from",
+    "wp_mail_from_name",
+    "cron_schedules",
+    "http_request_args",
+    "wp_headers",
+    "home_url",
+    "site_url",
+    "plugin_action_links",
+    "admin_footer_text",
+    "manage_posts_columns",
+    "image_size_names_choose",
+    "intermediate_image_sizes_advanced",
+    "allowed_block_types_all",
+    "block_categories_all",
+    "rest_pre_dispatch",
+];
+
+/// Build hook name completions when the cursor is inside the hook name
+/// string of a WordPress hook function call.
+///
+/// The text typed since the opening quote is replaced by the chosen
+/// hook name.  Returns `None` when the cursor is not inside a hook name.
+pub(crate) fn complete_hook_name(content: &str, position: Position) -> Option<Vec<CompletionItem>> {
+    let chars: Vec<char> = content.chars().collect();
+    let cursor = position_to_char_offset(&chars, position)?;
+    let (kind, typed) = hook_name_argument(&chars, cursor)?;
+
+    let replace_range = Range {
+        start: Position {
+            line: position.line,
+            character: position.character.saturating_sub(typed as u32),
+        },
+        end: position,
+    };
+    let (hooks, detail) = match kind {
+        HookKind::Action => (ACTION_HOOKS, "WordPress action"),
+        HookKind::Filter => (FILTER_HOOKS, "WordPress filter"),
+    };
+
+    let items = hooks
+        .iter()
+        .map(|hook| CompletionItem {
+            label: hook.to_string(),
+            kind: Some(CompletionItemKind::VALUE),
+            detail: Some(detail.to_string()),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: replace_range,
+                new_text: hook.to_string(),
+            })),
+            filter_text: Some(hook.to_string()),
+            ..CompletionItem::default()
+        })
+        .collect();
+    Some(items)
+}
+
+/// When `cursor` sits inside the still-open first-argument string of the
+/// innermost enclosing hook function call, return the hook kind and the
+/// number of characters typed since the opening quote.
+fn hook_name_argument(chars: &[char], cursor: usize) -> Option<(HookKind, usize)> {
+    for open in (0..cursor).rev() {
+        if chars[open] != '(' {
+            continue;
+        }
+        let Some(call) = extract_call_expression(chars, open) else {
+            continue;
+        };
+        let name = call.trim_start_matches('\\');
+        let &(_, kind) = HOOK_FUNCTIONS
+            .iter()
+            .find(|(f, _)| f.eq_ignore_ascii_case(name))?;
+
+        let args_text: String = chars[open + 1..cursor].iter().collect();
+        let args = split_args_top_level(&args_text);
+        if args.len() != 1 {
+            return None;
+        }
+        let arg = args[0].trim_start();
+        let mut arg_chars = arg.chars();
+        let quote = arg_chars.next().filter(|c| *c == '\'' || *c == '"')?;
+        let typed: Vec<char> = arg_chars.collect();
+        if typed.iter().any(|c| *c == quote || *c == '\\') {
+            return None;
+        }
+        return Some((kind, typed.len()));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels_at(content: &str, line: u32, character: u32) -> Option<Vec<String>> {
+        complete_hook_name(content, Position { line, character })
+            .map(|items| items.into_iter().map(|i| i.label).collect())
+    }
+
+    #[test]
+    fn offers_action_hooks_in_add_action() {
+        let content = "<?php\nadd_action('in";
+        let items = complete_hook_name(content, Position::new(1, 14)).unwrap();
+        assert_eq!(items.len(), ACTION_HOOKS.len());
+        let init = items.iter().find(|i| i.label == "init").unwrap();
+        assert_eq!(init.kind, Some(CompletionItemKind::VALUE));
+        assert_eq!(init.detail.as_deref(), Some("WordPress action"));
+        let Some(CompletionTextEdit::Edit(edit)) = &init.text_edit else {
+            panic!("expected a text edit");
+        };
+        assert_eq!(edit.range.start, Position::new(1, 12));
+    }
+
+    #[test]
+    fn offers_filter_hooks_in_filter_functions() {
+        let labels = labels_at("<?php\nadd_filter(\"", 1, 12).unwrap();
+        assert!(labels.contains(&"the_content".to_string()));
+        assert!(!labels.contains(&"init".to_string()));
+        assert!(labels_at("<?php\n$t = apply_filters('", 1, 20).is_some());
+    }
+
+    #[test]
+    fn ignores_other_arguments_and_calls() {
+        // Cursor in the callback argument.
+        assert!(labels_at("<?php\nadd_action('init', '", 1, 20).is_none());
+        // Hook name string already closed.
+        assert!(labels_at("<?php\nadd_action('init' . ", 1, 19).is_none());
+        // Not a hook function.
+        assert!(labels_at("<?php\nstrlen('", 1, 8).is_none());
+    }
+
+    #[test]
+    fn recognises_fully_qualified_call() {
+        assert!(labels_at("<?php\n\\add_action('", 1, 13).is_some());
+    }
+}
@@ -5,14 +5,23 @@
 /// - **catch_completion**: Smart exception type completion inside `catch()` clauses
 /// - **class_completion**: Class name completions (class, interface, trait, enum)
 /// - **constant_completion**: Global constant name completions
+/// - **doctrine_completion**: Doctrine `Column` type names and annotation
+///   argument names
+/// - **format_completion**: `printf`-style format specifier completions
+///   inside the format string of `sprintf` and friends
 /// - **function_completion**: Standalone function name completions
+/// - **hook_completion**: WordPress hook name completions inside the
+///   first argument of `add_action`, `add_filter`, and friends
 /// - **namespace_completion**: Namespace declaration completions
 /// - **type_hint_completion**: Type completion inside function/method parameter lists,
 ///   return types, and property declarations
 pub(crate) mod catch_completion;
 pub(crate) mod class_completion;
 pub(crate) mod constant_completion;
+pub(crate) mod doctrine_completion;
+pub(crate) mod format_completion;
 pub(crate) mod function_completion;
+pub(crate) mod hook_completion;
 pub(crate) mod keyword_completion;
 pub(crate) mod namespace_completion;
 pub(crate) mod type_hint_completion;
@@ -90,12 +90,31 @@ fn paren_follows_cursor(content: &str, position: Position) -> bool {
     after_ident.starts_with('(')
 }
 
+/// Return the trigger character when the request was fired by typing one.
+///
+/// Requests fired by `Ctrl+Space` (`INVOKED`) or to refresh an incomplete
+/// list return `None`, so they always run the full strategy pipeline.
+fn typed_trigger_character(params: &CompletionParams) -> Option<String> {
+    let ctx = params.context.as_ref()?;
+    if ctx.trigger_kind != CompletionTriggerKind::TRIGGER_CHARACTER {
+        return None;
+    }
+    ctx.trigger_character.clone()
+}
+
+/// Check whether the text immediately before the cursor ends with `suffix`.
+fn cursor_follows(content: &str, position: Position, suffix: &str) -> bool {
+    let byte_off = position_to_byte_offset(content, position);
+    content[..byte_off].ends_with(suffix)
+}
+
 /// Downgrade callable snippet items to plain-name insertions.
 ///
 /// When `(` already follows the cursor, snippets that insert their own
 /// parentheses would produce duplicates.  This strips the snippet
 /// format and replaces the insert text with just the name from
-/// `filter_text`.
+/// `filter_text`.  Also applied to every response when the user has
+/// turned snippets off via `[completion] use-snippets = false`.
 ///
 /// Applies to methods, functions, and class names (for `new` / `throw new`).
 fn strip_snippet_parens(items: Vec<CompletionItem>) -> Vec<CompletionItem> {
@@ -255,13 +274,49 @@ fn append_semicolon_to_insert_text(items: Vec<CompletionItem>) -> Vec<Completion
 impl Backend {
     /// Main completion handler — called by `LanguageServer::completion`.
     ///
-    /// Tries each completion strategy in priority order and returns the
-    /// first one that produces results.  Falls back to no completions
-    /// when nothing matches.
+    /// Delegates to [`Self::complete_at`] and then applies user-level
+    /// post-processing: when `[completion] use-snippets = false`,
+    /// callable snippets are downgraded to plain-name insertions.
+    ///
+    /// A new completion request starts a new popup, so documentation
+    /// cached by `completionItem/resolve` for the previous one is dropped.
     pub(crate) async fn handle_completion(
         &self,
         params: CompletionParams,
     ) -> Result<Option<CompletionResponse>> {
+        self.completion_resolve_cache.lock().clear();
+        let response = self.complete_at(params).await?;
+        if self.config.lock().completion.snippets_enabled() {
+            return Ok(response);
+        }
+        Ok(response.map(|response| match response {
+            CompletionResponse::Array(items) => {
+                CompletionResponse::Array(strip_snippet_parens(items))
+            }
+            CompletionResponse::List(mut list) => {
+                list.items = strip_snippet_parens(list.items);
+                CompletionResponse::List(list)
+            }
+        }))
+    }
+
+    /// Tries each completion strategy in priority order and returns the
+    /// first one that produces results.  Falls back to no completions
+    /// when nothing matches.
+    ///
+    /// When the request was fired by a trigger character, strategies
+    /// that cannot apply to that character are skipped:
+    ///
+    /// - `>` only completes members after `->` / `?->`.  Comparison and
+    ///   `=>` operators produce nothing, and an unresolvable subject
+    ///   produces nothing rather than falling through to name
+    ///   completion.
+    /// - `$` only completes variable names, except after `::` where it
+    ///   starts a static property name.
+    /// - `%` only completes format specifiers inside the format string
+    ///   of a `printf`-family call.
+    async fn complete_at(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let trigger = typed_trigger_character(&params);
         let uri = params.text_document_position.text_document.uri.to_string();
         let mut position = params.text_document_position.position;
 
@@ -286,6 +341,7 @@ impl Backend {
             // request.  The guard is re-entrant safe.
             let _chain_guard = super::resolver::with_chain_resolution_cache();
             let _body_infer_guard = self.activate_body_return_inferrer();
+            let _service_map_guard = self.activate_symfony_service_map();
 
             // Gather per-file context (classes, use-map, namespace) in one
             // call instead of three separate lock-and-unwrap blocks.
@@ -299,6 +355,23 @@ impl Backend {
                 return Ok(None);
             }
 
+            // ── `>` typed outside of `->` ────────────────────────────
+            if trigger.as_deref() == Some(">") && !cursor_follows(&content, position, "->") {
+                return Ok(None);
+            }
+
+            // ── `%` typed ───────────────────────────────────────────
+            // Only a format string has anything to complete after `%`;
+            // elsewhere it is the modulo operator.
+            if trigger.as_deref() == Some("%") {
+                return Ok(
+                    crate::completion::context::format_completion::complete_format_specifier(
+                        &content, position,
+                    )
+                    .map(CompletionResponse::Array),
+                );
+            }
+
             // ── PHPDoc block generation on `/**` ────────────────────
             // When the user types `/**` above a declaration, generate
             // a complete docblock skeleton as a single snippet item.
@@ -331,8 +404,12 @@ impl Backend {
             }
 
             // ── Docblock type / variable completion ─────────────────
-            // Always short-circuits when inside a docblock.
+            // Always short-circuits when inside a docblock.  A Doctrine
+            // `@ORM\Column(` annotation is handled first.
             if crate::completion::comment_position::is_inside_docblock(&content, position) {
+                if let Some(items) = self.complete_doctrine_column(&content, position, &ctx) {
+                    return Ok(Some(CompletionResponse::Array(items)));
+                }
                 return Ok(self.complete_docblock_type_or_variable(&content, position, &ctx, &uri));
             }
 
@@ -356,6 +433,38 @@ impl Backend {
                 crate::completion::comment_position::classify_string_context(&content, position);
             use crate::completion::comment_position::StringContext;
 
+            // ── Format specifier completion ─────────────────────────
+            // Inside the format string of `sprintf` and friends, offer
+            // conversion specifiers after a `%`.
+            if matches!(string_ctx, StringContext::InStringLiteral)
+                && let Some(items) =
+                    crate::completion::context::format_completion::complete_format_specifier(
+                        &content, position,
+                    )
+            {
+                return Ok(Some(CompletionResponse::Array(items)));
+            }
+
+            // ── WordPress hook name completion ──────────────────────
+            // Inside the hook name of `add_action('…')` and friends,
+            // offer the core action or filter hook names.
+            if matches!(string_ctx, StringContext::InStringLiteral)
+                && let Some(items) = crate::completion::context::hook_completion::complete_hook_name(
+                    &content, position,
+                )
+            {
+                return Ok(Some(CompletionResponse::Array(items)));
+            }
+
+            // ── Doctrine column type completion ─────────────────────
+            // Inside the `type:` string of `#[ORM\Column(…)]`, offer
+            // the Doctrine DBAL type names.
+            if matches!(string_ctx, StringContext::InStringLiteral)
+                && let Some(items) = self.complete_doctrine_column(&content, position, &ctx)
+            {
+                return Ok(Some(CompletionResponse::Array(items)));
+            }
+
             // ── Array shape key completion ───────────────────────────
             // Runs before `InStringLiteral` suppression because in
             // normal code `$arr['` puts the scanner inside a
@@ -397,6 +506,11 @@ impl Backend {
                 return Ok(Some(response));
             }
 
+            // An unresolvable `->` subject has nothing useful to offer.
+            if trigger.as_deref() == Some(">") {
+                return Ok(None);
+            }
+
             // ── Variable name completion ────────────────────────────
             // Placed before the interpolation guard so that `"$`
             // and `"{$` both offer variable suggestions.
@@ -404,6 +518,13 @@ impl Backend {
                 return Ok(Some(response));
             }
 
+            // A typed `$` that did not start a variable name (or a
+            // static property after `::`, handled above) has nothing
+            // else to complete.
+            if trigger.as_deref() == Some("$") {
+                return Ok(None);
+            }
+
             // Inside any interpolation context the only useful
             // completions are variable names and member access (handled
             // above).  Suppress the remaining completion strategies so
@@ -952,14 +1073,14 @@ impl Backend {
                     );
 
                     // ── Incomplete-expression retry ─────────────────
-                    // When the cursor sits right after `->` (or `?->`)
-                    // at the end of an expression with no trailing
+                    // When the cursor sits right after `->`, `?->` or
+                    // `::` at the end of an expression with no trailing
                     // semicolon (e.g. inside an arrow function body the
                     // user is still typing), the PHP parser may fail to
                     // produce the enclosing statement.  Patch the
                     // content by appending a dummy identifier +
                     // semicolon so the parser can recover.
-                    if resolved.is_empty() && target.subject.starts_with('$') {
+                    if resolved.is_empty() {
                         let patched = Self::patch_incomplete_member_access(content, position);
                         if patched != content {
                             let patched_classes: Vec<Arc<crate::types::ClassInfo>> =
@@ -1012,6 +1133,7 @@ impl Backend {
                     &class_loader,
                     &self.resolved_class_cache,
                     uri,
+                    ctx.namespace.as_deref(),
                 )
             },
         );
@@ -1561,20 +1683,15 @@ impl Backend {
 
     // ─── Shared helpers ─────────────────────────────────────────────────
 
-    /// Insert `);` at the given cursor position in `content`.
-    ///
-    /// This produces a patched version of the source that the parser can
-    /// handle when the user is in the middle of typing a function call
-    /// (e.g. `$this->greet(|` where the closing `)` hasn't been typed
-    /// yet).  Closing the call expression lets the parser recover the
-    /// surrounding class/function structure.
     /// Patch incomplete member-access expressions for parser recovery.
     ///
-    /// When the cursor is right after `->` or `?->` and the line has no
-    /// semicolon, the PHP parser may fail to recognise the enclosing
-    /// statement (e.g. an arrow function body).  This inserts a dummy
-    /// identifier and semicolon (`_x;`) at the cursor so the parser can
-    /// recover the surrounding structure.
+    /// When the cursor is right after `->`, `?->` or `::` and the line
+    /// has no semicolon, the PHP parser may fail to recognise the
+    /// enclosing statement (e.g. an arrow function body).  This inserts
+    /// a dummy identifier and semicolon (`_x;`) at the cursor so the
+    /// parser can recover the surrounding structure.  The dummy is only
+    /// ever parsed, never shown: completion items come from the resolved
+    /// subject, not from the patched member name.
     fn patch_incomplete_member_access(content: &str, position: Position) -> String {
         let line_idx = position.line as usize;
         let col = position.character as usize;
@@ -1587,11 +1704,11 @@ impl Backend {
                     .nth(col)
                     .map(|(idx, _)| idx)
                     .unwrap_or(line.len());
-                // Only patch when the cursor is right after `->` or
-                // `?->` with nothing meaningful following it.
+                // Only patch when the cursor is right after `->`,
+                // `?->` or `::` with nothing meaningful following it.
                 let before = &line[..byte_col];
                 let after = line[byte_col..].trim();
-                if (before.ends_with("->") || before.ends_with("?->")) && after.is_empty() {
+                if (before.ends_with("->") || before.ends_with("::")) && after.is_empty() {
                     result.push_str(before);
                     result.push_str("_x;");
                     result.push_str(&line[byte_col..]);
@@ -1611,6 +1728,13 @@ impl Backend {
         result
     }
 
+    /// Insert `);` at the given cursor position in `content`.
+    ///
+    /// This produces a patched version of the source that the parser can
+    /// handle when the user is in the middle of typing a function call
+    /// (e.g. `$this->greet(|` where the closing `)` hasn't been typed
+    /// yet).  Closing the call expression lets the parser recover the
+    /// surrounding class/function structure.
     fn patch_content_at_cursor(content: &str, position: Position) -> String {
         let line_idx = position.line as usize;
         let col = position.character as usize;
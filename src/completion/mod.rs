@@ -43,7 +43,15 @@
 /// - **catch_completion**: Smart exception type completion inside `catch()` clauses
 /// - **class_completion**: Class name completions (class, interface, trait, enum)
 /// - **constant_completion**: Global constant name completions
+/// - **doctrine_completion**: Doctrine DBAL type names inside the `type`
+///   string of `#[ORM\Column(…)]`, and argument names inside a docblock
+///   `@ORM\Column(…)` annotation
+/// - **format_completion**: `printf`-style format specifier completions inside
+///   the format string of `sprintf`, `printf`, `fprintf`, and `sscanf`
 /// - **function_completion**: Standalone function name completions
+/// - **hook_completion**: WordPress core action and filter hook names inside
+///   the hook name string of `add_action`, `add_filter`, `do_action`, and
+///   `apply_filters`
 /// - **namespace_completion**: Namespace declaration completions
 /// - **type_hint_completion**: Type completion inside function/method parameter lists,
 ///   return types, and property declarations (offers native PHP types + class names)
@@ -91,12 +91,14 @@ const TYPE_TAGS: &[&str] = &[
     "property",
     "property-read",
     "property-write",
+    "method",
     "mixin",
     "extends",
     "implements",
     "use",
     "phpstan-param",
     "phpstan-return",
+    "phpstan-var",
     "phpstan-self-out",
     "phpstan-this-out",
     "phpstan-assert",
@@ -106,6 +108,7 @@ const TYPE_TAGS: &[&str] = &[
     "phpstan-require-implements",
     "psalm-param",
     "psalm-return",
+    "psalm-var",
 ];
 
 /// Tags where a `$variable` follows the type (second argument).
@@ -115,6 +115,12 @@ impl Backend {
             return item;
         };
 
+        let cache_key = data_value.to_string();
+        if let Some(documentation) = self.completion_resolve_cache.lock().get(&cache_key) {
+            item.documentation = documentation.clone();
+            return item;
+        }
+
         let ctx = self.file_context(&data.uri);
         let content = self.get_file_content(&data.uri).unwrap_or_default();
 
@@ -142,6 +148,9 @@ impl Backend {
             _ => {}
         }
 
+        self.completion_resolve_cache
+            .lock()
+            .insert(cache_key, item.documentation.clone());
         item
     }
 
@@ -1036,22 +1036,47 @@ pub(crate) fn find_uncaught_throw_types_with_context(
         Some(b) => b,
         None => return Vec::new(),
     };
+    let signature = if ctx.is_some() {
+        extract_function_signature(content, position)
+    } else {
+        String::new()
+    };
 
-    let throws = find_throw_statements(&body);
-    let throw_expr_types = find_throw_expression_types(&body, content);
-    let propagated = find_propagated_throws(&body, content);
-    let catches = find_catch_blocks(&body);
-    let throw_vars = find_throw_variable_types(&body, &catches);
+    find_uncaught_throws_in_body(&body, &signature, content, ctx)
+        .into_iter()
+        .map(|info| info.type_name)
+        .collect()
+}
+
+/// Like [`find_uncaught_throw_types_with_context`] but operates on an
+/// already-extracted function body and signature (the text between
+/// `function` and the opening `{`).
+///
+/// Returns one [`ThrowInfo`] per uncaught exception type.  The type is
+/// resolved to its FQN and the offset (relative to `body`) points at the
+/// first occurrence that was found to be uncaught.  Used by the
+/// uncaught-exception diagnostic, which needs the offsets to place
+/// diagnostics and already knows the body span from the AST.
+pub(crate) fn find_uncaught_throws_in_body(
+    body: &str,
+    signature: &str,
+    content: &str,
+    ctx: Option<&ThrowsContext<'_>>,
+) -> Vec<ThrowInfo> {
+    let throws = find_throw_statements(body);
+    let throw_expr_types = find_throw_expression_types(body, content);
+    let propagated = find_propagated_throws(body, content);
+    let catches = find_catch_blocks(body);
+    let throw_vars = find_throw_variable_types(body, &catches);
 
     // Cross-file propagated throws from all call patterns.
     let cross_file_propagated = if let Some(throws_ctx) = ctx {
-        let signature = extract_function_signature(content, position);
-        find_cross_file_propagated_throws(&body, &signature, content, throws_ctx)
+        find_cross_file_propagated_throws(body, signature, content, throws_ctx)
     } else {
         Vec::new()
     };
 
-    let mut uncaught: Vec<PhpType> = Vec::new();
+    let mut uncaught: Vec<ThrowInfo> = Vec::new();
     let mut seen = std::collections::HashSet::new();
 
     /// Check whether a throw at `offset` in the function body is caught
@@ -1094,8 +1119,11 @@ pub(crate) fn find_uncaught_throw_types_with_context(
                     if resolved_lower == "throwable" || resolved_lower == "exception" {
                         return true;
                     }
-                    // Exact FQN comparison (case-insensitive)
+                    // Exact FQN comparison (case-insensitive), or the caught
+                    // type is a parent class of the thrown exception.
                     resolved_ct.eq_ignore_ascii_case(&exc_fqn)
+                        || class_loader
+                            .is_some_and(|loader| is_subclass_of(&exc_fqn, &resolved_ct, loader))
                 })
         })
     }
@@ -1154,7 +1182,10 @@ pub(crate) fn find_uncaught_throw_types_with_context(
             )
             && seen.insert(exc_type.to_string())
         {
-            uncaught.push(exc_type);
+            uncaught.push(ThrowInfo {
+                type_name: exc_type,
+                offset: throw.offset,
+            });
         }
     }
 
@@ -1172,7 +1203,10 @@ pub(crate) fn find_uncaught_throw_types_with_context(
             )
             && seen.insert(exc_type.to_string())
         {
-            uncaught.push(exc_type);
+            uncaught.push(ThrowInfo {
+                type_name: exc_type,
+                offset: te.offset,
+            });
         }
     }
 
@@ -1190,12 +1224,15 @@ pub(crate) fn find_uncaught_throw_types_with_context(
             )
             && seen.insert(exc_type.to_string())
         {
-            uncaught.push(exc_type);
+            uncaught.push(ThrowInfo {
+                type_name: exc_type,
+                offset: prop.offset,
+            });
         }
     }
 
     // 4. Inline `/** @throws ExceptionType */` annotations in the body
-    let inline = find_inline_throws_annotations(&body);
+    let inline = find_inline_throws_annotations(body);
     for info in &inline {
         if let Some(exc_type) =
             normalize_throw_type(&info.type_name, use_map, file_namespace, class_loader)
@@ -1209,7 +1246,10 @@ pub(crate) fn find_uncaught_throw_types_with_context(
             )
             && seen.insert(exc_type.to_string())
         {
-            uncaught.push(exc_type);
+            uncaught.push(ThrowInfo {
+                type_name: exc_type,
+                offset: info.offset,
+            });
         }
     }
 
@@ -1227,7 +1267,10 @@ pub(crate) fn find_uncaught_throw_types_with_context(
             )
             && seen.insert(exc_type.to_string())
         {
-            uncaught.push(exc_type);
+            uncaught.push(ThrowInfo {
+                type_name: exc_type,
+                offset: tv.offset,
+            });
         }
     }
 
@@ -1245,7 +1288,10 @@ pub(crate) fn find_uncaught_throw_types_with_context(
             )
             && seen.insert(exc_type.to_string())
         {
-            uncaught.push(exc_type);
+            uncaught.push(ThrowInfo {
+                type_name: exc_type,
+                offset: prop.offset,
+            });
         }
     }
 
@@ -1661,6 +1707,31 @@ fn collect_method_throws(
     }
 }
 
+/// Check whether the class `class_fqn` extends `ancestor_fqn`, walking
+/// the parent chain through `class_loader`.  Names are compared
+/// case-insensitively without a leading backslash.
+pub(crate) fn is_subclass_of(
+    class_fqn: &str,
+    ancestor_fqn: &str,
+    class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+) -> bool {
+    let ancestor = ancestor_fqn.trim_start_matches('\\');
+    let mut parent = class_loader(class_fqn.trim_start_matches('\\')).and_then(|c| c.parent_class);
+    let mut depth = 0u32;
+    while let Some(name) = parent {
+        depth += 1;
+        if depth > 20 {
+            break;
+        }
+        let name = name.trim_start_matches('\\');
+        if name.eq_ignore_ascii_case(ancestor) {
+            return true;
+        }
+        parent = class_loader(name).and_then(|c| c.parent_class);
+    }
+    false
+}
+
 /// Check whether an identifier is a PHP keyword that should not be
 /// treated as a function call (e.g. `if(…)`, `foreach(…)`, `return`).
 fn is_php_keyword(ident: &str) -> bool {
@@ -824,11 +824,13 @@ fn make_class_with_throws(name: &str, methods: Vec<(&str, Vec<&str>)>) -> Arc<Cl
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: Vec::new(),
             template_param_bounds: Default::default(),
             template_bindings: Vec::new(),
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: false,
             type_assertions: Vec::new(),
             throws: throws.into_iter().map(PhpType::parse).collect(),
@@ -860,6 +862,7 @@ fn make_class_with_throws(name: &str, methods: Vec<(&str, Vec<&str>)>) -> Arc<Cl
         is_abstract: false,
         deprecation_message: None,
         deprecated_replacement: None,
+        is_internal: false,
         links: Vec::new(),
         see_refs: Vec::new(),
         template_params: Vec::new(),
@@ -7,6 +7,8 @@
 ///   - `if ($var instanceof ClassName)` — narrows inside the then-body
 ///   - `if (!$var instanceof ClassName)` — narrows inside the else-body
 ///   - `is_a($var, ClassName::class)` — equivalent to instanceof
+///   - `is_subclass_of($var, ClassName::class)` — treated like `is_a`
+///     when true; a false result excludes nothing
 ///   - `get_class($var) === ClassName::class` — exact class identity check
 ///   - `$var::class === ClassName::class` — exact class identity check
 ///   - `assert($var instanceof ClassName)` — unconditional narrowing
@@ -24,6 +26,10 @@
 ///     union type, preserving generic element types from PHPDoc.
 ///   - `is_string($var)`, `is_int($var)`, `is_bool($var)`, etc. —
 ///     narrows to the corresponding scalar type.
+///   - `switch ($var) { case 'a': … }` — narrows a literal union or an
+///     enum union to the values named by the case labels.
+///   - `match ($var) { Status::Active => … }` — narrows the subject per
+///     arm, using the same rules as `switch` case labels.
 use std::sync::Arc;
 
 use crate::atom::Atom;
@@ -192,10 +198,10 @@ pub(in crate::completion) fn try_apply_instanceof_narrowing(
 
     if let Some(mut extraction) = try_extract_instanceof_with_negation(condition, ctx.var_name) {
         resolve_extraction_to_fqn(&mut extraction, ctx.class_loader);
-        if extraction.negated {
-            apply_instanceof_exclusion(&extraction.class_type, ctx, results);
-        } else {
+        if !extraction.negated {
             apply_instanceof_inclusion(&extraction.class_type, extraction.exact, ctx, results);
+        } else if !extraction.positive_only {
+            apply_instanceof_exclusion(&extraction.class_type, ctx, results);
         }
     }
 }
@@ -237,7 +243,7 @@ pub(in crate::completion) fn try_apply_instanceof_narrowing_inverse(
         // negated condition → include in else.
         if extraction.negated {
             apply_instanceof_inclusion(&extraction.class_type, extraction.exact, ctx, results);
-        } else {
+        } else if !extraction.positive_only {
             apply_instanceof_exclusion(&extraction.class_type, ctx, results);
         }
     }
@@ -419,6 +425,8 @@ pub(in crate::completion) fn try_extract_instanceof<'b>(
 ///
 /// Also handles:
 ///   - `is_a($var, ClassName::class)` — treated as equivalent to instanceof
+///   - `is_subclass_of($var, ClassName::class)` — treated like `is_a`,
+///     but only ever narrows positively (see `positive_only`)
 ///   - `get_class($var) === ClassName::class` or `==` — exact class match
 ///   - `$var::class === ClassName::class` or `==` — exact class match
 ///
@@ -431,11 +439,15 @@ pub(in crate::completion) fn try_extract_instanceof<'b>(
 ///   `$x::class === Foo::class`) where subclasses should NOT be preserved.
 ///   `false` for `instanceof` / `is_a()` checks where a more-specific subtype
 ///   in the current results should be kept.
+/// - `positive_only`: `true` when a failed check says nothing about the
+///   class, so the type must not be excluded (`is_subclass_of($x, Foo::class)`
+///   is false for a `Foo` instance).
 pub(in crate::completion) struct InstanceofExtraction {
     /// The narrowed type (e.g. `PhpType::Named("ClassName".into())`).
     pub class_type: PhpType,
     pub negated: bool,
     pub exact: bool,
+    pub positive_only: bool,
 }
 
 pub(in crate::completion) fn try_extract_instanceof_with_negation<'b>(
@@ -461,13 +473,18 @@ pub(in crate::completion) fn try_extract_instanceof_with_negation<'b>(
                     class_type: cls_type,
                     negated: false,
                     exact: false,
+                    positive_only: false,
                 })
                 .or_else(|| {
-                    // `is_a($var, ClassName::class)` — equivalent to instanceof
-                    try_extract_is_a(expr, var_name).map(|cls_type| InstanceofExtraction {
-                        class_type: cls_type,
-                        negated: false,
-                        exact: false,
+                    // `is_a($var, ClassName::class)` /
+                    // `is_subclass_of(…)` — equivalent to instanceof
+                    try_extract_is_a(expr, var_name).map(|(cls_type, positive_only)| {
+                        InstanceofExtraction {
+                            class_type: cls_type,
+                            negated: false,
+                            exact: false,
+                            positive_only,
+                        }
                     })
                 })
                 .or_else(|| {
@@ -478,6 +495,7 @@ pub(in crate::completion) fn try_extract_instanceof_with_negation<'b>(
                             class_type: cls_type,
                             negated: neg,
                             exact: true,
+                            positive_only: false,
                         }
                     })
                 })
@@ -488,8 +506,16 @@ pub(in crate::completion) fn try_extract_instanceof_with_negation<'b>(
 /// Detect `is_a($var, ClassName::class)` — semantically equivalent to
 /// `$var instanceof ClassName`.
 ///
-/// Returns the class name if the pattern matches.
-fn try_extract_is_a<'b>(expr: &'b Expression<'b>, var_name: &str) -> Option<PhpType> {
+/// `is_subclass_of($var, ClassName::class)` is accepted too: it only
+/// differs in rejecting `ClassName` itself, and any subclass still has
+/// `ClassName`'s members.  Because a `ClassName` instance fails the
+/// check, it is reported as positive-only so that a false result never
+/// excludes `ClassName`.  The class may also be given as a string
+/// literal (`'App\\Foo'`), which PHP always treats as fully qualified.
+///
+/// Returns the class name and the positive-only flag if the pattern
+/// matches.
+fn try_extract_is_a<'b>(expr: &'b Expression<'b>, var_name: &str) -> Option<(PhpType, bool)> {
     let expr = match expr {
         Expression::Parenthesized(inner) => inner.expression,
         other => other,
@@ -499,7 +525,7 @@ fn try_extract_is_a<'b>(expr: &'b Expression<'b>, var_name: &str) -> Option<PhpT
             Expression::Identifier(ident) => ident.value(),
             _ => return None,
         };
-        if func_name != "is_a" {
+        if !matches!(func_name, "is_a" | "is_subclass_of") {
             return None;
         }
         let args: Vec<_> = func_call.argument_list.arguments.iter().collect();
@@ -523,12 +549,38 @@ fn try_extract_is_a<'b>(expr: &'b Expression<'b>, var_name: &str) -> Option<PhpT
             Argument::Positional(pos) => pos.value,
             Argument::Named(named) => named.value,
         };
-        extract_class_string_from_expr(second_expr).map(PhpType::Named)
+        let positive_only = func_name == "is_subclass_of";
+        extract_class_string_from_expr(second_expr)
+            .or_else(|| class_name_from_string_literal(second_expr))
+            .map(|name| (PhpType::Named(name), positive_only))
     } else {
         None
     }
 }
 
+/// Read a class name given as a string literal (`'App\\Foo'`).
+///
+/// The result carries a leading `\` because a string class name is
+/// never resolved against the file's imports.
+fn class_name_from_string_literal(expr: &Expression<'_>) -> Option<String> {
+    use mago_syntax::ast::Literal;
+    let Expression::Literal(Literal::String(s)) = expr else {
+        return None;
+    };
+    let name = s.value.map(|v| v.to_string()).unwrap_or_else(|| {
+        crate::util::unquote_php_string(s.raw)
+            .unwrap_or(s.raw)
+            .to_string()
+    });
+    // Single-quoted `'App\\Foo'` and `'App\Foo'` both mean `App\Foo`.
+    let name = name.replace("\\\\", "\\");
+    let name = name.trim_start_matches('\\');
+    if name.is_empty() {
+        return None;
+    }
+    Some(format!("\\{name}"))
+}
+
 /// Detect `get_class($var) === ClassName::class` (or `==`) and
 /// `$var::class === ClassName::class` (or `==`).
 ///
@@ -908,10 +960,10 @@ pub(in crate::completion) fn try_apply_assert_instanceof_narrowing(
 
     if let Some(mut extraction) = try_extract_assert_instanceof(expr, ctx.var_name) {
         resolve_extraction_to_fqn(&mut extraction, ctx.class_loader);
-        if extraction.negated {
-            apply_instanceof_exclusion(&extraction.class_type, ctx, results);
-        } else {
+        if !extraction.negated {
             apply_instanceof_inclusion(&extraction.class_type, extraction.exact, ctx, results);
+        } else if !extraction.positive_only {
+            apply_instanceof_exclusion(&extraction.class_type, ctx, results);
         }
     }
 }
@@ -1154,7 +1206,7 @@ pub(in crate::completion) fn apply_guard_clause_narrowing(
         // Negated instanceof + exit → include after (var IS that class)
         if extraction.negated {
             apply_instanceof_inclusion(&extraction.class_type, extraction.exact, ctx, results);
-        } else {
+        } else if !extraction.positive_only {
             apply_instanceof_exclusion(&extraction.class_type, ctx, results);
         }
     }
@@ -1379,6 +1431,7 @@ fn collect_negated_and_instanceof_classes<'b>(
             // Each leaf must be a negated instanceof for the target variable.
             if let Some(extraction) = try_extract_instanceof_with_negation(expr, var_name)
                 && extraction.negated
+                && !extraction.positive_only
             {
                 if !out.contains(&extraction.class_type) {
                     out.push(extraction.class_type);
@@ -1712,3 +1765,160 @@ fn expand_pseudo_type_for_guard(ty: &PhpType) -> Option<PhpType> {
         _ => None,
     }
 }
+
+// ─── switch-case narrowing ──────────────────────────────────────────────────
+
+/// A `case` label value that switch-case narrowing understands.
+enum SwitchCaseLabel {
+    /// A string or integer literal, without quotes (`'active'` → `active`).
+    Literal(String),
+    /// An enum case constant; holds the short enum name (`Status::Active`
+    /// → `Status`).
+    EnumCase(String),
+}
+
+/// Narrow `results` to the union members that a `switch` case group can
+/// match.
+///
+/// `case_values` are the expressions of every `case` label in one
+/// fall-through group (or the conditions of one `match` arm).  String and integer literals keep the matching
+/// literal members (`'active'|'inactive'` → `'active'`), and
+/// `Enum::Case` constants keep the members naming that enum
+/// (`Status|Suit|null` → `Status`).  The types are left unchanged when
+/// any label is some other expression, when a member could equal a
+/// label without being spelled out (e.g. plain `string` or `mixed`), or
+/// when nothing would be left.
+pub(in crate::completion) fn apply_switch_case_narrowing(
+    case_values: &[&Expression<'_>],
+    results: &mut Vec<ResolvedType>,
+) {
+    let Some(labels) = case_values
+        .iter()
+        .map(|expr| switch_case_label(expr))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    if labels.is_empty() {
+        return;
+    }
+
+    // The members each entry keeps.  A union parameter such as
+    // `Status|Suit` resolves to one entry per class, so an entry can
+    // be dropped entirely as well as narrowed.
+    let mut kept_per_entry = Vec::with_capacity(results.len());
+    for rt in results.iter() {
+        let members: Vec<PhpType> = match &rt.type_string {
+            PhpType::Union(members) => members.clone(),
+            PhpType::Nullable(inner) => vec![inner.as_ref().clone(), PhpType::null()],
+            other => vec![other.clone()],
+        };
+
+        let mut kept = Vec::new();
+        for member in &members {
+            let mut matched = false;
+            for label in &labels {
+                match switch_label_matches(member, label) {
+                    Some(true) => matched = true,
+                    Some(false) => {}
+                    None => return,
+                }
+            }
+            if matched {
+                kept.push(member.clone());
+            }
+        }
+        let unchanged = kept.len() == members.len();
+        kept_per_entry.push((kept, unchanged));
+    }
+
+    if kept_per_entry.iter().all(|(kept, _)| kept.is_empty()) {
+        return;
+    }
+
+    let mut kept_iter = kept_per_entry.into_iter();
+    results.retain_mut(|rt| {
+        let (kept, unchanged) = kept_iter.next().unwrap();
+        if kept.is_empty() {
+            return false;
+        }
+        if !unchanged {
+            let narrowed = if kept.len() == 1 {
+                kept.into_iter().next().unwrap()
+            } else {
+                PhpType::Union(kept)
+            };
+            rt.replace_type(narrowed);
+        }
+        true
+    });
+}
+
+/// Classify a `case` label expression.
+fn switch_case_label(expr: &Expression<'_>) -> Option<SwitchCaseLabel> {
+    match expr {
+        Expression::Parenthesized(inner) => switch_case_label(inner.expression),
+        Expression::Literal(Literal::String(lit)) => Some(SwitchCaseLabel::Literal(
+            lit.value
+                .as_ref()
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| unquote_literal(lit.raw).to_string()),
+        )),
+        Expression::Literal(Literal::Integer(lit)) => {
+            Some(SwitchCaseLabel::Literal(lit.raw.to_string()))
+        }
+        Expression::Access(Access::ClassConstant(cca)) => {
+            let Expression::Identifier(ident) = cca.class else {
+                return None;
+            };
+            let name = ident.value();
+            let short = name.rsplit('\\').next().unwrap_or(name);
+            Some(SwitchCaseLabel::EnumCase(short.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Whether a union member can equal a `case` label.
+///
+/// Returns `None` when the answer is unknown (the member is a broad
+/// type such as `string`, `int`, or `mixed` that may hold the label's
+/// value without naming it), which disables narrowing.
+fn switch_label_matches(member: &PhpType, label: &SwitchCaseLabel) -> Option<bool> {
+    if member.is_null() {
+        return match label {
+            // `null == ''` and `null == 0` under loose comparison.
+            SwitchCaseLabel::Literal(v) if v.is_empty() || v == "0" => None,
+            _ => Some(false),
+        };
+    }
+    match (member, label) {
+        (PhpType::Literal(lit), SwitchCaseLabel::Literal(v)) => Some(unquote_literal(lit) == v),
+        // Enum cases are objects and never loosely equal a scalar.
+        (PhpType::Literal(_), SwitchCaseLabel::EnumCase(_)) => Some(false),
+        (PhpType::Named(name), SwitchCaseLabel::EnumCase(enum_name)) => {
+            if member.is_mixed() || name.eq_ignore_ascii_case("object") {
+                return None;
+            }
+            if member.is_scalar() {
+                return Some(false);
+            }
+            let short = name.rsplit('\\').next().unwrap_or(name);
+            Some(short.eq_ignore_ascii_case(enum_name))
+        }
+        (PhpType::Named(_), SwitchCaseLabel::Literal(_))
+            if !member.is_scalar() && !member.is_mixed() =>
+        {
+            Some(false)
+        }
+        _ => None,
+    }
+}
+
+/// Strip matching single or double quotes from a literal.
+fn unquote_literal(raw: &str) -> &str {
+    raw.strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| raw.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+        .unwrap_or(raw)
+}
@@ -26,10 +26,12 @@ impl Backend {
         "$_FILES",
         "$_ENV",
         "$GLOBALS",
-        "$argc",
-        "$argv",
     ];
 
+    /// Variables PHP defines for command-line scripts, offered only in
+    /// files that look like scripts (see [`is_cli_script`]).
+    const CLI_GLOBALS: &'static [&'static str] = &["$argc", "$argv"];
+
     /// Maximum number of variable completions to return.
     const MAX_VARIABLE_COMPLETIONS: usize = 100;
 
@@ -112,7 +114,7 @@ impl Backend {
     ///   - Variables from unrelated classes/methods are excluded.
     ///
     /// Additionally, PHP superglobals (`$_GET`, `$_POST`, …) are always
-    /// offered.
+    /// offered, and `$argc` / `$argv` are offered in CLI scripts.
     ///
     /// The prefix must include the `$` (e.g. `"$us"`).
     /// Returns `(items, is_incomplete)`.
@@ -187,6 +189,27 @@ impl Backend {
             });
         }
 
+        // ── 3. CLI globals ──────────────────────────────────────────
+        if is_cli_script(content) {
+            for &name in Self::CLI_GLOBALS {
+                if !name.starts_with(&prefix_lower) || !seen.insert(name.to_string()) {
+                    continue;
+                }
+                items.push(CompletionItem {
+                    label: name.to_string(),
+                    kind: Some(CompletionItemKind::VARIABLE),
+                    detail: Some("PHP CLI global".to_string()),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range: replace_range,
+                        new_text: name.to_string(),
+                    })),
+                    filter_text: Some(name.to_string()),
+                    sort_text: Some(format!("z_{}", name)),
+                    ..CompletionItem::default()
+                });
+            }
+        }
+
         let is_incomplete = items.len() > Self::MAX_VARIABLE_COMPLETIONS;
         if is_incomplete {
             items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
@@ -197,6 +220,23 @@ impl Backend {
     }
 }
 
+/// Whether the file looks like a command-line script: it declares no
+/// namespace and no class-like types, only top-level code and functions.
+fn is_cli_script(content: &str) -> bool {
+    with_parsed_program(content, "is_cli_script", |program, _content| {
+        !program.statements.iter().any(|stmt| {
+            matches!(
+                stmt,
+                Statement::Namespace(_)
+                    | Statement::Class(_)
+                    | Statement::Interface(_)
+                    | Statement::Trait(_)
+                    | Statement::Enum(_)
+            )
+        })
+    })
+}
+
 // ─── Scope-aware variable collector ─────────────────────────────────────────
 
 /// Collect all variable names visible at `cursor_offset` by parsing the
@@ -1567,8 +1567,9 @@ fn walk_top_level_statements<'a, 'b: 'a>(
 
     let mut top_level_scope = ScopeState::new();
 
-    // Seed superglobals for top-level code.
+    // Seed superglobals and CLI globals for top-level code.
     seed_superglobals(&mut top_level_scope);
+    seed_cli_globals(&mut top_level_scope);
 
     for stmt in statements {
         match stmt {
@@ -2341,6 +2342,7 @@ pub(crate) fn resolve_in_method_body<'b>(
             has_scope_attr,
             &full_ctx,
         );
+        seed_superglobals(&mut scope);
 
         // Record the scope at the method body start.
         record_scope_snapshot(method_span_start, &scope);
@@ -2379,6 +2381,9 @@ pub(crate) fn resolve_in_method_body<'b>(
         ctx,
     );
 
+    // Seed superglobals so that `$_GET`, `$_POST`, etc. resolve.
+    seed_superglobals(&mut scope);
+
     // Walk the body forward.
     walk_body_forward(stmts_vec.iter().copied(), &mut scope, ctx);
 
@@ -2460,6 +2465,9 @@ pub(crate) fn resolve_in_function_body<'b>(
         ctx,
     );
 
+    // Seed superglobals so that `$_GET`, `$_POST`, etc. resolve.
+    seed_superglobals(&mut scope);
+
     // Walk the body forward.
     walk_body_forward(func.body.statements.iter(), &mut scope, ctx);
 
@@ -2497,6 +2505,7 @@ pub(crate) fn resolve_in_top_level<'b>(
 
     // Seed superglobals so that `$_GET`, `$_POST`, etc. resolve.
     seed_superglobals(&mut scope);
+    seed_cli_globals(&mut scope);
 
     // Walk the top-level statements forward.
     walk_body_forward(statements, &mut scope, ctx);
@@ -2520,6 +2529,7 @@ pub(crate) fn walk_top_level_for_globals<'b>(
     ctx: &ForwardWalkCtx<'_>,
 ) {
     seed_superglobals(scope);
+    seed_cli_globals(scope);
     walk_body_forward(statements, scope, ctx);
 }
 
@@ -2983,10 +2993,21 @@ fn process_statement<'b>(
             walk_body_forward(ns.statements().iter(), scope, ctx);
         }
         Statement::Global(global) => {
+            let global_offset = global.span().start.offset as usize;
             for var in global.variables.iter() {
                 if let Variable::Direct(dv) = var {
                     let var_name = dv.name.to_string();
-                    if let Some(top_scope) = &ctx.top_level_scope {
+                    // An explicit `@global Type $var` annotation (inline or
+                    // on the enclosing function) wins over the top-level
+                    // assignment scan.
+                    if let Some(php_type) = crate::docblock::find_global_type_in_source(
+                        ctx.content,
+                        global_offset,
+                        &var_name,
+                    ) {
+                        let resolved = resolve_type_to_resolved_types(&php_type, ctx);
+                        scope.set(&var_name, resolved);
+                    } else if let Some(top_scope) = &ctx.top_level_scope {
                         if let Some(types) = top_scope.get(&atom(&var_name)) {
                             scope.set(&var_name, types.clone());
                         } else {
@@ -2998,6 +3019,34 @@ fn process_statement<'b>(
                 }
             }
         }
+        Statement::Static(static_stmt) => {
+            // `static $x = init;` — the variable starts out as the
+            // initializer's type.  A preceding `/** @var Type $x */`
+            // (common for memoisation: `static $cache = null;`) wins.
+            let inline_var = crate::docblock::find_inline_var_docblock(
+                ctx.content,
+                static_stmt.span().start.offset as usize,
+            );
+            for item in static_stmt.items.iter() {
+                let var_name = item.variable().name.to_string();
+                if let Some((php_type, doc_name)) = &inline_var
+                    && doc_name.as_deref().is_none_or(|n| n == var_name)
+                {
+                    let resolved = resolve_type_to_resolved_types(php_type, ctx);
+                    scope.set(&var_name, resolved);
+                    continue;
+                }
+                let types = item
+                    .value()
+                    .map(|init| resolve_rhs_with_scope(init, scope, ctx))
+                    .unwrap_or_default();
+                if types.is_empty() {
+                    scope.set_empty(&var_name);
+                } else {
+                    scope.set(&var_name, types);
+                }
+            }
+        }
         Statement::Return(ret) => {
             if let Some(val) = ret.value {
                 // Record `&&` chain snapshots so that member accesses
@@ -3334,15 +3383,30 @@ fn record_match_ternary_snapshots<'b>(
                 record_match_ternary_snapshots(elem_expr, scope, ctx);
             }
         }
-        // Match expressions where the subject is NOT `true` — just
-        // recurse into arm expressions.
+        // Match expressions where the subject is NOT `true` — narrow
+        // the subject variable per arm, then recurse into the arms.
         Expression::Match(match_expr) => {
             for arm in match_expr.arms.iter() {
-                let arm_expr = match arm {
-                    MatchArm::Expression(e) => e.expression,
-                    MatchArm::Default(d) => d.expression,
-                };
-                record_match_ternary_snapshots(arm_expr, scope, ctx);
+                match arm {
+                    MatchArm::Expression(expr_arm) => {
+                        let mut arm_scope = scope.clone();
+                        if apply_match_subject_narrowing(
+                            match_expr.expression,
+                            expr_arm,
+                            &mut arm_scope,
+                        ) {
+                            record_scope_snapshot(
+                                expr_arm.expression.span().start.offset,
+                                &arm_scope,
+                            );
+                            record_scope_snapshot_recursive(expr_arm.expression, &arm_scope);
+                        }
+                        record_match_ternary_snapshots(expr_arm.expression, &arm_scope, ctx);
+                    }
+                    MatchArm::Default(def_arm) => {
+                        record_match_ternary_snapshots(def_arm.expression, scope, ctx);
+                    }
+                }
             }
         }
         _ => {}
@@ -3506,20 +3570,54 @@ fn apply_cursor_ternary_narrowing<'b>(
             apply_cursor_ternary_narrowing(bin.lhs, scope, ctx);
             apply_cursor_ternary_narrowing(bin.rhs, scope, ctx);
         }
-        // Non-`true` match expressions — recurse into arms.
+        // Non-`true` match expressions — narrow the subject variable
+        // to the arm's conditions, then recurse into the arm body.
         Expression::Match(match_expr) => {
             for arm in match_expr.arms.iter() {
-                let arm_expr = match arm {
-                    MatchArm::Expression(e) => e.expression,
-                    MatchArm::Default(d) => d.expression,
-                };
-                apply_cursor_ternary_narrowing(arm_expr, scope, ctx);
+                match arm {
+                    MatchArm::Expression(expr_arm) => {
+                        let arm_span = expr_arm.expression.span();
+                        if cursor >= arm_span.start.offset && cursor <= arm_span.end.offset {
+                            apply_match_subject_narrowing(match_expr.expression, expr_arm, scope);
+                            apply_cursor_ternary_narrowing(expr_arm.expression, scope, ctx);
+                            return;
+                        }
+                    }
+                    MatchArm::Default(def_arm) => {
+                        apply_cursor_ternary_narrowing(def_arm.expression, scope, ctx);
+                    }
+                }
             }
         }
         _ => {}
     }
 }
 
+/// Narrow the subject of `match ($var) { … }` inside one arm.
+///
+/// The arm conditions are compared against the subject the same way
+/// `switch` case labels are, so `Status::Active, Status::Pending =>`
+/// keeps only the `Status` members of `Status|Suit|null` and
+/// `'a' =>` keeps only `'a'` of `'a'|'b'`.  Returns `true` when the
+/// subject is a plain variable with a known type.
+fn apply_match_subject_narrowing<'b>(
+    subject: &'b Expression<'b>,
+    arm: &'b MatchExpressionArm<'b>,
+    scope: &mut ScopeState,
+) -> bool {
+    let Expression::Variable(Variable::Direct(dv)) = subject else {
+        return false;
+    };
+    let mut results = scope.get(dv.name).to_vec();
+    if results.is_empty() {
+        return false;
+    }
+    let conditions: Vec<&Expression<'b>> = arm.conditions.iter().copied().collect();
+    narrowing::apply_switch_case_narrowing(&conditions, &mut results);
+    scope.set(dv.name, results);
+    true
+}
+
 /// Record intermediate scope snapshots within `&&` chains.
 ///
 /// When the diagnostic scope cache is active and an expression contains
@@ -5005,12 +5103,13 @@ fn process_pass_by_ref<'b>(
 }
 
 /// Seed PHP superglobals (`$_SERVER`, `$_GET`, `$_POST`, etc.) into the
-/// scope as `array` so that accesses on them resolve correctly.
-/// PHP makes these available in every scope without
-/// an explicit `global` declaration.
+/// scope as `array<string, mixed>` so that accesses on them resolve
+/// correctly and element reads like `$_SERVER['REQUEST_URI']` type as
+/// `mixed`.  PHP makes these available in every scope without an
+/// explicit `global` declaration.
 fn seed_superglobals(scope: &mut ScopeState) {
-    let array_type = vec![ResolvedType::from_type_string(PhpType::Named(
-        "array".to_string(),
+    let array_type = vec![ResolvedType::from_type_string(PhpType::parse(
+        "array<string, mixed>",
     ))];
     for name in [
         "$_SERVER",
@@ -5027,6 +5126,22 @@ fn seed_superglobals(scope: &mut ScopeState) {
     }
 }
 
+/// Seed the command-line globals `$argv` (`array<int, string>`) and
+/// `$argc` (`int`) into a top-level scope.  Unlike superglobals they are
+/// not visible inside functions, so only top-level walks call this.
+fn seed_cli_globals(scope: &mut ScopeState) {
+    scope.set(
+        "$argv",
+        vec![ResolvedType::from_type_string(PhpType::parse(
+            "array<int, string>",
+        ))],
+    );
+    scope.set(
+        "$argc",
+        vec![ResolvedType::from_type_string(PhpType::parse("int"))],
+    );
+}
+
 /// Recursively walk an expression tree to find function call
 /// sub-expressions and seed pass-by-reference primitive types for each.
 /// This handles patterns like `if (preg_match($pattern, $subject, $matches))`
@@ -6124,12 +6239,21 @@ fn process_foreach<'b>(foreach: &'b Foreach<'b>, scope: &mut ScopeState, ctx: &F
     //
     // We extract all variables referenced in the foreach expression and
     // check for @var annotations for each one.
+    //
+    // An annotation attached to the foreach itself (directly above it, or
+    // inline before the iterable) names the iterable's type for this loop,
+    // so it replaces whatever type the variable already has.
     let foreach_offset = foreach.foreach.span().start.offset as usize;
     if let Expression::Variable(Variable::Direct(dv)) = foreach.expression {
-        let var_name = format!("${}", dv.name);
-        if scope.get(dv.name).is_empty()
+        if let Some(var_type) = attached_iterable_var_type(foreach, dv.name, ctx.content) {
+            let resolved = resolve_type_to_resolved_types(
+                &crate::util::resolve_php_type_names(&var_type, ctx.class_loader),
+                ctx,
+            );
+            scope.set(dv.name, resolved);
+        } else if scope.get(dv.name).is_empty()
             && let Some(var_type) =
-                crate::docblock::find_var_raw_type_in_source(ctx.content, foreach_offset, &var_name)
+                crate::docblock::find_var_raw_type_in_source(ctx.content, foreach_offset, dv.name)
         {
             let resolved = resolve_type_to_resolved_types(
                 &crate::util::resolve_php_type_names(&var_type, ctx.class_loader),
@@ -6439,6 +6563,35 @@ fn resolve_foreach_iterable_type<'b>(
     None
 }
 
+/// Find a `/** @var Type $var */` annotation attached to a foreach whose
+/// iterable is the bare variable `var_name` (including its `$`).
+///
+/// The annotation is either directly above the `foreach` keyword or
+/// inline before the iterable (`foreach (/** @var User[] $users */ $users
+/// as $user)`), and must name the iterable variable.  Annotations naming
+/// the loop variable are handled when the value is bound.
+fn attached_iterable_var_type(
+    foreach: &Foreach<'_>,
+    var_name: &str,
+    content: &str,
+) -> Option<PhpType> {
+    let keyword_end = foreach.foreach.span().end.offset as usize;
+    let expr_start = foreach.expression.span().start.offset as usize;
+    let inline = content
+        .get(keyword_end..expr_start)
+        .map(|text| text.trim().trim_start_matches('(').trim_start())
+        .filter(|text| text.starts_with("/**") && text.ends_with("*/"))
+        .and_then(crate::docblock::extract_var_type_with_name);
+
+    let (php_type, doc_name) = inline.or_else(|| {
+        crate::docblock::find_inline_var_docblock(
+            content,
+            foreach.foreach.span().start.offset as usize,
+        )
+    })?;
+    (doc_name.as_deref() == Some(var_name)).then_some(php_type)
+}
+
 /// Resolve a foreach expression to a `PhpType` by treating it as a
 /// subject string and going through the full resolver pipeline.
 ///
@@ -7311,11 +7464,24 @@ fn process_switch<'b>(switch: &'b Switch<'b>, scope: &mut ScopeState, ctx: &Forw
     let mut branch_scopes: Vec<ScopeState> = Vec::new();
     let mut has_default = false;
 
+    // `switch ($var)` narrows `$var` inside each case group to the
+    // values named by its labels.
+    let subject_var = match switch.expression {
+        Expression::Variable(Variable::Direct(dv)) => Some(dv.name.to_string()),
+        _ => None,
+    };
+
     // Walk cases, accumulating fall-through groups.
     let mut accumulated_stmts: Vec<&Statement<'b>> = Vec::new();
+    let mut group_labels: Vec<&Expression<'b>> = Vec::new();
+    let mut group_has_default = false;
     for case in &cases {
         if case.is_default() {
             has_default = true;
+            group_has_default = true;
+        }
+        if let Some(label) = case.expression() {
+            group_labels.push(label);
         }
 
         let stmts: Vec<_> = case.statements().iter().collect();
@@ -7327,9 +7493,29 @@ fn process_switch<'b>(switch: &'b Switch<'b>, scope: &mut ScopeState, ctx: &Forw
         accumulated_stmts.extend(stmts);
 
         let mut case_scope = pre_switch_scope.clone();
+        if let Some(var_name) = &subject_var
+            && !group_has_default
+        {
+            let mut results = case_scope.get(var_name).to_vec();
+            if !results.is_empty() {
+                narrowing::apply_switch_case_narrowing(&group_labels, &mut results);
+                case_scope.set(var_name, results);
+            }
+        }
         walk_body_forward(accumulated_stmts.iter().copied(), &mut case_scope, ctx);
+
+        // Cursor inside this case group: the narrowed case scope is the
+        // answer, not the merge across all cases.
+        let group_start = accumulated_stmts[0].span().start.offset;
+        if ctx.cursor_offset >= group_start && ctx.cursor_offset <= case.span().end.offset {
+            *scope = case_scope;
+            return;
+        }
+
         branch_scopes.push(case_scope);
         accumulated_stmts.clear();
+        group_labels.clear();
+        group_has_default = false;
     }
 
     // Handle trailing fall-through cases (empty cases at the end).
@@ -7427,7 +7613,10 @@ fn apply_condition_narrowing<'b>(
                 narrowing::try_extract_instanceof_with_negation(operand, var_name)
             {
                 let var_ctx = build_var_ctx(var_name, ctx, &scope_resolver);
-                if extraction.negated {
+                if extraction.negated && extraction.positive_only {
+                    // `!is_subclass_of(…)` holds for the class itself,
+                    // so nothing can be excluded.
+                } else if extraction.negated {
                     // Negated instanceof: apply exclusion to the current
                     // scope immediately (each negation removes one type).
                     let mut results = scope.get(var_name).to_vec();
@@ -7675,7 +7864,7 @@ fn apply_condition_narrowing_inverse_single<'b>(
                     );
                 });
                 results.retain(|rt| !rt.type_string.is_null());
-            } else {
+            } else if !extraction.positive_only {
                 // Inverse of positive instanceof → exclusion.
                 // Exclusion does NOT strip null (`!instanceof` is
                 // true for null values).
@@ -9023,13 +9212,13 @@ fn collect_condition_var_names_inner(expr: &Expression<'_>, names: &mut Vec<Stri
             collect_condition_var_names_inner(bin.lhs, names);
             collect_condition_var_names_inner(bin.rhs, names);
         }
-        // is_a($var, ...) and get_class($var) === ...
+        // is_a($var, ...), is_subclass_of($var, ...) and get_class($var) === ...
         Expression::Call(Call::Function(func_call)) => {
             let func_name = match func_call.function {
                 Expression::Identifier(ident) => ident.value(),
                 _ => return,
             };
-            if (func_name == "is_a" || func_name == "get_class")
+            if matches!(func_name, "is_a" | "is_subclass_of" | "get_class")
                 && let Some(first_arg) = func_call.argument_list.arguments.first()
             {
                 let arg_expr = match first_arg {
@@ -1746,8 +1746,8 @@ fn resolve_rhs_array_access<'b>(
             ArrayBracketSegment::StringKey(key) => current
                 .shape_value_type(key)
                 .cloned()
-                .or_else(|| current.extract_value_type(true).cloned()),
-            ArrayBracketSegment::ElementAccess => current.extract_value_type(true).cloned(),
+                .or_else(|| current.extract_value_type(false).cloned()),
+            ArrayBracketSegment::ElementAccess => current.extract_value_type(false).cloned(),
         };
 
         if let Some(element) = extracted {
@@ -3073,9 +3073,18 @@ fn resolve_rhs_method_call_inner<'b>(
             // method's declared return type (typically `mixed`) as
             // the type hint would be misleading.  Skip it so that
             // `from_classes` uses the resolved class names instead.
+            // The same applies to Symfony container `get()` calls,
+            // which are declared `?object`.
             let has_conditional = merged
                 .get_method_ci(&method_name)
-                .is_some_and(|m| m.conditional_return.is_some());
+                .is_some_and(|m| m.conditional_return.is_some())
+                || crate::symfony::resolve_container_get(
+                    owner,
+                    &method_name,
+                    &text_args,
+                    ctx.class_loader,
+                )
+                .is_some();
             let effective_hint = if has_conditional {
                 None
             } else {
@@ -9,7 +9,12 @@
 //!
 //! Project settings override global settings.  When neither file
 //! exists, all settings use their defaults.
+//!
+//! Editor settings under the `phpantom` section are pulled separately
+//! through `workspace/configuration` (see [`ClientSettings`]).  They
+//! fill gaps the TOML file leaves open but never override it.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use etcetera::BaseStrategy as _;
@@ -21,6 +26,8 @@ use serde::Deserialize;
 pub struct Config {
     /// PHP version and language settings.
     pub php: PhpConfig,
+    /// Completion behaviour.
+    pub completion: CompletionConfig,
     /// Diagnostic toggles.
     pub diagnostics: DiagnosticsConfig,
     /// Indexing strategy and file discovery settings.
@@ -44,6 +51,28 @@ pub struct PhpConfig {
     pub version: Option<String>,
 }
 
+/// `[completion]` section — completion item behaviour.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CompletionConfig {
+    /// Insert methods, functions, and constructors as snippets with a
+    /// tab stop for each required parameter.
+    ///
+    /// On by default. When disabled, accepting a callable completion
+    /// inserts only its name, leaving the argument list to the user.
+    #[serde(rename = "use-snippets")]
+    pub use_snippets: Option<bool>,
+}
+
+impl CompletionConfig {
+    /// Whether callable completions are inserted as snippets.
+    ///
+    /// Defaults to `true` (on) when not explicitly set.
+    pub fn snippets_enabled(&self) -> bool {
+        self.use_snippets.unwrap_or(true)
+    }
+}
+
 /// `[diagnostics]` section — toggle individual diagnostic providers.
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
@@ -79,6 +108,19 @@ pub struct DiagnosticsConfig {
     /// behaviour.
     #[serde(rename = "report-magic-properties")]
     pub report_magic_properties: Option<bool>,
+
+    /// Report exceptions that escape a function or method without a
+    /// matching `@throws` tag.
+    ///
+    /// Off by default. When enabled, PHPantom emits a hint-level
+    /// diagnostic wherever an exception is thrown (directly or through
+    /// a called method's `@throws`) that is neither caught by an
+    /// enclosing `try/catch` nor declared in the docblock of the
+    /// enclosing function. Uncaught exceptions are not errors in PHP,
+    /// so this is only useful in codebases that document checked
+    /// exceptions.
+    #[serde(rename = "missing-throws")]
+    pub missing_throws: Option<bool>,
 }
 
 impl DiagnosticsConfig {
@@ -102,6 +144,13 @@ impl DiagnosticsConfig {
     pub fn report_magic_properties_enabled(&self) -> bool {
         self.report_magic_properties.unwrap_or(false)
     }
+
+    /// Whether the missing-throws diagnostic is enabled.
+    ///
+    /// Defaults to `false` (off) when not explicitly set.
+    pub fn missing_throws_enabled(&self) -> bool {
+        self.missing_throws.unwrap_or(false)
+    }
 }
 
 /// `[formatting]` section — controls the formatting strategy.
@@ -312,12 +361,30 @@ pub struct IndexingConfig {
     ///   if present, still resolves on demand, but never falls back to
     ///   self-scan.
     pub strategy: Option<IndexingStrategy>,
+
+    /// Maximum number of lazily loaded files kept in memory.
+    ///
+    /// When set, the least recently used files beyond this limit are
+    /// dropped from the index and re-parsed on their next lookup.  Files
+    /// open in the editor are never dropped.  Unlimited when not set.
+    #[serde(rename = "max-entries")]
+    pub max_entries: Option<usize>,
+
+    /// Whether workspace files are parsed on multiple threads when
+    /// find-references or workspace diagnostics first need every file.
+    /// Default: `true`.  Set to `false` to parse them one at a time.
+    pub parallel: Option<bool>,
 }
 
 impl IndexingConfig {
     pub fn strategy(&self) -> IndexingStrategy {
         self.strategy.unwrap_or_default()
     }
+
+    /// Whether workspace parsing may use multiple threads.
+    pub fn parallel(&self) -> bool {
+        self.parallel.unwrap_or(true)
+    }
 }
 
 /// The indexing strategy that controls class discovery behaviour.
@@ -372,6 +439,48 @@ impl std::fmt::Display for IndexingStrategy {
     }
 }
 
+/// Configuration section requested from the client through
+/// `workspace/configuration`.
+pub const CLIENT_SETTINGS_SECTION: &str = "phpantom";
+
+/// Editor settings for one workspace folder, pulled from the client
+/// through `workspace/configuration` (section `phpantom`).
+///
+/// Unknown keys are ignored and missing keys use their defaults, so a
+/// client that knows nothing about PHPantom yields an empty value.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClientSettings {
+    /// PHP version (e.g. `"8.2"`).  Used when `.phpantom.toml` sets no
+    /// `[php] version`.  Only read from the primary workspace folder,
+    /// since the whole workspace is analysed against one version.
+    pub php_version: Option<String>,
+    /// Extra directories to index, absolute or relative to the
+    /// workspace folder (e.g. a shared library outside the project).
+    pub include_paths: Vec<String>,
+    /// Class aliases mapping an alias FQN to the class it stands for,
+    /// for aliases registered at runtime with `class_alias()`.  When
+    /// folders disagree, the primary folder wins, then the others in
+    /// the order they were added.
+    pub aliases: HashMap<String, String>,
+}
+
+impl ClientSettings {
+    /// Parse the value the client returned for one configuration item.
+    ///
+    /// Clients answer `null` for sections they have no settings for,
+    /// and malformed values are treated the same way.
+    pub fn from_value(value: serde_json::Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+
+    /// Resolve [`include_paths`](Self::include_paths) against the
+    /// workspace folder they were configured for.
+    pub fn resolved_include_paths(&self, folder: &Path) -> Vec<PathBuf> {
+        self.include_paths.iter().map(|p| folder.join(p)).collect()
+    }
+}
+
 /// Recursively merge `overlay` into `base`.  Keys in `overlay` take
 /// precedence; sub-tables are merged recursively rather than replaced
 /// wholesale so that a project config section inherits individual
@@ -646,6 +755,42 @@ mod tests {
         assert!(!config.diagnostics.report_magic_properties_enabled());
     }
 
+    #[test]
+    fn missing_throws_defaults_to_false() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(&path, "[diagnostics]\n").unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert!(!config.diagnostics.missing_throws_enabled());
+    }
+
+    #[test]
+    fn parses_missing_throws() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(&path, "[diagnostics]\nmissing-throws = true\n").unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert!(config.diagnostics.missing_throws_enabled());
+    }
+
+    #[test]
+    fn snippets_default_to_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(&path, "[completion]\n").unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert!(config.completion.snippets_enabled());
+    }
+
+    #[test]
+    fn parses_use_snippets() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(&path, "[completion]\nuse-snippets = false\n").unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert!(!config.completion.snippets_enabled());
+    }
+
     #[test]
     fn extra_arguments_defaults_to_false() {
         let dir = tempfile::tempdir().unwrap();
@@ -855,6 +1000,27 @@ analyze-timeout = 45000
         assert_eq!(config.indexing.strategy, Some(IndexingStrategy::None));
     }
 
+    #[test]
+    fn parses_indexing_max_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(&path, "[indexing]\nmax-entries = 50000\n").unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert_eq!(config.indexing.max_entries, Some(50_000));
+    }
+
+    #[test]
+    fn indexing_parallel_defaults_to_true() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert!(config.indexing.parallel());
+
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(&path, "[indexing]\nparallel = false\n").unwrap();
+        let config = load_config(dir.path()).unwrap();
+        assert!(!config.indexing.parallel());
+    }
+
     #[test]
     fn invalid_indexing_strategy_returns_parse_error() {
         let dir = tempfile::tempdir().unwrap();
@@ -1058,4 +1224,40 @@ analyze-timeout = 45000
         let config: Config = base.try_into().unwrap();
         assert_eq!(config.indexing.strategy, Some(IndexingStrategy::SelfScan));
     }
+
+    #[test]
+    fn client_settings_from_camel_case_json() {
+        let settings = ClientSettings::from_value(serde_json::json!({
+            "phpVersion": "8.1",
+            "includePaths": ["../shared"],
+            "aliases": { "Legacy\\User": "App\\Models\\User" },
+            "somethingElse": true,
+        }));
+        assert_eq!(settings.php_version.as_deref(), Some("8.1"));
+        assert_eq!(settings.include_paths, vec!["../shared".to_string()]);
+        assert_eq!(
+            settings.aliases.get("Legacy\\User").map(String::as_str),
+            Some("App\\Models\\User")
+        );
+    }
+
+    #[test]
+    fn client_settings_from_null_is_default() {
+        assert_eq!(
+            ClientSettings::from_value(serde_json::Value::Null),
+            ClientSettings::default()
+        );
+    }
+
+    #[test]
+    fn client_settings_include_paths_resolve_against_folder() {
+        let settings = ClientSettings {
+            include_paths: vec!["lib".to_string(), "/opt/php".to_string()],
+            ..ClientSettings::default()
+        };
+        assert_eq!(
+            settings.resolved_include_paths(Path::new("/work/app")),
+            vec![PathBuf::from("/work/app/lib"), PathBuf::from("/opt/php")]
+        );
+    }
 }
@@ -806,6 +806,10 @@ impl Backend {
 
             for dir in &psr4_dirs {
                 for php_file in collect_php_files(dir, &vendor_dir_paths) {
+                    if self.is_request_cancelled() {
+                        return result;
+                    }
+
                     // Skip files already covered by the classmap (Phase 3).
                     if classmap_paths.contains(&php_file) {
                         continue;
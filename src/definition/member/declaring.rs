@@ -35,6 +35,17 @@ impl Backend {
         (member_name.to_string(), None)
     }
 
+    /// The class's used traits minus those whose `member_name` is
+    /// excluded by an `insteadof` adaptation.
+    fn traits_after_precedence(class: &ClassInfo, member_name: &str) -> Vec<Atom> {
+        class
+            .used_traits
+            .iter()
+            .filter(|t| !class.trait_method_excluded(t.as_str(), member_name))
+            .copied()
+            .collect()
+    }
+
     /// Walk up the inheritance chain to find the class that actually declares
     /// the given member and the FQN (or best-known name) used to load it.
     ///
@@ -57,10 +68,14 @@ impl Backend {
             return Some((class.clone(), fqn));
         }
 
-        // Check traits used by this class.
-        if let Some(found) =
-            Self::find_declaring_in_traits(&class.used_traits, member_name, class_loader, 0)
-        {
+        // Check traits used by this class, skipping any whose version of
+        // the member lost an `insteadof` conflict resolution.
+        if let Some(found) = Self::find_declaring_in_traits(
+            &Self::traits_after_precedence(class, member_name),
+            member_name,
+            class_loader,
+            0,
+        ) {
             return Some(found);
         }
 
@@ -79,9 +94,12 @@ impl Backend {
                 return Some((parent, parent_name.to_string()));
             }
             // Check traits used by the parent class.
-            if let Some(found) =
-                Self::find_declaring_in_traits(&parent.used_traits, member_name, class_loader, 0)
-            {
+            if let Some(found) = Self::find_declaring_in_traits(
+                &Self::traits_after_precedence(&parent, member_name),
+                member_name,
+                class_loader,
+                0,
+            ) {
                 return Some(found);
             }
             current = parent;
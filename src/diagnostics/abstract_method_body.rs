@@ -0,0 +1,159 @@
+//! Abstract method body diagnostic.
+//!
+//! Flags methods declared `abstract` that also carry a concrete body,
+//! e.g. `abstract public function foo() {}`.  The Mago parser accepts
+//! this, but PHP rejects it at compile time.  `MethodInfo` does not
+//! record whether a body was present, so the collector walks the AST
+//! directly and reports the method name.
+
+use mago_syntax::ast::*;
+use tower_lsp::lsp_types::*;
+
+use super::helpers::{for_each_class_like_members, make_diagnostic};
+use crate::Backend;
+use crate::parser::{with_parse_cache, with_parsed_program};
+
+/// Diagnostic code used for abstract methods that declare a body.
+pub(crate) const INVALID_ABSTRACT_METHOD_BODY_CODE: &str = "invalid_abstract_method_body";
+
+impl Backend {
+    /// Collect abstract-method-body diagnostics for a single file.
+    ///
+    /// Appends an Error-severity diagnostic to `out` for every method
+    /// with the `abstract` modifier and a `MethodBody::Concrete` body.
+    pub fn collect_abstract_method_body_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let _parse_guard = with_parse_cache(content);
+        with_parsed_program(content, "abstract_method_body", |program, content| {
+            let mut found: Vec<(String, String, u32, u32)> = Vec::new();
+            for_each_class_like_members(program.statements.iter(), &mut |class_name, members| {
+                collect_from_members(class_name, members, &mut found);
+            });
+
+            for (class_name, method_name, start, end) in found {
+                let Some(range) =
+                    self.offset_range_to_lsp_range(uri, content, start as usize, end as usize)
+                else {
+                    continue;
+                };
+
+                out.push(make_diagnostic(
+                    range,
+                    DiagnosticSeverity::ERROR,
+                    INVALID_ABSTRACT_METHOD_BODY_CODE,
+                    format!(
+                        "Abstract method '{}' in class '{}' cannot have a body",
+                        method_name, class_name
+                    ),
+                ));
+            }
+        });
+    }
+}
+
+/// Record `(class, method, name_start, name_end)` for each abstract
+/// method among `members` that also has a body.
+fn collect_from_members(
+    class_name: &str,
+    members: &[ClassLikeMember<'_>],
+    found: &mut Vec<(String, String, u32, u32)>,
+) {
+    for member in members.iter() {
+        if let ClassLikeMember::Method(method) = member
+            && method.modifiers.iter().any(|m| m.is_abstract())
+            && matches!(method.body, MethodBody::Concrete(_))
+        {
+            found.push((
+                class_name.to_string(),
+                method.name.value.to_string(),
+                method.name.span.start.offset,
+                method.name.span.end.offset,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, &Arc::new(php.to_string()));
+        let mut out = Vec::new();
+        backend.collect_abstract_method_body_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    #[test]
+    fn no_diagnostic_for_bodyless_abstract_method() {
+        let php = r#"<?php
+abstract class Bar {
+    abstract public function foo(): void;
+    public function baz(): void {}
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn flags_abstract_method_with_body() {
+        let php = r#"<?php
+abstract class Bar {
+    abstract public function foo(): void {}
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Abstract method 'foo' in class 'Bar' cannot have a body"
+        );
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diags[0].range.start.line, 2);
+        assert_eq!(diags[0].range.start.character, 29);
+        assert_eq!(diags[0].range.end.character, 32);
+    }
+
+    #[test]
+    fn flags_method_inside_namespace_block() {
+        let php = r#"<?php
+namespace App {
+    trait Greets {
+        abstract protected function hello(): string { return 'hi'; }
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("'hello'"));
+        assert!(diags[0].message.contains("'Greets'"));
+    }
+
+    #[test]
+    fn flags_method_of_class_declared_inside_block() {
+        let php = r#"<?php
+if (!class_exists('Legacy')) {
+    abstract class Legacy {
+        abstract public function run(): void {}
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("'run'"));
+        assert!(diags[0].message.contains("'Legacy'"));
+    }
+}
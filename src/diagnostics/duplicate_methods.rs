@@ -0,0 +1,178 @@
+//! Duplicate method diagnostic.
+//!
+//! Flags a method that is declared more than once in the same class,
+//! interface, trait, or enum.  The parser keeps every declaration in
+//! `ClassInfo::methods`, so the check is a case-insensitive name scan
+//! over the classes already stored in the `ast_map`.  The diagnostic is
+//! placed on the name of each repeated declaration; the first one is
+//! left alone.
+
+use std::collections::HashSet;
+
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::types::{ClassInfo, ClassLikeKind};
+
+use super::helpers::make_diagnostic;
+
+/// Diagnostic code used for repeated method declarations.
+pub(crate) const DUPLICATE_METHOD_CODE: &str = "duplicate_method_declaration";
+
+impl Backend {
+    /// Collect duplicate-method diagnostics for a single file.
+    ///
+    /// Appends a Warning-severity diagnostic to `out` for every method
+    /// whose name was already declared earlier in the same class-like.
+    /// No type resolution is needed, so this runs in the fast phase.
+    pub fn collect_duplicate_method_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let classes = match self.ast_map.read().get(uri) {
+            Some(classes) => classes.clone(),
+            None => return,
+        };
+
+        for class in &classes {
+            let mut seen: HashSet<String> = HashSet::new();
+            for method in &class.methods {
+                if method.is_virtual || method.name_offset == 0 {
+                    continue;
+                }
+                if seen.insert(method.name.to_lowercase()) {
+                    continue;
+                }
+
+                let start = method.name_offset as usize;
+                let range = match self.offset_range_to_lsp_range(
+                    uri,
+                    content,
+                    start,
+                    start + method.name.len(),
+                ) {
+                    Some(r) => r,
+                    None => continue,
+                };
+
+                out.push(make_diagnostic(
+                    range,
+                    DiagnosticSeverity::WARNING,
+                    DUPLICATE_METHOD_CODE,
+                    format!(
+                        "Duplicate method '{}' in {}",
+                        method.name,
+                        describe_class(class)
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Describe a class-like for diagnostic messages, e.g. `class 'Bar'`
+/// or `trait 'Greets'`.  Anonymous classes have no usable name.
+fn describe_class(class: &ClassInfo) -> String {
+    if class.name.starts_with("__anonymous@") {
+        return "anonymous class".to_string();
+    }
+    let kind = match class.kind {
+        ClassLikeKind::Class => "class",
+        ClassLikeKind::Interface => "interface",
+        ClassLikeKind::Trait => "trait",
+        ClassLikeKind::Enum => "enum",
+    };
+    format!("{} '{}'", kind, class.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, &Arc::new(php.to_string()));
+        let mut out = Vec::new();
+        backend.collect_duplicate_method_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    #[test]
+    fn no_diagnostic_for_distinct_methods() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): void {}
+    public function baz(): void {}
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn flags_second_declaration() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): void {}
+    public function foo(): int { return 1; }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Duplicate method 'foo' in class 'Bar'");
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diags[0].range.start.line, 3);
+        assert_eq!(diags[0].range.start.character, 20);
+        assert_eq!(diags[0].range.end.character, 23);
+    }
+
+    #[test]
+    fn method_names_compare_case_insensitively() {
+        let php = r#"<?php
+trait Greets {
+    public function hello(): void {}
+    public function HELLO(): void {}
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Duplicate method 'HELLO' in trait 'Greets'"
+        );
+    }
+
+    #[test]
+    fn same_name_in_different_classes_is_fine() {
+        let php = r#"<?php
+class A { public function run(): void {} }
+class B { public function run(): void {} }
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn stub_files_are_skipped() {
+        let php = r#"<?php
+class A {
+    public function run(): void {}
+    public function run(): void {}
+}
+"#;
+        let backend = Backend::new_test();
+        let uri = "phpantom-stub://A";
+        backend.update_ast(uri, &Arc::new(php.to_string()));
+        let mut out = Vec::new();
+        backend.collect_duplicate_method_diagnostics(uri, php, &mut out);
+        assert!(out.is_empty());
+    }
+}
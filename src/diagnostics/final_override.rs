@@ -0,0 +1,163 @@
+//! Final method override diagnostic.
+//!
+//! Flags methods that override a `final` method declared in an ancestor
+//! class.  PHP refuses to load such a class with "Cannot override final
+//! method", so the overriding declaration is flagged at its name.
+//!
+//! Extending a `final` class is reported by the invalid-class-kind
+//! diagnostic on the `extends` reference.
+
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::types::{ClassInfo, MAX_INHERITANCE_DEPTH, Visibility};
+
+use super::helpers::make_diagnostic;
+
+/// Diagnostic code used for overriding a final method.
+pub(crate) const INVALID_FINAL_OVERRIDE_CODE: &str = "invalid_final_override";
+
+impl Backend {
+    /// Collect final-method-override diagnostics for a single file.
+    ///
+    /// For each class in the file, walks the parent chain and appends an
+    /// Error-severity diagnostic on every method whose nearest ancestor
+    /// declaration is `final`.
+    pub fn collect_final_override_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let ctx = self.file_context(uri);
+        for class in ctx.classes.iter() {
+            if class.parent_class.is_none() {
+                continue;
+            }
+            for method in class.methods.iter() {
+                if method.is_virtual || method.name_offset == 0 {
+                    continue;
+                }
+                let Some((owner, is_final)) = self.find_overridden_method(class, &method.name)
+                else {
+                    continue;
+                };
+                if !is_final {
+                    continue;
+                }
+
+                let start = method.name_offset as usize;
+                let Some(range) =
+                    self.offset_range_to_lsp_range(uri, content, start, start + method.name.len())
+                else {
+                    continue;
+                };
+                out.push(make_diagnostic(
+                    range,
+                    DiagnosticSeverity::ERROR,
+                    INVALID_FINAL_OVERRIDE_CODE,
+                    format!(
+                        "Cannot override final method '{}::{}()'",
+                        owner, method.name
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// Find the nearest ancestor class that declares `method_name`.
+    ///
+    /// Returns the ancestor's FQN and whether its declaration is `final`.
+    /// Private methods are not inherited, so they are skipped.
+    fn find_overridden_method(
+        &self,
+        class: &ClassInfo,
+        method_name: &str,
+    ) -> Option<(String, bool)> {
+        let mut parent_name = class.parent_class;
+        for _ in 0..MAX_INHERITANCE_DEPTH {
+            let parent = self.find_or_load_class(&parent_name?)?;
+            if let Some(m) = parent.methods.iter().find(|m| {
+                !m.is_virtual
+                    && m.visibility != Visibility::Private
+                    && m.name.eq_ignore_ascii_case(method_name)
+            }) {
+                return Some((parent.fqn().to_string(), m.is_final));
+            }
+            parent_name = parent.parent_class;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, php);
+        let mut out = Vec::new();
+        backend.collect_final_override_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    #[test]
+    fn flags_override_of_final_method() {
+        let php = r#"<?php
+class Base {
+    final public function run(): void {}
+}
+class Child extends Base {
+    public function run(): void {}
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "Cannot override final method 'Base::run()'"
+        );
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diags[0].range.start.line, 5);
+        assert_eq!(diags[0].range.start.character, 20);
+        assert_eq!(diags[0].range.end.character, 23);
+    }
+
+    #[test]
+    fn flags_final_method_from_grandparent() {
+        let php = r#"<?php
+class Base {
+    final protected function boot(): void {}
+}
+class Middle extends Base {}
+class Leaf extends Middle {
+    protected function BOOT(): void {}
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(diags[0].range.start.line, 6);
+    }
+
+    #[test]
+    fn no_diagnostic_for_non_final_or_private_parent_method() {
+        let php = r#"<?php
+class Base {
+    public function run(): void {}
+    final private function helper(): void {}
+}
+class Child extends Base {
+    public function run(): void {}
+    private function helper(): void {}
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+}
@@ -6,6 +6,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use mago_syntax::ast::*;
 use tower_lsp::lsp_types::*;
 
 use crate::types::ClassInfo;
@@ -695,3 +696,50 @@ pub(crate) fn make_diagnostic(
         data: None,
     }
 }
+
+/// Call `visit` with the name and members of every named class-like
+/// (class, interface, trait, enum) declared in `statements`.
+///
+/// Descends into namespaces, `{ … }` blocks, `if`/`elseif`/`else`
+/// bodies and function bodies, so conditionally declared classes such
+/// as `if (!class_exists('Foo')) { class Foo { … } }` are visited too.
+/// Anonymous classes are expressions and are not reported.
+pub(crate) fn for_each_class_like_members<'a>(
+    statements: impl Iterator<Item = &'a Statement<'a>>,
+    visit: &mut impl FnMut(&'a str, &'a [ClassLikeMember<'a>]),
+) {
+    for stmt in statements {
+        match stmt {
+            Statement::Class(class) => visit(class.name.value, class.members.as_slice()),
+            Statement::Interface(iface) => visit(iface.name.value, iface.members.as_slice()),
+            Statement::Trait(tr) => visit(tr.name.value, tr.members.as_slice()),
+            Statement::Enum(en) => visit(en.name.value, en.members.as_slice()),
+            Statement::Namespace(ns) => for_each_class_like_members(ns.statements().iter(), visit),
+            Statement::Block(block) => for_each_class_like_members(block.statements.iter(), visit),
+            Statement::Function(func) => {
+                for_each_class_like_members(func.body.statements.iter(), visit)
+            }
+            Statement::If(if_stmt) => match &if_stmt.body {
+                IfBody::Statement(body) => {
+                    for_each_class_like_members(std::iter::once(body.statement), visit);
+                    for else_if in body.else_if_clauses.iter() {
+                        for_each_class_like_members(std::iter::once(else_if.statement), visit);
+                    }
+                    if let Some(ref else_clause) = body.else_clause {
+                        for_each_class_like_members(std::iter::once(else_clause.statement), visit);
+                    }
+                }
+                IfBody::ColonDelimited(body) => {
+                    for_each_class_like_members(body.statements.iter(), visit);
+                    for else_if in body.else_if_clauses.iter() {
+                        for_each_class_like_members(else_if.statements.iter(), visit);
+                    }
+                    if let Some(ref else_clause) = body.else_clause {
+                        for_each_class_like_members(else_clause.statements.iter(), visit);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+}
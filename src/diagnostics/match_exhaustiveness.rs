@@ -0,0 +1,539 @@
+//! Non-exhaustive `match` diagnostic.
+//!
+//! Flags `match` expressions whose subject is an enum but whose arms do
+//! not cover every case of that enum and there is no `default` arm.
+//! Such a `match` throws `\UnhandledMatchError` at runtime as soon as
+//! one of the missing cases reaches it.
+//!
+//! The check is deliberately conservative: it only fires when the
+//! subject resolves to a single enum type (not nullable, not a union)
+//! and every arm condition is a plain `Enum::Case` constant.  Any other
+//! condition (a variable, a function call, a constant of another class)
+//! could match a case we cannot see statically, so the diagnostic is
+//! suppressed.
+
+use std::collections::HashMap;
+
+use mago_span::HasSpan;
+use mago_syntax::ast::*;
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::completion::resolver::{Loaders, VarResolutionCtx};
+use crate::completion::variable::foreach_resolution::resolve_expression_type;
+use crate::parser::{with_parse_cache, with_parsed_program};
+use crate::php_type::PhpType;
+use crate::types::{ClassInfo, ClassLikeKind};
+
+use super::helpers::{find_innermost_enclosing_class, make_diagnostic};
+
+/// Diagnostic code used for non-exhaustive `match` expressions.
+pub(crate) const MISSING_MATCH_CASE_CODE: &str = "missing_match_case";
+
+impl Backend {
+    /// Collect non-exhaustive `match` diagnostics for a single file.
+    ///
+    /// Appends a Warning-severity diagnostic on the `match` keyword of
+    /// every enum `match` that leaves at least one case unhandled.
+    pub fn collect_match_exhaustiveness_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let file_ctx = self.file_context(uri);
+        let _parse_guard = with_parse_cache(content);
+
+        let class_loader = self.class_loader(&file_ctx);
+        let function_loader_cl = self.function_loader(&file_ctx);
+        let constant_loader_cl = self.constant_loader();
+        let default_class = ClassInfo::default();
+
+        let findings: Vec<(u32, Vec<String>)> =
+            with_parsed_program(content, "match_exhaustiveness", |program, content| {
+                let mut findings = Vec::new();
+                let mut check = |match_expr: &Match<'_>| {
+                    let offset = match_expr.span().start.offset;
+                    let enclosing = find_innermost_enclosing_class(&file_ctx.classes, offset);
+                    let current_class = enclosing.unwrap_or(&default_class);
+
+                    let Some(covered) = covered_case_names(match_expr, current_class) else {
+                        return;
+                    };
+
+                    let var_ctx = VarResolutionCtx {
+                        var_name: "",
+                        top_level_scope: None,
+                        current_class,
+                        all_classes: &file_ctx.classes,
+                        content,
+                        cursor_offset: offset,
+                        class_loader: &class_loader,
+                        loaders: Loaders {
+                            function_loader: Some(&function_loader_cl),
+                            constant_loader: Some(&constant_loader_cl),
+                        },
+                        resolved_class_cache: Some(&self.resolved_class_cache),
+                        enclosing_return_type: None,
+                        branch_aware: true,
+                        match_arm_narrowing: HashMap::new(),
+                        scope_var_resolver: None,
+                    };
+                    let Some(PhpType::Named(name)) =
+                        resolve_expression_type(match_expr.expression, &var_ctx)
+                    else {
+                        return;
+                    };
+                    let Some(enum_info) = class_loader(&name) else {
+                        return;
+                    };
+                    if enum_info.kind != ClassLikeKind::Enum {
+                        return;
+                    }
+
+                    // Every arm must name a case of the subject's enum;
+                    // otherwise we cannot tell what the arms cover.
+                    let enum_short = enum_info.name.as_str();
+                    if covered
+                        .iter()
+                        .any(|(class, _)| !class.eq_ignore_ascii_case(enum_short))
+                    {
+                        return;
+                    }
+
+                    let missing: Vec<String> = enum_info
+                        .constants
+                        .iter()
+                        .filter(|c| c.is_enum_case)
+                        .filter(|c| !covered.iter().any(|(_, case)| *case == c.name.as_str()))
+                        .map(|c| c.name.to_string())
+                        .collect();
+                    if !missing.is_empty() {
+                        findings.push((offset, missing));
+                    }
+                };
+                for stmt in program.statements.iter() {
+                    walk_statement(stmt, &mut check);
+                }
+                findings
+            });
+
+        for (offset, missing) in findings {
+            let start = offset as usize;
+            let Some(range) =
+                self.offset_range_to_lsp_range(uri, content, start, start + "match".len())
+            else {
+                continue;
+            };
+            let list = missing
+                .iter()
+                .map(|name| format!("'{}'", name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let noun = if missing.len() == 1 { "case" } else { "cases" };
+            out.push(make_diagnostic(
+                range,
+                DiagnosticSeverity::WARNING,
+                MISSING_MATCH_CASE_CODE,
+                format!("Non-exhaustive match: missing {} {}", noun, list),
+            ));
+        }
+    }
+}
+
+/// The `(enum short name, case name)` pairs named by the arm conditions.
+///
+/// Returns `None` when the `match` has a `default` arm or when any
+/// condition is not an `Enum::Case` constant.  `self::Case` and
+/// `static::Case` resolve to the enclosing class.
+fn covered_case_names(
+    match_expr: &Match<'_>,
+    current_class: &ClassInfo,
+) -> Option<Vec<(String, String)>> {
+    let mut covered = Vec::new();
+    for arm in match_expr.arms.iter() {
+        let MatchArm::Expression(arm) = arm else {
+            return None;
+        };
+        for cond in arm.conditions.iter() {
+            let Expression::Access(Access::ClassConstant(cca)) = cond else {
+                return None;
+            };
+            let ClassLikeConstantSelector::Identifier(ident) = &cca.constant else {
+                return None;
+            };
+            let class = match cca.class {
+                Expression::Identifier(class_ident) => {
+                    let name = class_ident.value();
+                    name.rsplit('\\').next().unwrap_or(name).to_string()
+                }
+                Expression::Self_(_) | Expression::Static(_) => current_class.name.to_string(),
+                _ => return None,
+            };
+            covered.push((class, ident.value.to_string()));
+        }
+    }
+    Some(covered)
+}
+
+// ── AST walk ────────────────────────────────────────────────────────────────
+
+fn walk_statement(stmt: &Statement<'_>, f: &mut dyn FnMut(&Match<'_>)) {
+    match stmt {
+        Statement::Namespace(ns) => {
+            for inner in ns.statements().iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::Class(class) => walk_members(class.members.as_slice(), f),
+        Statement::Trait(tr) => walk_members(tr.members.as_slice(), f),
+        Statement::Enum(en) => walk_members(en.members.as_slice(), f),
+        Statement::Function(func) => {
+            for inner in func.body.statements.iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::If(if_stmt) => {
+            walk_expression(if_stmt.condition, f);
+            match &if_stmt.body {
+                IfBody::Statement(body) => {
+                    walk_statement(body.statement, f);
+                    for else_if in body.else_if_clauses.iter() {
+                        walk_expression(else_if.condition, f);
+                        walk_statement(else_if.statement, f);
+                    }
+                    if let Some(ref else_clause) = body.else_clause {
+                        walk_statement(else_clause.statement, f);
+                    }
+                }
+                IfBody::ColonDelimited(body) => {
+                    for inner in body.statements.iter() {
+                        walk_statement(inner, f);
+                    }
+                    for else_if in body.else_if_clauses.iter() {
+                        walk_expression(else_if.condition, f);
+                        for inner in else_if.statements.iter() {
+                            walk_statement(inner, f);
+                        }
+                    }
+                    if let Some(ref else_clause) = body.else_clause {
+                        for inner in else_clause.statements.iter() {
+                            walk_statement(inner, f);
+                        }
+                    }
+                }
+            }
+        }
+        Statement::Switch(switch_stmt) => {
+            walk_expression(switch_stmt.expression, f);
+            let cases = match &switch_stmt.body {
+                SwitchBody::BraceDelimited(body) => &body.cases,
+                SwitchBody::ColonDelimited(body) => &body.cases,
+            };
+            for case in cases.iter() {
+                for inner in case.statements().iter() {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::While(while_stmt) => {
+            walk_expression(while_stmt.condition, f);
+            for inner in while_stmt.body.statements().iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::DoWhile(do_while) => {
+            walk_statement(do_while.statement, f);
+            walk_expression(do_while.condition, f);
+        }
+        Statement::For(for_stmt) => {
+            for expr in for_stmt.initializations.iter() {
+                walk_expression(expr, f);
+            }
+            for expr in for_stmt.conditions.iter() {
+                walk_expression(expr, f);
+            }
+            for expr in for_stmt.increments.iter() {
+                walk_expression(expr, f);
+            }
+            for inner in for_stmt.body.statements().iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::Foreach(foreach_stmt) => {
+            walk_expression(foreach_stmt.expression, f);
+            for inner in foreach_stmt.body.statements().iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::Try(try_stmt) => {
+            for inner in try_stmt.block.statements.iter() {
+                walk_statement(inner, f);
+            }
+            for catch in try_stmt.catch_clauses.iter() {
+                for inner in catch.block.statements.iter() {
+                    walk_statement(inner, f);
+                }
+            }
+            if let Some(ref finally) = try_stmt.finally_clause {
+                for inner in finally.block.statements.iter() {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Block(block) => {
+            for inner in block.statements.iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::Expression(expr_stmt) => walk_expression(expr_stmt.expression, f),
+        Statement::Return(ret) => {
+            if let Some(val) = ret.value {
+                walk_expression(val, f);
+            }
+        }
+        Statement::Echo(echo) => {
+            for expr in echo.values.iter() {
+                walk_expression(expr, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_members(members: &[ClassLikeMember<'_>], f: &mut dyn FnMut(&Match<'_>)) {
+    for member in members.iter() {
+        if let ClassLikeMember::Method(method) = member
+            && let MethodBody::Concrete(block) = &method.body
+        {
+            for inner in block.statements.iter() {
+                walk_statement(inner, f);
+            }
+        }
+    }
+}
+
+fn walk_expression(expr: &Expression<'_>, f: &mut dyn FnMut(&Match<'_>)) {
+    match expr {
+        Expression::Match(match_expr) => {
+            f(match_expr);
+            walk_expression(match_expr.expression, f);
+            for arm in match_expr.arms.iter() {
+                match arm {
+                    MatchArm::Expression(arm) => {
+                        for cond in arm.conditions.iter() {
+                            walk_expression(cond, f);
+                        }
+                        walk_expression(arm.expression, f);
+                    }
+                    MatchArm::Default(arm) => walk_expression(arm.expression, f),
+                }
+            }
+        }
+        Expression::Closure(closure) => {
+            for inner in closure.body.statements.iter() {
+                walk_statement(inner, f);
+            }
+        }
+        Expression::ArrowFunction(arrow) => walk_expression(arrow.expression, f),
+        Expression::Array(array) => {
+            for elem in array.elements.iter() {
+                walk_array_element(elem, f);
+            }
+        }
+        Expression::LegacyArray(array) => {
+            for elem in array.elements.iter() {
+                walk_array_element(elem, f);
+            }
+        }
+        Expression::Call(call) => {
+            let arguments = match call {
+                Call::Function(fc) => {
+                    walk_expression(fc.function, f);
+                    &fc.argument_list
+                }
+                Call::Method(mc) => {
+                    walk_expression(mc.object, f);
+                    &mc.argument_list
+                }
+                Call::NullSafeMethod(mc) => {
+                    walk_expression(mc.object, f);
+                    &mc.argument_list
+                }
+                Call::StaticMethod(mc) => {
+                    walk_expression(mc.class, f);
+                    &mc.argument_list
+                }
+            };
+            for arg in arguments.arguments.iter() {
+                walk_expression(arg.value(), f);
+            }
+        }
+        Expression::Instantiation(inst) => {
+            if let Some(ref arg_list) = inst.argument_list {
+                for arg in arg_list.arguments.iter() {
+                    walk_expression(arg.value(), f);
+                }
+            }
+        }
+        Expression::Assignment(assign) => {
+            walk_expression(assign.lhs, f);
+            walk_expression(assign.rhs, f);
+        }
+        Expression::Binary(bin) => {
+            walk_expression(bin.lhs, f);
+            walk_expression(bin.rhs, f);
+        }
+        Expression::UnaryPrefix(u) => walk_expression(u.operand, f),
+        Expression::UnaryPostfix(u) => walk_expression(u.operand, f),
+        Expression::Parenthesized(p) => walk_expression(p.expression, f),
+        Expression::Conditional(cond) => {
+            walk_expression(cond.condition, f);
+            if let Some(then_expr) = cond.then {
+                walk_expression(then_expr, f);
+            }
+            walk_expression(cond.r#else, f);
+        }
+        Expression::ArrayAccess(access) => {
+            walk_expression(access.array, f);
+            walk_expression(access.index, f);
+        }
+        Expression::Throw(throw_expr) => walk_expression(throw_expr.exception, f),
+        Expression::Yield(Yield::Value(yv)) => {
+            if let Some(value) = yv.value {
+                walk_expression(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_array_element(elem: &array::ArrayElement<'_>, f: &mut dyn FnMut(&Match<'_>)) {
+    match elem {
+        array::ArrayElement::KeyValue(kv) => {
+            walk_expression(kv.key, f);
+            walk_expression(kv.value, f);
+        }
+        array::ArrayElement::Value(v) => walk_expression(v.value, f),
+        array::ArrayElement::Variadic(v) => walk_expression(v.value, f),
+        array::ArrayElement::Missing(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, php);
+        let mut out = Vec::new();
+        backend.collect_match_exhaustiveness_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    const STATUS: &str = r#"<?php
+enum Status: string {
+    case ACTIVE = 'active';
+    case PENDING = 'pending';
+    case FAILED = 'failed';
+}
+"#;
+
+    #[test]
+    fn flags_missing_cases() {
+        let php = format!(
+            "{STATUS}{}",
+            r#"function label(Status $status): string {
+    return match ($status) {
+        Status::ACTIVE => 'Active',
+    };
+}
+"#
+        );
+        let diags = collect(&php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "Non-exhaustive match: missing cases 'PENDING', 'FAILED'"
+        );
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diags[0].range.start.line, 7);
+        assert_eq!(diags[0].range.start.character, 11);
+    }
+
+    #[test]
+    fn no_diagnostic_when_all_cases_covered() {
+        let php = format!(
+            "{STATUS}{}",
+            r#"function label(Status $status): string {
+    return match ($status) {
+        Status::ACTIVE, Status::PENDING => 'Open',
+        Status::FAILED => 'Failed',
+    };
+}
+"#
+        );
+        assert!(collect(&php).is_empty());
+    }
+
+    #[test]
+    fn no_diagnostic_with_default_arm() {
+        let php = format!(
+            "{STATUS}{}",
+            r#"function label(Status $status): string {
+    return match ($status) {
+        Status::ACTIVE => 'Active',
+        default => 'Other',
+    };
+}
+"#
+        );
+        assert!(collect(&php).is_empty());
+    }
+
+    #[test]
+    fn self_cases_inside_enum_method() {
+        let php = r#"<?php
+enum Suit {
+    case Hearts;
+    case Spades;
+
+    public function color(): string {
+        return match ($this) {
+            self::Hearts => 'Red',
+        };
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "Non-exhaustive match: missing case 'Spades'"
+        );
+    }
+
+    #[test]
+    fn no_diagnostic_for_non_enum_subject_or_dynamic_arm() {
+        let php = format!(
+            "{STATUS}{}",
+            r#"function label(string $s, Status $status, Status $other): string {
+    $a = match ($s) {
+        'x' => 1,
+    };
+    return match ($status) {
+        $other => 'Same',
+    };
+}
+"#
+        );
+        assert!(collect(&php).is_empty());
+    }
+}
@@ -0,0 +1,365 @@
+//! Member visibility diagnostic.
+//!
+//! Flags accesses to `private` and `protected` methods, properties, and
+//! constants from a scope that is not allowed to see them, e.g.
+//! `$order->recalculate()` from a controller when `recalculate()` is
+//! private to `Order`.
+//!
+//! The check piggybacks on the unknown-member walk: once a member has
+//! been found on the resolved subject classes, this module decides
+//! whether the calling class may access it.  The rules are deliberately
+//! conservative so that a diagnostic is only emitted when PHP would
+//! throw an `Error` at runtime:
+//!
+//! - **private** members are accessible only from the same class.
+//! - **protected** members are accessible when the caller and the
+//!   target share any class in their inheritance chains (this covers
+//!   subclasses, parents, and siblings that inherit the member from a
+//!   common ancestor).
+//! - Accesses rooted in `$this`, `self`, `static`, or `parent` are
+//!   never flagged (closures rebound with `Closure::bind` change the
+//!   scope in ways we cannot see).
+//! - Accesses inside traits are never flagged (the host class is
+//!   unknown).
+//! - Members reachable through `__call` / `__callStatic` / `__get` are
+//!   never flagged, because PHP falls back to the magic method instead
+//!   of throwing.
+
+use std::sync::Arc;
+
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::types::{ClassInfo, ClassLikeKind, Visibility};
+use crate::virtual_members::resolve_class_fully_cached;
+
+use super::helpers::make_diagnostic;
+
+/// Diagnostic code used for member visibility violations.
+pub(crate) const INVALID_MEMBER_ACCESS_CODE: &str = "invalid_member_access";
+
+/// What kind of member an access refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberKind {
+    Method,
+    Property,
+    Constant,
+}
+
+impl MemberKind {
+    fn label(self) -> &'static str {
+        match self {
+            MemberKind::Method => "method",
+            MemberKind::Property => "property",
+            MemberKind::Constant => "constant",
+        }
+    }
+}
+
+impl Backend {
+    /// Check whether `member_name` on `base_classes` is visible from
+    /// `current_class` and build a diagnostic when it is not.
+    ///
+    /// Only called for members that are already known to exist on the
+    /// resolved subject.  Returns `None` when the access is allowed on
+    /// at least one branch of a union type, or when any visibility rule
+    /// exemption applies (see the module docs).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn check_member_visibility(
+        &self,
+        uri: &str,
+        content: &str,
+        base_classes: &[Arc<ClassInfo>],
+        current_class: Option<&ClassInfo>,
+        member_name: &str,
+        is_static: bool,
+        is_method_call: bool,
+        class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+        cache: &crate::virtual_members::ResolvedClassCache,
+        start: u32,
+        end: u32,
+    ) -> Option<Diagnostic> {
+        if current_class.is_some_and(|cc| cc.kind == ClassLikeKind::Trait) {
+            return None;
+        }
+
+        let mut violation: Option<(Visibility, MemberKind)> = None;
+        for base in base_classes {
+            // Object shapes are synthetic and all of their members are public.
+            if base.name == "__object_shape" {
+                return None;
+            }
+            let resolved = resolve_class_fully_cached(base, class_loader, cache);
+            if has_magic_fallback(&resolved, is_static, is_method_call) {
+                return None;
+            }
+
+            let Some((visibility, kind)) =
+                find_member_visibility(&resolved, member_name, is_static, is_method_call)
+            else {
+                // Only present on the unresolved class (context-specific
+                // virtual members), which are always public.
+                return None;
+            };
+
+            let allowed = match visibility {
+                Visibility::Public => true,
+                Visibility::Private => current_class.is_some_and(|cc| cc.fqn() == resolved.fqn()),
+                Visibility::Protected => {
+                    current_class.is_some_and(|cc| shares_ancestor(cc, &resolved, class_loader))
+                }
+            };
+            if allowed {
+                return None;
+            }
+            violation.get_or_insert((visibility, kind));
+        }
+
+        let (visibility, kind) = violation?;
+        let range = self.offset_range_to_lsp_range(uri, content, start as usize, end as usize)?;
+
+        let visibility_label = match visibility {
+            Visibility::Private => "private",
+            _ => "protected",
+        };
+        let scope = match current_class {
+            Some(cc) if cc.name.starts_with("__anonymous@") => "anonymous class".to_string(),
+            Some(cc) => format!("class '{}'", cc.name),
+            None => "global scope".to_string(),
+        };
+        let message = format!(
+            "Cannot access {} {} '{}' from {}",
+            visibility_label,
+            kind.label(),
+            member_name,
+            scope,
+        );
+
+        Some(make_diagnostic(
+            range,
+            DiagnosticSeverity::ERROR,
+            INVALID_MEMBER_ACCESS_CODE,
+            message,
+        ))
+    }
+}
+
+/// Look up the visibility of a member on a fully-resolved class.
+///
+/// Method names are matched case-insensitively.  Static non-method
+/// access checks constants before static properties, mirroring the
+/// lookup order of the unknown-member check.
+fn find_member_visibility(
+    class: &ClassInfo,
+    member_name: &str,
+    is_static: bool,
+    is_method_call: bool,
+) -> Option<(Visibility, MemberKind)> {
+    if is_method_call {
+        return class
+            .methods
+            .iter()
+            .find(|m| m.name.eq_ignore_ascii_case(member_name))
+            .map(|m| (m.visibility, MemberKind::Method));
+    }
+
+    if is_static {
+        if let Some(c) = class.constants.iter().find(|c| c.name == member_name) {
+            return Some((c.visibility, MemberKind::Constant));
+        }
+        let bare = member_name.strip_prefix('$').unwrap_or(member_name);
+        return class
+            .properties
+            .iter()
+            .find(|p| p.is_static && p.name == bare)
+            .map(|p| (p.visibility, MemberKind::Property));
+    }
+
+    class
+        .properties
+        .iter()
+        .find(|p| p.name == member_name)
+        .map(|p| (p.visibility, MemberKind::Property))
+}
+
+/// Whether an inaccessible member would be routed to a magic method
+/// (`__call`, `__callStatic`, or `__get`) instead of throwing.
+fn has_magic_fallback(class: &ClassInfo, is_static: bool, is_method_call: bool) -> bool {
+    let magic = match (is_method_call, is_static) {
+        (true, true) => "__callStatic",
+        (true, false) => "__call",
+        (false, false) => "__get",
+        (false, true) => return false,
+    };
+    class
+        .methods
+        .iter()
+        .any(|m| m.name.eq_ignore_ascii_case(magic))
+}
+
+/// Whether the inheritance chains of `a` and `b` have any class in
+/// common (each chain includes the class itself).
+fn shares_ancestor(
+    a: &ClassInfo,
+    b: &ClassInfo,
+    class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+) -> bool {
+    let chain_a = ancestor_chain(a, class_loader);
+    ancestor_chain(b, class_loader)
+        .iter()
+        .any(|fqn| chain_a.contains(fqn))
+}
+
+/// The FQNs of `class` and every parent class above it.
+fn ancestor_chain(
+    class: &ClassInfo,
+    class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+) -> Vec<String> {
+    let mut chain = vec![class.fqn().to_string()];
+    let mut parent = class.parent_class;
+    while let Some(name) = parent {
+        let name = name.trim_start_matches('\\').to_string();
+        if chain.contains(&name) || chain.len() > 20 {
+            break;
+        }
+        parent = class_loader(&name).and_then(|p| p.parent_class);
+        chain.push(name);
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    use super::INVALID_MEMBER_ACCESS_CODE;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, php);
+        let mut out = Vec::new();
+        backend.collect_unknown_member_diagnostics(uri, php, &mut out);
+        out.retain(|d| {
+            d.code
+                == Some(NumberOrString::String(
+                    INVALID_MEMBER_ACCESS_CODE.to_string(),
+                ))
+        });
+        out
+    }
+
+    #[test]
+    fn flags_private_method_from_other_class() {
+        let php = r#"<?php
+class Bar {
+    private function foo(): void {}
+}
+class Baz {
+    public function run(Bar $bar): void {
+        $bar->foo();
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "Cannot access private method 'foo' from class 'Baz'"
+        );
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn flags_protected_property_from_global_scope() {
+        let php = r#"<?php
+class Bar {
+    protected string $name = '';
+}
+function run(Bar $bar): void {
+    echo $bar->name;
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "Cannot access protected property 'name' from global scope"
+        );
+    }
+
+    #[test]
+    fn flags_private_constant_from_other_class() {
+        let php = r#"<?php
+class Bar {
+    private const SECRET = 1;
+}
+class Baz {
+    public function run(): int {
+        return Bar::SECRET;
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert!(diags[0].message.contains("private constant 'SECRET'"));
+    }
+
+    #[test]
+    fn allows_private_access_on_other_instance_of_same_class() {
+        let php = r#"<?php
+class Money {
+    private int $amount = 0;
+    public function equals(Money $other): bool {
+        return $other->amount === $this->amount;
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn allows_protected_access_from_subclass_and_sibling() {
+        let php = r#"<?php
+abstract class Node {
+    protected function children(): array { return []; }
+}
+class Leaf extends Node {}
+class Branch extends Node {
+    public function walk(Leaf $leaf, Node $node): void {
+        $leaf->children();
+        $node->children();
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn allows_access_handled_by_magic_call() {
+        let php = r#"<?php
+class Proxy {
+    private function hidden(): void {}
+    public function __call(string $name, array $args): mixed { return null; }
+}
+function run(Proxy $p): void {
+    $p->hidden();
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn allows_public_access() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): void {}
+}
+function run(Bar $bar): void {
+    $bar->foo();
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+}
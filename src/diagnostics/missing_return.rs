@@ -0,0 +1,254 @@
+//! Missing return diagnostic.
+//!
+//! Flags methods with a native return type other than `void` or `never`
+//! whose body contains no `return` statement at all, e.g.
+//! `public function total(): int { $this->sum = 0; }`.  Such a method
+//! throws a `TypeError` ("none returned") as soon as it is called.
+//!
+//! The check is syntactic: one `return` anywhere in the body (outside
+//! nested closures and classes) is enough to pass.  Generators are
+//! exempt because their return type describes the `Generator`, and so
+//! are bodies that end in `throw`, `exit`, or `die`.
+
+use mago_span::HasSpan;
+use mago_syntax::ast::*;
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::code_actions::stmt_contains_return;
+use crate::parser::{with_parse_cache, with_parsed_program};
+
+use super::helpers::{for_each_class_like_members, make_diagnostic};
+
+/// Diagnostic code used for missing-return diagnostics.
+pub(crate) const MISSING_RETURN_CODE: &str = "missing_return_statement";
+
+impl Backend {
+    /// Collect missing-return diagnostics for a single file.
+    ///
+    /// Appends a Warning-severity diagnostic on the method name to `out`
+    /// for every non-void method body without a `return` statement.
+    pub fn collect_missing_return_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let _parse_guard = with_parse_cache(content);
+        with_parsed_program(content, "missing_return", |program, content| {
+            let mut found: Vec<(String, u32, u32)> = Vec::new();
+            for_each_class_like_members(program.statements.iter(), &mut |_, members| {
+                collect_from_members(members, content, &mut found);
+            });
+
+            for (method_name, start, end) in found {
+                let Some(range) =
+                    self.offset_range_to_lsp_range(uri, content, start as usize, end as usize)
+                else {
+                    continue;
+                };
+                out.push(make_diagnostic(
+                    range,
+                    DiagnosticSeverity::WARNING,
+                    MISSING_RETURN_CODE,
+                    format!("Method '{}' must return a value", method_name),
+                ));
+            }
+        });
+    }
+}
+
+/// Record `(method, name_start, name_end)` for each method among
+/// `members` whose body never returns a value.
+fn collect_from_members(
+    members: &[ClassLikeMember<'_>],
+    content: &str,
+    found: &mut Vec<(String, u32, u32)>,
+) {
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+        let MethodBody::Concrete(block) = &method.body else {
+            continue;
+        };
+        let Some(return_hint) = &method.return_type_hint else {
+            continue;
+        };
+        if matches!(return_hint.hint, Hint::Void(_) | Hint::Never(_)) {
+            continue;
+        }
+
+        let statements = block.statements.as_slice();
+        if statements.iter().any(stmt_contains_return)
+            || statements.last().is_some_and(ends_in_exit)
+            || is_generator_body(block, content)
+        {
+            continue;
+        }
+
+        found.push((
+            method.name.value.to_string(),
+            method.name.span.start.offset,
+            method.name.span.end.offset,
+        ));
+    }
+}
+
+/// Whether a statement is a `throw`, `exit`, or `die` expression
+/// statement, which terminates the method without returning.
+fn ends_in_exit(stmt: &Statement<'_>) -> bool {
+    matches!(
+        stmt,
+        Statement::Expression(es) if matches!(
+            es.expression,
+            Expression::Throw(_)
+                | Expression::Construct(Construct::Exit(_))
+                | Expression::Construct(Construct::Die(_))
+        )
+    )
+}
+
+/// Whether the method body contains a `yield` keyword.
+///
+/// A text scan is enough here: a false match (e.g. `yield` inside a
+/// string) only suppresses the diagnostic.
+fn is_generator_body(block: &Block<'_>, content: &str) -> bool {
+    let span = block.span();
+    let body = content
+        .get(span.start.offset as usize..span.end.offset as usize)
+        .unwrap_or("");
+    body.match_indices("yield").any(|(i, _)| {
+        let before = body[..i].chars().next_back();
+        let after = body[i + 5..].chars().next();
+        !before.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+            && !after.is_some_and(|c| c.is_alphanumeric() || c == '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, &Arc::new(php.to_string()));
+        let mut out = Vec::new();
+        backend.collect_missing_return_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    #[test]
+    fn flags_method_without_return() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): int {
+        $x = 1;
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Method 'foo' must return a value");
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diags[0].range.start.line, 2);
+        assert_eq!(diags[0].range.start.character, 20);
+    }
+
+    #[test]
+    fn no_diagnostic_for_void_never_or_untyped() {
+        let php = r#"<?php
+class Bar {
+    public function a(): void {}
+    public function b(): never { exit(1); }
+    public function c() {}
+    abstract public function d(): int;
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn nested_return_counts() {
+        let php = r#"<?php
+class Bar {
+    public function foo(bool $flag): ?int {
+        if ($flag) {
+            foreach ([1, 2] as $i) {
+                return $i;
+            }
+        }
+        return null;
+    }
+    public function bar(): int {
+        try {
+            return 1;
+        } finally {
+        }
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn return_inside_closure_does_not_count() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): array {
+        $f = function () { return 1; };
+    }
+}
+"#;
+        assert_eq!(collect(php).len(), 1);
+    }
+
+    #[test]
+    fn no_diagnostic_for_throw_only_body() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): int {
+        throw new \LogicException('not implemented');
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn no_diagnostic_for_generator() {
+        let php = r#"<?php
+class Bar {
+    public function items(): iterable {
+        yield 1;
+        yield 2;
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn flags_method_of_class_declared_inside_block() {
+        let php = r#"<?php
+if (PHP_VERSION_ID >= 80000) {
+    class Bar {
+        public function foo(): int {
+            $x = 1;
+        }
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Method 'foo' must return a value");
+    }
+}
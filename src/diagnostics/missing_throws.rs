@@ -0,0 +1,364 @@
+//! Missing `@throws` diagnostic (opt-in).
+//!
+//! Flags exceptions that can escape a function or method without being
+//! declared in its docblock.  An exception counts when it is thrown
+//! directly (`throw new FooException()`) or propagated from a called
+//! function or method whose docblock declares `@throws FooException`,
+//! and the throw site is not wrapped in a `try/catch` that catches the
+//! exception or one of its parent classes.
+//!
+//! Detection reuses the throws-analysis pipeline behind `@throws`
+//! completion and the "Update docblock" code action, so the three
+//! features agree on which exceptions are uncaught.
+//!
+//! Uncaught exceptions are not errors in PHP, so this diagnostic is
+//! **off by default** and uses `Severity::HINT`.  Enable it by adding
+//! the following to `.phpantom.toml`:
+//!
+//! ```toml
+//! [diagnostics]
+//! missing-throws = true
+//! ```
+
+use std::sync::Arc;
+
+use mago_syntax::ast::*;
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::completion::source::throws_analysis::{self, ThrowsContext};
+use crate::docblock::{extract_throws_tags, get_docblock_text_for_node};
+use crate::parser::with_parsed_program;
+use crate::types::ClassInfo;
+
+use super::helpers::make_diagnostic;
+
+/// Diagnostic code used for missing-throws diagnostics.
+pub(crate) const MISSING_THROWS_CODE: &str = "missing_throws_declaration";
+
+/// A function-like body to analyse: the signature text (between the
+/// name and the opening brace), the body span, and the `@throws` types
+/// declared in its docblock.
+struct ThrowingBody {
+    signature_start: u32,
+    body_start: u32,
+    body_end: u32,
+    declared: Vec<String>,
+}
+
+impl Backend {
+    /// Collect missing-throws diagnostics for a single file.
+    ///
+    /// Appends a Hint-severity diagnostic to `out` at the first uncaught
+    /// throw site of every exception type that the enclosing function
+    /// or method does not declare with `@throws`.  Does nothing unless
+    /// `missing-throws` is enabled in the `[diagnostics]` config.
+    pub fn collect_missing_throws_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if !self.config().diagnostics.missing_throws_enabled() {
+            return;
+        }
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let bodies = with_parsed_program(content, "missing_throws", |program, content| {
+            let trivia = program.trivia.as_slice();
+            let mut bodies = Vec::new();
+            for stmt in program.statements.iter() {
+                collect_from_statement(stmt, trivia, content, &mut bodies);
+            }
+            bodies
+        });
+        if bodies.is_empty() {
+            return;
+        }
+
+        let file_ctx = self.file_context(uri);
+        let class_loader = self.class_loader(&file_ctx);
+        let function_loader = self.function_loader(&file_ctx);
+        let throws_ctx = ThrowsContext {
+            class_loader: &class_loader,
+            function_loader: Some(&function_loader),
+            use_map: &file_ctx.use_map,
+            file_namespace: &file_ctx.namespace,
+        };
+
+        for body in &bodies {
+            let signature = &content[body.signature_start as usize..body.body_start as usize];
+            let body_text = &content[body.body_start as usize..body.body_end as usize];
+            let uncaught = throws_analysis::find_uncaught_throws_in_body(
+                body_text,
+                signature,
+                content,
+                Some(&throws_ctx),
+            );
+
+            let declared: Vec<String> = body
+                .declared
+                .iter()
+                .map(|name| canonical_fqn(name, &throws_ctx))
+                .collect();
+
+            for info in uncaught {
+                let exc_fqn = info.type_name.to_string();
+                if is_declared(&exc_fqn, &declared, &class_loader) {
+                    continue;
+                }
+
+                let start = body.body_start as usize + info.offset;
+                let end = statement_end(content, start, body.body_end as usize);
+                let Some(range) = self.offset_range_to_lsp_range(uri, content, start, end) else {
+                    continue;
+                };
+                out.push(make_diagnostic(
+                    range,
+                    DiagnosticSeverity::HINT,
+                    MISSING_THROWS_CODE,
+                    format!(
+                        "Exception '{}' is neither caught nor declared with @throws",
+                        exc_fqn
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Walk top-level functions and class-like methods, recording each
+/// concrete body together with its declared `@throws` types.
+fn collect_from_statement(
+    stmt: &Statement<'_>,
+    trivia: &[Trivia<'_>],
+    content: &str,
+    bodies: &mut Vec<ThrowingBody>,
+) {
+    match stmt {
+        Statement::Function(func) => {
+            bodies.push(ThrowingBody {
+                signature_start: func.name.span.start.offset,
+                body_start: func.body.left_brace.end.offset,
+                body_end: func.body.right_brace.start.offset,
+                declared: declared_throws(trivia, content, func),
+            });
+        }
+        Statement::Class(class) => {
+            collect_from_members(class.members.as_slice(), trivia, content, bodies)
+        }
+        Statement::Trait(tr) => {
+            collect_from_members(tr.members.as_slice(), trivia, content, bodies)
+        }
+        Statement::Enum(en) => collect_from_members(en.members.as_slice(), trivia, content, bodies),
+        Statement::Namespace(ns) => {
+            for inner in ns.statements().iter() {
+                collect_from_statement(inner, trivia, content, bodies);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_from_members(
+    members: &[ClassLikeMember<'_>],
+    trivia: &[Trivia<'_>],
+    content: &str,
+    bodies: &mut Vec<ThrowingBody>,
+) {
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+        let MethodBody::Concrete(block) = &method.body else {
+            continue;
+        };
+        bodies.push(ThrowingBody {
+            signature_start: method.name.span.start.offset,
+            body_start: block.left_brace.end.offset,
+            body_end: block.right_brace.start.offset,
+            declared: declared_throws(trivia, content, method),
+        });
+    }
+}
+
+/// The raw `@throws` type names from the docblock attached to `node`.
+fn declared_throws(
+    trivia: &[Trivia<'_>],
+    content: &str,
+    node: &impl mago_span::HasSpan,
+) -> Vec<String> {
+    get_docblock_text_for_node(trivia, content, node)
+        .map(|doc| {
+            extract_throws_tags(doc)
+                .iter()
+                .map(|t| t.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Resolve a `@throws` type name to the same FQN form that the throws
+/// analysis produces for uncaught exceptions.
+fn canonical_fqn(name: &str, ctx: &ThrowsContext<'_>) -> String {
+    let trimmed = name.trim_start_matches('\\');
+    let resolved = crate::util::resolve_to_fqn(trimmed, ctx.use_map, ctx.file_namespace);
+    (ctx.class_loader)(&resolved)
+        .or_else(|| (ctx.class_loader)(trimmed))
+        .map(|cls| cls.fqn().to_string())
+        .unwrap_or(resolved)
+}
+
+/// Whether `exc_fqn` is covered by one of the declared `@throws` types,
+/// either exactly, through a parent class, or by a blanket `Throwable`.
+fn is_declared(
+    exc_fqn: &str,
+    declared: &[String],
+    class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+) -> bool {
+    declared.iter().any(|d| {
+        d.eq_ignore_ascii_case("Throwable")
+            || d.eq_ignore_ascii_case(exc_fqn)
+            || throws_analysis::is_subclass_of(exc_fqn, d, class_loader)
+    })
+}
+
+/// End offset of the statement starting at `start`: the first `;` or
+/// newline, whichever comes first, capped at `limit`.
+fn statement_end(content: &str, start: usize, limit: usize) -> usize {
+    let rest = &content[start..limit.max(start)];
+    let len = rest.find([';', '\n']).unwrap_or(rest.len());
+    start + rest[..len].trim_end().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let mut cfg = backend.config.lock().clone();
+        cfg.diagnostics.missing_throws = Some(true);
+        *backend.config.lock() = cfg;
+        let uri = "file:///test.php";
+        backend.update_ast(uri, php);
+        let mut out = Vec::new();
+        backend.collect_missing_throws_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    #[test]
+    fn off_by_default() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): void {
+        throw new \RuntimeException('x');
+    }
+}
+"#;
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, php);
+        let mut out = Vec::new();
+        backend.collect_missing_throws_diagnostics(uri, php, &mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn flags_undeclared_direct_throw() {
+        let php = r#"<?php
+class Bar {
+    public function foo(): void {
+        throw new \RuntimeException('x');
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "Exception 'RuntimeException' is neither caught nor declared with @throws"
+        );
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::HINT));
+        assert_eq!(diags[0].range.start.line, 3);
+        assert_eq!(diags[0].range.start.character, 8);
+    }
+
+    #[test]
+    fn flags_undeclared_propagated_throw() {
+        let php = r#"<?php
+class FooException extends \Exception {}
+class Bar {
+    /**
+     * @throws FooException
+     */
+    private function load(): void {
+        throw new FooException();
+    }
+
+    public function run(): void {
+        $this->load();
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1, "got: {diags:?}");
+        assert!(diags[0].message.contains("'FooException'"));
+        assert_eq!(diags[0].range.start.line, 11);
+    }
+
+    #[test]
+    fn no_diagnostic_when_declared() {
+        let php = r#"<?php
+class Bar {
+    /**
+     * @throws \InvalidArgumentException when the input is empty
+     */
+    public function foo(string $s): void {
+        throw new \InvalidArgumentException('empty');
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn no_diagnostic_when_caught() {
+        let php = r#"<?php
+function run(): void {
+    try {
+        throw new \RuntimeException('x');
+    } catch (\RuntimeException $e) {
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn parent_class_covers_catch_and_declaration() {
+        let php = r#"<?php
+class StorageException extends \RuntimeException {}
+class MissingFile extends StorageException {}
+class Store {
+    /**
+     * @throws StorageException
+     */
+    public function read(): void {
+        throw new MissingFile();
+    }
+
+    public function tryRead(): void {
+        try {
+            throw new MissingFile();
+        } catch (StorageException $e) {
+        }
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+}
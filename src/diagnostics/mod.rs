@@ -23,6 +23,8 @@
 //! 9. **`syntax_error`** — parser-level errors.
 //! 10. **`unresolved_*`** — the analyser couldn't determine the type of an
 //!     expression (opt-in coverage hints).
+//! 11. **`duplicate_*`** — a member is declared more than once in the same
+//!     scope (e.g. `duplicate_method_declaration`).
 //!
 //! Two delivery models are supported:
 //!
@@ -49,6 +51,15 @@
 //!   strikethrough in most editors).
 //! - **Unused `use` dimming** — dim `use` declarations that are not
 //!   referenced anywhere in the file with `DiagnosticTag::Unnecessary`.
+//! - **Duplicate method diagnostics** — report a method declared more
+//!   than once in the same class-like.  PHP refuses to compile such a
+//!   class, so the repeated declaration is flagged at its name.
+//! - **Abstract method body diagnostics** — report `abstract` methods
+//!   that also declare a `{ ... }` body.
+//! - **Missing return diagnostics** — report methods with a non-void
+//!   native return type whose body has no `return` statement.
+//! - **`$this` in static method diagnostics** — report `$this` used
+//!   directly inside a `static` method body.
 //!
 //! ## Phase 2 — slow (require type resolution)
 //!
@@ -59,6 +70,9 @@
 //!   the member does not exist on the resolved class after full
 //!   resolution (inheritance + virtual member providers).  Suppressed
 //!   when the class has `__call` / `__callStatic` / `__get` magic methods.
+//! - **Member visibility diagnostics** — report `private` / `protected`
+//!   members accessed from a class (or the global scope) that cannot
+//!   see them.  Runs inside the unknown-member walk.
 //! - **Unknown function diagnostics** — report function calls that
 //!   cannot be resolved to any known function definition.
 //! - **Undefined variable diagnostics** — report variable reads that
@@ -75,12 +89,23 @@
 //!   unresolved-member-access = true` in `.phpantom.toml`.  Uses
 //!   `Severity::HINT` to surface type-coverage gaps without drowning
 //!   the editor in warnings.
+//! - **Missing `@throws` diagnostics** (opt-in) — report exceptions
+//!   that are thrown (directly or through a called method's `@throws`)
+//!   without being caught or declared in the enclosing function's
+//!   docblock.  Off by default; enable via `[diagnostics]
+//!   missing-throws = true` in `.phpantom.toml`.  Uses
+//!   `Severity::HINT`.
+//! - **Non-exhaustive `match` diagnostics** — report `match`
+//!   expressions on an enum subject that leave some of the enum's cases
+//!   unhandled and have no `default` arm.
 //! - **Argument count diagnostics** — report calls where the number of
 //!   arguments does not match the function/method signature.
 //! - **Implementation error diagnostics** — report concrete classes that
 //!   fail to implement all required methods from their interfaces or
 //!   abstract parents.  Reuses the same missing-method detection as the
 //!   "Implement missing methods" code action.
+//! - **Final method override diagnostics** — report methods that
+//!   override a `final` method declared in an ancestor class.
 //!
 //! ## Phase 3 — heavy (external process, dedicated workers)
 //!
@@ -160,12 +185,20 @@
 //! External tool workers (PHPStan, PHPCS, Mago) use their own
 //! debounce timers in both modes because they are expensive.
 
+mod abstract_method_body;
 mod argument_count;
 mod deprecated;
+mod duplicate_methods;
+mod final_override;
 pub(crate) mod helpers;
 mod implementation_errors;
 mod invalid_class_kind;
+mod match_exhaustiveness;
+pub(crate) mod member_visibility;
+mod missing_return;
+mod missing_throws;
 mod syntax_errors;
+mod this_in_static;
 mod type_errors;
 pub(crate) mod undefined_variables;
 pub(crate) mod unknown_classes;
@@ -182,6 +215,7 @@ use tower_lsp::lsp_types::*;
 
 use crate::Backend;
 use crate::mago;
+use crate::parser::with_parse_cache;
 use crate::phpcs;
 use crate::phpstan;
 use crate::util::ranges_overlap;
@@ -199,21 +233,33 @@ impl Backend {
     }
 
     /// Collect Phase 1 (fast) diagnostics: syntax errors, unused
-    /// imports.  These are cheap — no type resolution.
+    /// imports, duplicate methods, abstract methods with bodies, missing
+    /// returns, `$this` in static methods.  These are cheap — no type
+    /// resolution.
     pub(crate) fn collect_fast_diagnostics(
         &self,
         uri_str: &str,
         content: &str,
         out: &mut Vec<Diagnostic>,
     ) {
+        // Activate the thread-local parse cache so that the fast
+        // collectors share one parsed AST instead of each re-parsing
+        // the file.
+        let _parse_guard = with_parse_cache(content);
+
         self.collect_syntax_error_diagnostics(uri_str, content, out);
         self.collect_unused_import_diagnostics(uri_str, content, out);
         self.collect_unused_variable_diagnostics(uri_str, content, out);
+        self.collect_duplicate_method_diagnostics(uri_str, content, out);
+        self.collect_abstract_method_body_diagnostics(uri_str, content, out);
+        self.collect_missing_return_diagnostics(uri_str, content, out);
+        self.collect_this_in_static_diagnostics(uri_str, content, out);
     }
 
     /// Collect Phase 2 (slow) diagnostics: unknown class/member/function,
-    /// argument count, implementation errors, deprecated usage.  These
-    /// require type resolution and are expensive.
+    /// argument count, implementation errors, deprecated usage, missing
+    /// `@throws` (opt-in).  These require type resolution and are
+    /// expensive.
     pub fn collect_slow_diagnostics(
         &self,
         uri_str: &str,
@@ -237,6 +283,7 @@ impl Backend {
         // call site in the file.
         let _callable_guard = crate::completion::call_resolution::with_callable_target_cache();
         let _body_infer_guard = self.activate_body_return_inferrer();
+        let _service_map_guard = self.activate_symfony_service_map();
 
         // ── Phase 2: forward-walked diagnostic scope cache ──────
         // Walk every function/method body in the file once with the
@@ -277,6 +324,9 @@ impl Backend {
         self.collect_deprecated_diagnostics(uri_str, content, out);
         self.collect_undefined_variable_diagnostics(uri_str, content, out);
         self.collect_invalid_class_kind_diagnostics(uri_str, content, out);
+        self.collect_final_override_diagnostics(uri_str, content, out);
+        self.collect_missing_throws_diagnostics(uri_str, content, out);
+        self.collect_match_exhaustiveness_diagnostics(uri_str, content, out);
     }
 }
 
@@ -0,0 +1,226 @@
+//! `$this` in static method diagnostic.
+//!
+//! Flags uses of `$this` directly inside the body of a `static` method,
+//! which fails at runtime with "Using $this when not in object context".
+//!
+//! Non-static closures and arrow functions nested in the static method
+//! are skipped: they can be bound to an object later with
+//! `Closure::bind()` / `Closure::call()`, so `$this` inside them may be
+//! valid.  `static function () {}` and `static fn () =>` can never be
+//! bound, so `$this` inside them is flagged like the method body itself.
+//! Anonymous class bodies are their own scope and are never visited.
+
+use mago_syntax::ast::*;
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::parser::{with_parse_cache, with_parsed_program};
+use crate::scope_collector::{FrameKind, collect_function_scope_with_kind};
+
+use super::helpers::{for_each_class_like_members, make_diagnostic};
+
+/// Diagnostic code used for `$this` inside a static method.
+pub(crate) const INVALID_THIS_IN_STATIC_CODE: &str = "invalid_this_in_static";
+
+impl Backend {
+    /// Collect `$this`-in-static-method diagnostics for a single file.
+    ///
+    /// Appends an Error-severity diagnostic on each offending `$this`
+    /// token to `out`.
+    pub fn collect_this_in_static_diagnostics(
+        &self,
+        uri: &str,
+        content: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        if self.should_skip_diagnostics(uri) {
+            return;
+        }
+
+        let _parse_guard = with_parse_cache(content);
+        with_parsed_program(content, "this_in_static", |program, content| {
+            let mut found: Vec<(String, u32)> = Vec::new();
+            for_each_class_like_members(program.statements.iter(), &mut |_, members| {
+                collect_from_members(members, &mut found);
+            });
+
+            for (method_name, offset) in found {
+                let start = offset as usize;
+                let Some(range) =
+                    self.offset_range_to_lsp_range(uri, content, start, start + "$this".len())
+                else {
+                    continue;
+                };
+                out.push(make_diagnostic(
+                    range,
+                    DiagnosticSeverity::ERROR,
+                    INVALID_THIS_IN_STATIC_CODE,
+                    format!("Cannot use $this in static method '{}'", method_name),
+                ));
+            }
+        });
+    }
+}
+
+/// Record `(method, this_offset)` for each `$this` in the body of a
+/// static method among `members`.
+fn collect_from_members(members: &[ClassLikeMember<'_>], found: &mut Vec<(String, u32)>) {
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+        if !method.modifiers.iter().any(|m| m.is_static()) {
+            continue;
+        }
+        let MethodBody::Concrete(block) = &method.body else {
+            continue;
+        };
+
+        let scope = collect_function_scope_with_kind(
+            &method.parameter_list,
+            block.statements.as_slice(),
+            block.left_brace.start.offset,
+            block.right_brace.end.offset,
+            FrameKind::Method,
+        );
+        if !scope.has_this_or_self {
+            continue;
+        }
+        let Some(frame) = scope.frames.iter().find(|f| f.kind == FrameKind::Method) else {
+            continue;
+        };
+
+        // A `$this` is reported when every frame between it and the
+        // method body is a static closure or arrow function.
+        for access in scope
+            .accesses
+            .iter()
+            .filter(|a| a.name == "$this" && a.offset >= frame.start && a.offset <= frame.end)
+        {
+            let bindable = scope.frames.iter().any(|f| {
+                f.start > frame.start
+                    && f.end < frame.end
+                    && access.offset >= f.start
+                    && access.offset <= f.end
+                    && f.kind != FrameKind::Catch
+                    && !f.is_static
+            });
+            if !bindable {
+                found.push((method.name.value.to_string(), access.offset));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::Backend;
+    use tower_lsp::lsp_types::*;
+
+    fn collect(php: &str) -> Vec<Diagnostic> {
+        let backend = Backend::new_test();
+        let uri = "file:///test.php";
+        backend.update_ast(uri, &Arc::new(php.to_string()));
+        let mut out = Vec::new();
+        backend.collect_this_in_static_diagnostics(uri, php, &mut out);
+        out
+    }
+
+    #[test]
+    fn flags_this_in_static_method() {
+        let php = r#"<?php
+class Foo {
+    public static function bar(): void {
+        $this->baz();
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Cannot use $this in static method 'bar'");
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diags[0].range.start.line, 3);
+        assert_eq!(diags[0].range.start.character, 8);
+        assert_eq!(diags[0].range.end.character, 13);
+    }
+
+    #[test]
+    fn flags_this_inside_catch_block() {
+        let php = r#"<?php
+class Foo {
+    public static function bar(): void {
+        try {
+        } catch (\Exception $e) {
+            $this->log($e);
+        }
+    }
+}
+"#;
+        assert_eq!(collect(php).len(), 1);
+    }
+
+    #[test]
+    fn no_diagnostic_in_instance_method() {
+        let php = r#"<?php
+class Foo {
+    public function bar(): void {
+        $this->baz();
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn no_diagnostic_inside_closure_or_anonymous_class() {
+        let php = r#"<?php
+class Foo {
+    public static function bar(): \Closure {
+        $obj = new class {
+            public function run(): self { return $this; }
+        };
+        return function () { return $this->value; };
+    }
+}
+"#;
+        assert!(collect(php).is_empty());
+    }
+
+    #[test]
+    fn flags_this_inside_static_closure_and_arrow_function() {
+        let php = r#"<?php
+class Foo {
+    public static function bar(): array {
+        $a = static function () { return $this->value; };
+        $b = static fn() => $this->value;
+        $c = static function () {
+            return function () { return $this->value; };
+        };
+        return [$a, $b, $c];
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].range.start.line, 3);
+        assert_eq!(diags[1].range.start.line, 4);
+    }
+
+    #[test]
+    fn flags_this_in_class_declared_inside_block() {
+        let php = r#"<?php
+{
+    class Foo {
+        public static function make(): void {
+            $this->bar();
+        }
+    }
+}
+"#;
+        let diags = collect(php);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Cannot use $this in static method 'make'");
+    }
+}
@@ -8,6 +8,12 @@
 //! incompatible types are flagged — when in doubt (unresolved types,
 //! `mixed`, complex generics), the diagnostic is suppressed to avoid
 //! false positives.
+//!
+//! Mismatches are errors in files that declare `strict_types=1`, where
+//! PHP throws a `TypeError`.  Other files downgrade scalar-to-scalar
+//! mismatches (`int`, `float`, `string`, `bool`) to warnings, since
+//! coercive mode may still convert the value at runtime.  Class and
+//! other non-scalar mismatches stay errors in every file.
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -706,6 +712,53 @@ fn is_refined_scalar_pair(arg: &PhpType, param: &PhpType) -> bool {
     )
 }
 
+/// Returns `true` when coercive typing mode may convert `arg` to
+/// `param` at runtime: every member of the argument is an `int`,
+/// `float`, `string` or `bool` type (or a literal of one), and the
+/// parameter accepts at least one of them.
+///
+/// `null` is not coerced for user-defined functions, and objects and
+/// arrays are never coerced, so those mismatches stay errors even
+/// without `strict_types=1`.
+fn is_scalar_coercible(arg: &PhpType, param: &PhpType) -> bool {
+    fn is_coercible_scalar(ty: &PhpType) -> bool {
+        match ty {
+            PhpType::Literal(_) | PhpType::IntRange(_, _) => true,
+            PhpType::Named(name) => matches!(
+                name.to_ascii_lowercase().as_str(),
+                "int"
+                    | "integer"
+                    | "positive-int"
+                    | "negative-int"
+                    | "non-negative-int"
+                    | "non-positive-int"
+                    | "non-zero-int"
+                    | "float"
+                    | "double"
+                    | "string"
+                    | "non-empty-string"
+                    | "numeric-string"
+                    | "literal-string"
+                    | "truthy-string"
+                    | "non-falsy-string"
+                    | "lowercase-string"
+                    | "non-empty-lowercase-string"
+                    | "bool"
+                    | "boolean"
+                    | "true"
+                    | "false"
+            ),
+            _ => false,
+        }
+    }
+
+    arg.union_members().into_iter().all(is_coercible_scalar)
+        && param.union_members().into_iter().any(|m| match m {
+            PhpType::Nullable(inner) => is_coercible_scalar(inner),
+            other => is_coercible_scalar(other),
+        })
+}
+
 /// Returns `true` when the type is any form of array (bare, generic,
 /// slice, or shape).  Used by the bare-array MAYBE rules to check
 /// inside unions as well as at the top level.
@@ -1382,6 +1435,8 @@ impl Backend {
             return;
         }
 
+        let strict_types = self.strict_types_map.read().get(uri).copied() == Some(true);
+
         let class_loader = self.class_loader(&file_ctx);
         let function_loader_cl = self.function_loader(&file_ctx);
         let constant_loader_cl = self.constant_loader();
@@ -1659,9 +1714,15 @@ impl Backend {
                     arg_type,
                 );
 
+                let severity = if !strict_types && is_scalar_coercible(arg_type, param_type) {
+                    DiagnosticSeverity::WARNING
+                } else {
+                    DiagnosticSeverity::ERROR
+                };
+
                 out.push(make_diagnostic(
                     range,
-                    DiagnosticSeverity::ERROR,
+                    severity,
                     TYPE_MISMATCH_ARGUMENT_CODE,
                     message,
                 ));
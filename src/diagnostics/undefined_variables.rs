@@ -44,8 +44,9 @@
 //!   exists.
 //! - **`$this`** inside a non-static method or closure — always
 //!   defined.
-//! - **`$this`** inside a static method or top-level code — flagged
-//!   separately by other tools; we skip it.
+//! - **`$this`** inside a static method or top-level code — static
+//!   methods are covered by the `invalid_this_in_static` diagnostic;
+//!   we skip it here.
 
 use std::collections::HashSet;
 
@@ -435,7 +436,7 @@ fn check_scope(
             }
 
             // Skip $this — even if not "defined", we don't flag it
-            // (static methods will have $this reads flagged by other tools).
+            // (static methods are handled by the this_in_static diagnostic).
             if access.name == "$this" {
                 continue;
             }
@@ -2107,7 +2108,7 @@ class Foo {
 
     #[test]
     fn no_diagnostic_for_this_in_static_method() {
-        // We don't flag $this in static methods — other tools handle that.
+        // We don't flag $this in static methods here — this_in_static does.
         let diags = collect(
             r#"<?php
 class Foo {
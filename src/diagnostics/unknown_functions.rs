@@ -401,6 +401,25 @@ function test(): void {
         );
     }
 
+    #[test]
+    fn no_diagnostic_for_dynamic_function_names() {
+        let php = r#"<?php
+function test(string $name, callable $cb): void {
+    $name();
+    $cb(1);
+    $handlers = ['a' => 'strtoupper'];
+    $handlers['a']('x');
+    ('str' . 'len')('x');
+}
+"#;
+        let diags = collect(php);
+        assert!(
+            diags.is_empty(),
+            "No diagnostics expected for dynamically named calls, got: {:?}",
+            diags,
+        );
+    }
+
     #[test]
     fn no_diagnostic_for_use_function_imported_call() {
         // Simulate the PHPUnit pattern: a namespaced function is defined
@@ -27,6 +27,10 @@
 //!   accesses that only exist on host classes produces a high rate of
 //!   false positives.
 //!
+//! Members that do exist are also checked for visibility (private or
+//! protected access from outside the allowed scope); see
+//! [`super::member_visibility`].
+//!
 //! ## Performance: subject resolution cache
 //!
 //! A single file can contain hundreds of member access spans that share
@@ -612,6 +616,28 @@ impl Backend {
                         } else {
                             (result, coarse_diags)
                         };
+
+                    // ── Visibility check on members that exist ──────
+                    if result == MemberCheckResult::Ok
+                        && diags.is_empty()
+                        && !is_docblock_ref
+                        && !subject_text_is_rooted_in_self(subject_text)
+                        && let Some(diag) = self.check_member_visibility(
+                            uri,
+                            content,
+                            base_classes,
+                            current_class,
+                            member_name,
+                            is_static,
+                            is_method_call,
+                            &class_loader,
+                            resolved_cache,
+                            span.start,
+                            span.end,
+                        )
+                    {
+                        out.push(diag);
+                    }
                     out.extend(diags);
 
                     // Only break the chain when the member is truly
@@ -47,19 +47,21 @@ pub(crate) use tags::is_compatible_refinement_typed;
 pub use tags::{
     extract_all_param_tags, extract_all_param_tags_from_info, extract_deprecation_message,
     extract_deprecation_message_from_info, extract_deprecation_with_see,
-    extract_deprecation_with_see_from_info, extract_if_this_is_type, extract_link_urls,
-    extract_link_urls_from_info, extract_mixin_tags, extract_mixin_tags_from_info,
-    extract_param_closure_this, extract_param_closure_this_from_info, extract_param_description,
-    extract_param_description_from_info, extract_param_raw_type, extract_param_raw_type_from_info,
-    extract_param_types_positional_from_info, extract_removed_version, extract_return_description,
-    extract_return_description_from_info, extract_return_type, extract_return_type_from_info,
-    extract_see_references, extract_see_references_from_info, extract_throws_tags,
-    extract_throws_tags_from_info, extract_type_assertions, extract_type_assertions_from_info,
-    extract_var_type, extract_var_type_from_info, extract_var_type_with_name,
-    extract_var_type_with_name_from_info, find_enclosing_return_type, find_inline_var_docblock,
+    extract_deprecation_with_see_from_info, extract_global_type, extract_if_this_is_type,
+    extract_link_urls, extract_link_urls_from_info, extract_mixin_tags,
+    extract_mixin_tags_from_info, extract_param_closure_this, extract_param_closure_this_from_info,
+    extract_param_description, extract_param_description_from_info, extract_param_raw_type,
+    extract_param_raw_type_from_info, extract_param_types_positional_from_info,
+    extract_removed_version, extract_return_description, extract_return_description_from_info,
+    extract_return_type, extract_return_type_from_info, extract_see_references,
+    extract_see_references_from_info, extract_throws_tags, extract_throws_tags_from_info,
+    extract_type_assertions, extract_type_assertions_from_info, extract_var_type,
+    extract_var_type_from_info, extract_var_type_with_name, extract_var_type_with_name_from_info,
+    find_enclosing_return_type, find_global_type_in_source, find_inline_var_docblock,
     find_iterable_raw_type_in_source, find_var_raw_type_in_source, get_docblock_info_for_node,
-    get_docblock_text_for_node, has_deprecated_tag, has_deprecated_tag_from_info,
-    resolve_effective_type_typed, sanitise_and_parse_docblock_type, should_override_type_typed,
+    get_docblock_text_for_node, has_deprecated_tag, has_deprecated_tag_from_info, has_internal_tag,
+    has_internal_tag_from_info, resolve_effective_type_typed, sanitise_and_parse_docblock_type,
+    should_override_type_typed,
 };
 
 // Template / generics / type alias tags
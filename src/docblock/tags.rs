@@ -97,6 +97,18 @@ pub fn has_deprecated_tag_from_info(info: &DocblockInfo) -> bool {
     extract_deprecation_message_from_info(info).is_some()
 }
 
+/// Check whether a PHPDoc block contains an `@internal` tag.
+pub fn has_internal_tag(docblock: &str) -> bool {
+    parse_docblock_for_tags(docblock).is_some_and(|info| has_internal_tag_from_info(&info))
+}
+
+/// Like [`has_internal_tag`], but operates on a pre-parsed [`DocblockInfo`].
+pub fn has_internal_tag_from_info(info: &DocblockInfo) -> bool {
+    // Matched by name so the check does not depend on how mago-docblock
+    // classifies the tag.
+    info.tags.iter().any(|t| t.name == "internal")
+}
+
 /// Extract the type from a `@psalm-if-this-is` or `@phpstan-if-this-is` tag.
 ///
 /// `@psalm-if-this-is ArrayList<TOption|TEither>` returns
@@ -618,6 +630,10 @@ pub fn find_var_raw_type_in_source(
 /// Given a docblock and a parameter name (with `$` prefix), returns the
 /// raw type string including generic parameters.
 ///
+/// For variadic parameters (`...$users`) the tag type describes each
+/// argument, so a trailing `[]` is stripped: `@param User[] ...$users`
+/// yields `User`.
+///
 /// Example:
 ///   docblock containing `@param list<User> $users` with var_name `"$users"`
 ///   → `Some("list<User>")`
@@ -647,9 +663,18 @@ pub fn extract_param_raw_type_from_info(info: &DocblockInfo, var_name: &str) ->
             // The next token should be the parameter name.
             // Handle `...$name` (variadic) by stripping the leading `...`.
             if let Some(name) = remainder.split_whitespace().next() {
-                let name = name.strip_prefix("...").unwrap_or(name);
+                let (name, is_variadic) = match name.strip_prefix("...") {
+                    Some(stripped) => (stripped, true),
+                    None => (name, false),
+                };
                 if name == var_name {
-                    return sanitise_and_parse_docblock_type(type_token);
+                    let parsed = sanitise_and_parse_docblock_type(type_token);
+                    // `@param User[] ...$users` annotates the whole
+                    // argument list; the element type is `User`.
+                    if is_variadic && let Some(PhpType::Array(inner)) = parsed {
+                        return Some(*inner);
+                    }
+                    return parsed;
                 }
             }
         }
@@ -1127,11 +1152,21 @@ pub fn find_iterable_raw_type_in_source(
                     // Extract the full type token (respects `<…>` nesting).
                     let (type_token, remainder) = split_type_token(rest);
 
-                    // The next token must be our variable name.
-                    if let Some(name) = remainder.split_whitespace().next()
-                        && name == var_name
-                    {
-                        return Some(PhpType::parse(type_token));
+                    // The next token must be our variable name, possibly
+                    // prefixed with `...` for a variadic `@param`.
+                    if let Some(name) = remainder.split_whitespace().next() {
+                        let (name, is_variadic) = match name.strip_prefix("...") {
+                            Some(stripped) => (stripped, true),
+                            None => (name, false),
+                        };
+                        if name == var_name {
+                            let parsed = PhpType::parse(type_token);
+                            // `@param User[] ...$users` names the element type `User`.
+                            if is_variadic && let PhpType::Array(inner) = parsed {
+                                return Some(*inner);
+                            }
+                            return Some(parsed);
+                        }
                     }
                 }
             }
@@ -1203,6 +1238,14 @@ pub fn find_iterable_raw_type_in_source(
 /// Returns `None` when no enclosing function docblock or `@return` tag
 /// can be found.
 pub fn find_enclosing_return_type(content: &str, cursor_offset: usize) -> Option<PhpType> {
+    extract_return_type(find_enclosing_function_docblock(content, cursor_offset)?)
+}
+
+/// Locate the docblock of the function or method enclosing `cursor_offset`.
+///
+/// Shared by [`find_enclosing_return_type`] and
+/// [`find_global_type_in_source`].
+fn find_enclosing_function_docblock(content: &str, cursor_offset: usize) -> Option<&str> {
     let search_area = content.get(..cursor_offset)?;
 
     // Walk backward, tracking brace depth.  We start inside a function
@@ -1262,9 +1305,53 @@ pub fn find_enclosing_return_type(content: &str, cursor_offset: usize) -> Option
     }
 
     let open_pos = after_mods.rfind("/**")?;
-    let docblock = after_mods.get(open_pos..)?;
+    after_mods.get(open_pos..)
+}
+
+/// Extract the type of `var_name` (with `$` prefix) from a
+/// `@global Type $var` tag.
+///
+/// `@global` is the WordPress / phpDocumentor convention for documenting
+/// variables imported with the `global` keyword.
+pub fn extract_global_type(docblock: &str, var_name: &str) -> Option<PhpType> {
+    let info = parse_docblock_for_tags(docblock)?;
+    // Matched by name so the check does not depend on how mago-docblock
+    // classifies the tag.
+    info.tags
+        .iter()
+        .filter(|t| t.name == "global")
+        .find_map(|tag| {
+            let (type_token, remainder) = split_type_token(tag.description.trim());
+            if remainder.split_whitespace().next() == Some(var_name) {
+                sanitise_and_parse_docblock_type(type_token)
+            } else {
+                None
+            }
+        })
+}
 
-    extract_return_type(docblock)
+/// Find a `@global Type $var` annotation for a `global $var;` statement
+/// starting at `stmt_offset`.
+///
+/// Checks an inline docblock directly above the statement first, then
+/// the docblock of the enclosing function or method.
+pub fn find_global_type_in_source(
+    content: &str,
+    stmt_offset: usize,
+    var_name: &str,
+) -> Option<PhpType> {
+    let before = content.get(..stmt_offset)?.trim_end();
+    if before.ends_with("*/")
+        && let Some(open_pos) = before.rfind("/**")
+        && let Some(ty) = extract_global_type(&before[open_pos..], var_name)
+    {
+        return Some(ty);
+    }
+
+    extract_global_type(
+        find_enclosing_function_docblock(content, stmt_offset)?,
+        var_name,
+    )
 }
 
 // ─── Type Override Logic ────────────────────────────────────────────────────
@@ -1379,6 +1466,18 @@ fn has_parameterisation(ty: &PhpType) -> bool {
 /// Unlike `base_name()` this includes scalar names (`array`, `int`, ...)
 /// which are needed for the refinement checks.
 pub(crate) fn is_compatible_refinement_typed(doc_type: &PhpType, native_type: &PhpType) -> bool {
+    // A union refines a native scalar when every member does, e.g.
+    // `'active'|'inactive'` for `string`.
+    if let PhpType::Union(members) = doc_type
+        && (native_type.is_string_type()
+            || native_type.is_int()
+            || native_type.is_float()
+            || native_type.is_bool())
+    {
+        return members
+            .iter()
+            .all(|m| is_compatible_refinement_typed(m, native_type));
+    }
     if native_type.is_string_type() {
         return doc_type.is_string_subtype();
     }
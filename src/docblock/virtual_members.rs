@@ -237,11 +237,13 @@ pub fn extract_method_tags(docblock: &str) -> Vec<MethodInfo> {
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: Vec::new(),
             template_param_bounds: AtomMap::default(),
             template_bindings: Vec::new(),
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: true,
             type_assertions: Vec::new(),
             throws: Vec::new(),
@@ -0,0 +1,304 @@
+//! Document colors (`textDocument/documentColor` and
+//! `textDocument/colorPresentation`).
+//!
+//! PHP has no color literals, but many frameworks accept CSS color
+//! strings such as `'#ff0000'` or `'rgb(255, 0, 0)'`.  Hex colors
+//! (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`) and `rgb(…)` / `rgba(…)`
+//! values inside plain string literals are reported as color
+//! information, so the editor can show a swatch and an inline color
+//! picker.  The string literal spans come from the symbol map;
+//! interpolated strings and heredocs are not scanned.
+//!
+//! When the user picks a color, the presentations offer it in hex and in
+//! `rgb()` notation, with the notation already in the document first.
+
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+use crate::util::{byte_range_to_lsp_range, position_to_offset};
+
+impl Backend {
+    /// Handle a `textDocument/documentColor` request.
+    ///
+    /// Returns one [`ColorInformation`] per color value found inside the
+    /// file's string literals.
+    pub fn handle_document_color(&self, uri: &str, content: &str) -> Option<Vec<ColorInformation>> {
+        let maps = self.symbol_maps.read();
+        let literals = &maps.get(uri)?.string_literals;
+
+        let mut colors = Vec::new();
+        for &(start, end) in literals {
+            let start = start as usize;
+            let Some(text) = content.get(start..end as usize) else {
+                continue;
+            };
+            for (offset, len, color) in find_colors(text) {
+                colors.push(ColorInformation {
+                    range: byte_range_to_lsp_range(content, start + offset, start + offset + len),
+                    color,
+                });
+            }
+        }
+        Some(colors)
+    }
+
+    /// Handle a `textDocument/colorPresentation` request.
+    ///
+    /// Offers `color` as a hex string and as `rgb()` / `rgba()`, each
+    /// replacing `range`.  The notation currently written at `range`
+    /// comes first so that picking a color keeps the existing style.
+    pub fn handle_color_presentation(
+        &self,
+        content: &str,
+        color: Color,
+        range: Range,
+    ) -> Vec<ColorPresentation> {
+        let start = position_to_offset(content, range.start) as usize;
+        let end = position_to_offset(content, range.end) as usize;
+        let current = content.get(start..end).unwrap_or("");
+
+        let hex = format_hex(color);
+        let rgb = format_rgb(color);
+        let labels = if starts_with_ignore_case(current, "rgb") {
+            [rgb, hex]
+        } else {
+            [hex, rgb]
+        };
+
+        labels
+            .into_iter()
+            .map(|label| ColorPresentation {
+                text_edit: Some(TextEdit {
+                    range,
+                    new_text: label.clone(),
+                }),
+                label,
+                additional_text_edits: None,
+            })
+            .collect()
+    }
+}
+
+/// Find the color values in the text of one string literal.
+///
+/// Returns `(byte offset, byte length, color)` for each match.  A match
+/// must not directly follow a word character, so `&#123;` and
+/// `foo#abc` are not mistaken for colors.
+fn find_colors(text: &str) -> Vec<(usize, usize, Color)> {
+    let bytes = text.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let at_boundary = i == 0 || !is_word_byte(bytes[i - 1]);
+        let parsed = match bytes[i] {
+            b'#' if at_boundary => parse_hex(&bytes[i..]),
+            b'r' | b'R' if at_boundary => parse_rgb(&text[i..]),
+            _ => None,
+        };
+        match parsed {
+            Some((len, color)) => {
+                found.push((i, len, color));
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+    found
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'&' | b'#')
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
+
+/// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` at the start of
+/// `bytes`, returning the match length and the color.
+fn parse_hex(bytes: &[u8]) -> Option<(usize, Color)> {
+    let digits = bytes[1..]
+        .iter()
+        .take_while(|b| b.is_ascii_hexdigit())
+        .count();
+    let end = 1 + digits;
+    if bytes.get(end).is_some_and(|b| is_word_byte(*b)) {
+        return None;
+    }
+
+    let hex = &bytes[1..end];
+    let width = match digits {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let channel = |idx: usize| -> f32 {
+        let part = &hex[idx * width..(idx + 1) * width];
+        let value = part.iter().fold(0u32, |acc, b| {
+            acc * 16 + (*b as char).to_digit(16).unwrap_or(0)
+        });
+        let max = if width == 1 { 15.0 } else { 255.0 };
+        value as f32 / max
+    };
+    let alpha = if digits == 4 || digits == 8 {
+        channel(3)
+    } else {
+        1.0
+    };
+
+    Some((
+        end,
+        Color {
+            red: channel(0),
+            green: channel(1),
+            blue: channel(2),
+            alpha,
+        },
+    ))
+}
+
+/// Parse `rgb(r, g, b)` or `rgba(r, g, b, a)` at the start of `text`,
+/// returning the match length and the color.
+///
+/// Components may be separated by commas or spaces (with `/` before the
+/// alpha in the space-separated form).  Color channels are `0`–`255`
+/// or percentages; alpha is `0`–`1` or a percentage.
+fn parse_rgb(text: &str) -> Option<(usize, Color)> {
+    let open = if starts_with_ignore_case(text, "rgba(") {
+        5
+    } else if starts_with_ignore_case(text, "rgb(") {
+        4
+    } else {
+        return None;
+    };
+    let close = open + text[open..].find(')')?;
+
+    let parts: Vec<&str> = text[open..close]
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+    if parts.len() != 3 && parts.len() != 4 {
+        return None;
+    }
+
+    let alpha = match parts.get(3) {
+        Some(part) => parse_component(part, 1.0)?,
+        None => 1.0,
+    };
+    Some((
+        close + 1,
+        Color {
+            red: parse_component(parts[0], 255.0)?,
+            green: parse_component(parts[1], 255.0)?,
+            blue: parse_component(parts[2], 255.0)?,
+            alpha,
+        },
+    ))
+}
+
+/// Parse one `rgb()` component in the range `0..=max`, or a percentage,
+/// as a fraction in `0.0..=1.0`.
+fn parse_component(part: &str, max: f32) -> Option<f32> {
+    let (number, max) = match part.strip_suffix('%') {
+        Some(number) => (number, 100.0),
+        None => (part, max),
+    };
+    let value: f32 = number.parse().ok()?;
+    (0.0..=max).contains(&value).then(|| value / max)
+}
+
+fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Format `color` as `#rrggbb`, or `#rrggbbaa` when it is translucent.
+fn format_hex(color: Color) -> String {
+    let rgb = format!(
+        "#{:02x}{:02x}{:02x}",
+        to_byte(color.red),
+        to_byte(color.green),
+        to_byte(color.blue)
+    );
+    match to_byte(color.alpha) {
+        255 => rgb,
+        alpha => format!("{}{:02x}", rgb, alpha),
+    }
+}
+
+/// Format `color` as `rgb(r, g, b)`, or `rgba(r, g, b, a)` when it is
+/// translucent.
+fn format_rgb(color: Color) -> String {
+    let (r, g, b) = (
+        to_byte(color.red),
+        to_byte(color.green),
+        to_byte(color.blue),
+    );
+    if color.alpha >= 1.0 {
+        format!("rgb({}, {}, {})", r, g, b)
+    } else {
+        let alpha = (color.alpha.clamp(0.0, 1.0) * 100.0).round() / 100.0;
+        format!("rgba({}, {}, {}, {})", r, g, b, alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes_of(color: Color) -> [u8; 4] {
+        [
+            to_byte(color.red),
+            to_byte(color.green),
+            to_byte(color.blue),
+            to_byte(color.alpha),
+        ]
+    }
+
+    #[test]
+    fn finds_hex_colors_of_every_length() {
+        let found = find_colors("'#f00 #f008 #00ff00 #0000ff80'");
+        let spans: Vec<(usize, usize)> = found.iter().map(|(o, l, _)| (*o, *l)).collect();
+        assert_eq!(spans, vec![(1, 4), (6, 5), (12, 7), (20, 9)]);
+        assert_eq!(bytes_of(found[0].2), [255, 0, 0, 255]);
+        assert_eq!(bytes_of(found[1].2), [255, 0, 0, 136]);
+        assert_eq!(bytes_of(found[2].2), [0, 255, 0, 255]);
+        assert_eq!(bytes_of(found[3].2), [0, 0, 255, 128]);
+    }
+
+    #[test]
+    fn rejects_non_color_hashes() {
+        assert!(find_colors("'#12345'").is_empty());
+        assert!(find_colors("'#fffg'").is_empty());
+        assert!(find_colors("'&#123;'").is_empty());
+        assert!(find_colors("'issue#abc'").is_empty());
+    }
+
+    #[test]
+    fn finds_rgb_functions() {
+        let found = find_colors("\"rgb(255, 0, 0) RGBA(0 0 255 / 50%)\"");
+        let spans: Vec<(usize, usize)> = found.iter().map(|(o, l, _)| (*o, *l)).collect();
+        assert_eq!(spans, vec![(1, 14), (16, 19)]);
+        assert_eq!(bytes_of(found[0].2), [255, 0, 0, 255]);
+        assert_eq!(bytes_of(found[1].2), [0, 0, 255, 128]);
+
+        assert!(find_colors("'rgb(300, 0, 0)'").is_empty());
+        assert!(find_colors("'rgb(1, 2)'").is_empty());
+    }
+
+    #[test]
+    fn formats_opaque_and_translucent_colors() {
+        let red = Color {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        assert_eq!(format_hex(red), "#ff0000");
+        assert_eq!(format_rgb(red), "rgb(255, 0, 0)");
+
+        let translucent = Color { alpha: 0.5, ..red };
+        assert_eq!(format_hex(translucent), "#ff000080");
+        assert_eq!(format_rgb(translucent), "rgba(255, 0, 0, 0.5)");
+    }
+}
@@ -1,7 +1,12 @@
 //! Document Link (`textDocument/documentLink`) support.
 //!
-//! Provides clickable links for `require` / `require_once` / `include` /
-//! `include_once` paths that resolve to existing files on disk.
+//! Provides clickable links for:
+//! - `require` / `require_once` / `include` / `include_once` paths that
+//!   resolve to existing files on disk,
+//! - class names in `use` imports, targeting the file that declares the
+//!   imported class,
+//! - `@see` and `@link` targets in docblocks (URLs, and class names for
+//!   `@see`).
 
 use std::path::{Path, PathBuf};
 
@@ -11,7 +16,8 @@ use mago_syntax::ast::*;
 use tower_lsp::lsp_types::{DocumentLink, Range, Url};
 
 use crate::Backend;
-use crate::util::offset_to_position;
+use crate::symbol_map::{ClassRefContext, SymbolKind};
+use crate::util::{offset_to_position, resolve_to_fqn};
 
 /// A resolved include/require path with its source range in the document.
 struct IncludeLink {
@@ -27,7 +33,9 @@ struct IncludeLink {
 impl Backend {
     /// Handle a `textDocument/documentLink` request.
     ///
-    /// Parses the file and walks the AST for include/require expressions.
+    /// Parses the file and walks the AST for include/require expressions,
+    /// then adds links for `use` imports and docblock `@see` / `@link`
+    /// targets.
     pub fn handle_document_link(&self, uri: &str, content: &str) -> Option<Vec<DocumentLink>> {
         let file_path = Url::parse(uri).ok().and_then(|u| u.to_file_path().ok());
         let file_dir = file_path.as_deref().and_then(|p| p.parent());
@@ -57,8 +65,145 @@ impl Backend {
             }
         }
 
+        self.collect_use_import_links(uri, content, &mut links);
+        for trivia in program.trivia.iter() {
+            if trivia.kind == TriviaKind::DocBlockComment {
+                self.collect_docblock_links(
+                    uri,
+                    content,
+                    trivia.value,
+                    trivia.span.start.offset as usize,
+                    &mut links,
+                );
+            }
+        }
+
         if links.is_empty() { None } else { Some(links) }
     }
+
+    /// Link every class name in a `use` import to the file that declares
+    /// the class.  Imports that resolve to stubs or cannot be found are
+    /// skipped.
+    fn collect_use_import_links(&self, uri: &str, content: &str, links: &mut Vec<DocumentLink>) {
+        let Some(symbol_map) = self.symbol_maps.read().get(uri).cloned() else {
+            return;
+        };
+
+        for span in &symbol_map.spans {
+            let SymbolKind::ClassReference {
+                name,
+                context: ClassRefContext::UseImport,
+                ..
+            } = &span.kind
+            else {
+                continue;
+            };
+            if let Some(target) = self.class_file_url(name, uri, content) {
+                links.push(DocumentLink {
+                    range: Range {
+                        start: offset_to_position(content, span.start as usize),
+                        end: offset_to_position(content, span.end as usize),
+                    },
+                    target: Some(target),
+                    tooltip: Some(name.clone()),
+                    data: None,
+                });
+            }
+        }
+    }
+
+    /// Link the targets of `@see` and `@link` tags in one docblock.
+    ///
+    /// URLs are linked as-is.  `@see` also accepts a class name (or a
+    /// `Class::member` reference, linked to the class), resolved through
+    /// the file's imports and namespace.
+    fn collect_docblock_links(
+        &self,
+        uri: &str,
+        content: &str,
+        docblock: &str,
+        docblock_offset: usize,
+        links: &mut Vec<DocumentLink>,
+    ) {
+        for (tag, target_start, target_text) in docblock_link_targets(docblock) {
+            let target =
+                if target_text.starts_with("http://") || target_text.starts_with("https://") {
+                    Url::parse(target_text).ok()
+                } else if tag == "@see" {
+                    let class_name = target_text.split("::").next().unwrap_or(target_text);
+                    if class_name.is_empty() {
+                        continue;
+                    }
+                    let start = docblock_offset + target_start;
+                    let namespace = self.namespace_at_offset(uri, start as u32);
+                    let fqn = resolve_to_fqn(class_name, &self.file_use_map(uri), &namespace);
+                    self.class_file_url(&fqn, uri, content)
+                } else {
+                    None
+                };
+
+            if let Some(target) = target {
+                let start = docblock_offset + target_start;
+                let end = start + target_text.len();
+                links.push(DocumentLink {
+                    range: Range {
+                        start: offset_to_position(content, start),
+                        end: offset_to_position(content, end),
+                    },
+                    target: Some(target),
+                    tooltip: None,
+                    data: None,
+                });
+            }
+        }
+    }
+
+    /// The `file://` URL of the file declaring `fqn`, loading the class
+    /// through the usual resolution chain if needed.
+    fn class_file_url(&self, fqn: &str, uri: &str, content: &str) -> Option<Url> {
+        self.find_or_load_class(fqn)?;
+        let (class_uri, _) = self.find_class_file_content(fqn, uri, content)?;
+        Url::parse(&class_uri)
+            .ok()
+            .filter(|url| url.scheme() == "file")
+    }
+}
+
+// ─── Docblock @see / @link ──────────────────────────────────────────────────
+
+/// Find `@see` and `@link` tags in a docblock and return
+/// `(tag, target_offset, target_text)` for each, where `target_offset`
+/// is relative to the start of the docblock.
+///
+/// The target is the first whitespace-delimited word after the tag, with
+/// a trailing `()` (as in `@see Foo::bar()`) and inline-tag braces
+/// removed.
+fn docblock_link_targets(docblock: &str) -> Vec<(&'static str, usize, &str)> {
+    let mut targets = Vec::new();
+    for tag in ["@see", "@link"] {
+        let mut search_from = 0;
+        while let Some(found) = docblock[search_from..].find(tag) {
+            let tag_end = search_from + found + tag.len();
+            search_from = tag_end;
+
+            // Reject longer tag names such as `@seeAlso`.
+            let rest = &docblock[tag_end..];
+            if !rest.starts_with([' ', '\t']) {
+                continue;
+            }
+            let trimmed = rest.trim_start_matches([' ', '\t']);
+            let target_start = tag_end + (rest.len() - trimmed.len());
+            let word_len = trimmed
+                .find(|c: char| c.is_whitespace() || c == '}')
+                .unwrap_or(trimmed.len());
+            let word = &trimmed[..word_len];
+            let word = word.strip_suffix("()").unwrap_or(word);
+            if !word.is_empty() && word != "*/" {
+                targets.push((tag, target_start, word));
+            }
+        }
+    }
+    targets
 }
 
 // ─── AST walking for include/require ────────────────────────────────────────
@@ -420,6 +565,28 @@ fn strip_quotes(s: &str) -> &str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_docblock_link_targets() {
+        let doc = "/**\n * @see Foo::bar()\n * @link https://example.com/docs\n * {@see Baz}\n */";
+        let targets = docblock_link_targets(doc);
+        let found: Vec<(&str, &str)> = targets.iter().map(|(t, _, w)| (*t, *w)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("@see", "Foo::bar"),
+                ("@see", "Baz"),
+                ("@link", "https://example.com/docs"),
+            ]
+        );
+        let (_, start, word) = targets[2];
+        assert_eq!(&doc[start..start + word.len()], "https://example.com/docs");
+    }
+
+    #[test]
+    fn test_docblock_link_targets_ignores_longer_tags() {
+        assert!(docblock_link_targets("/** @seeAlso Foo */").is_empty());
+    }
+
     #[test]
     fn test_strip_quotes_single() {
         assert_eq!(strip_quotes("'hello.php'"), "hello.php");
@@ -518,11 +518,13 @@ mod tests {
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: vec![],
             template_param_bounds: Default::default(),
             template_bindings: vec![],
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: false,
             type_assertions: vec![],
             throws: vec![],
@@ -572,11 +574,13 @@ mod tests {
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: vec![],
             template_param_bounds: Default::default(),
             template_bindings: vec![],
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: false,
             type_assertions: vec![],
             throws: vec![],
@@ -611,6 +615,7 @@ mod tests {
             is_abstract: false,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             links: Vec::new(),
             see_refs: Vec::new(),
             template_params: vec![],
@@ -654,6 +659,7 @@ mod tests {
             is_abstract: false,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             links: Vec::new(),
             see_refs: Vec::new(),
             template_params: vec![],
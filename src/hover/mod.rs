@@ -197,8 +197,12 @@ pub(crate) fn find_declaring_class(
         return Arc::new(owner.clone());
     }
 
-    // Check traits used by the owner.
+    // Check traits used by the owner, skipping any whose version of the
+    // member lost an `insteadof` conflict resolution.
     for trait_name in &owner.used_traits {
+        if owner.trait_method_excluded(trait_name, member_name) {
+            continue;
+        }
         if let Some(trait_class) = class_loader(trait_name) {
             let has = match member_kind {
                 MemberKindForOrigin::Method => trait_class
@@ -229,6 +233,9 @@ pub(crate) fn find_declaring_class(
         if let Some(ancestor) = class_loader(name) {
             // Check traits on the ancestor first.
             for trait_name in &ancestor.used_traits {
+                if ancestor.trait_method_excluded(trait_name, member_name) {
+                    continue;
+                }
                 if let Some(trait_class) = class_loader(trait_name) {
                     let has = match member_kind {
                         MemberKindForOrigin::Method => trait_class
@@ -447,6 +454,7 @@ impl Backend {
     /// fails or the cursor is not on a navigable symbol.
     pub fn handle_hover(&self, uri: &str, content: &str, position: Position) -> Option<Hover> {
         let _body_infer_guard = self.activate_body_return_inferrer();
+        let _service_map_guard = self.activate_symfony_service_map();
         let offset = crate::util::position_to_offset(content, position);
 
         // Try the exact cursor offset first.
@@ -472,7 +480,66 @@ impl Backend {
             return Some(hover);
         }
 
-        None
+        self.hover_use_alias(uri, content, offset)
+    }
+
+    /// Produce hover information for the alias of a `use` import.
+    ///
+    /// The symbol map only records the imported name in
+    /// `use App\Foo\Bar as B;`, so hovering `B` is handled here: the
+    /// alias is looked up in the file's use map and the hover shows
+    /// which class it stands for, followed by the usual class hover.
+    fn hover_use_alias(&self, uri: &str, content: &str, offset: u32) -> Option<Hover> {
+        let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+        let bytes = content.as_bytes();
+        let mut start = (offset as usize).min(bytes.len());
+        if !content.is_char_boundary(start) {
+            return None;
+        }
+        while start > 0 && is_ident(bytes[start - 1]) {
+            start -= 1;
+        }
+        let mut end = start;
+        while end < bytes.len() && is_ident(bytes[end]) {
+            end += 1;
+        }
+        if start == end {
+            return None;
+        }
+        let alias = &content[start..end];
+
+        // The alias must directly follow the `as` keyword.
+        let before = content[..start].trim_end();
+        let as_start = before.len().checked_sub(2)?;
+        if !before.is_char_boundary(as_start) || !before[as_start..].eq_ignore_ascii_case("as") {
+            return None;
+        }
+        let before_as = &before[..as_start];
+        if !before_as.ends_with(|c: char| c.is_whitespace()) {
+            return None;
+        }
+
+        let fqn = self.file_use_map(uri).get(alias)?.clone();
+        if !before_as.trim_end().ends_with(short_name(&fqn)) {
+            return None;
+        }
+
+        let mut lines = vec![format!("`{}` is an alias of `{}`", alias, fqn)];
+        let ctx = self.file_context(uri);
+        let class_loader = self.class_loader(&ctx);
+        if let Some(cls) = class_loader(&fqn)
+            && let HoverContents::Markup(class_hover) =
+                self.hover_for_class_info(&cls, uri, content).contents
+        {
+            lines.push(class_hover.value);
+        }
+
+        let mut hover = make_hover(lines.join("\n\n"));
+        hover.range = Some(Range {
+            start: crate::util::offset_to_position(content, start),
+            end: crate::util::offset_to_position(content, end),
+        });
+        Some(hover)
     }
 
     /// Dispatch a symbol-map hit to the appropriate hover path.
@@ -1020,8 +1087,13 @@ impl Backend {
             loaders,
         );
 
+        // Nothing could be inferred: say so explicitly rather than
+        // showing a bare variable name.
         if resolved.is_empty() {
-            return Some(make_hover(format!("```php\n<?php\n{}\n```", var_name)));
+            return Some(make_hover(format!(
+                "```php\n<?php\n{} = mixed\n```",
+                var_name
+            )));
         }
 
         let joined = ResolvedType::types_joined(&resolved);
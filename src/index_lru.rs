@@ -0,0 +1,209 @@
+//! Least-recently-used bookkeeping for lazily loaded files.
+//!
+//! Very large projects can load more classes than fit comfortably in
+//! memory.  When `[indexing] max-entries` is set, [`IndexLru`] records
+//! when each lazily parsed file was loaded or had one of its classes
+//! looked up, and picks the least recently used files to drop once the
+//! limit is exceeded.  Dropped files are re-parsed from disk on their
+//! next lookup through the class index, classmap, or PSR-4 mapping.
+//!
+//! Eviction trims the index to 90% of the limit so that the sort over
+//! all tracked files runs once per batch rather than on every load.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+
+/// Recency tracker for files in `ast_map` / `fqn_index`.
+///
+/// Internally synchronised so that the hot class-lookup path can check
+/// [`is_enabled`](Self::is_enabled) with a single atomic load.
+#[derive(Debug, Default)]
+pub(crate) struct IndexLru {
+    /// Maximum number of tracked files.  `0` means unlimited.
+    max_entries: AtomicUsize,
+    /// Set once the first eviction has been logged.
+    warned: AtomicBool,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    /// Monotonic counter used as the access timestamp.
+    clock: u64,
+    /// File URI → last access timestamp.
+    last_used: HashMap<String, u64>,
+    /// Class FQN → URI of the file that defines it.
+    fqn_to_uri: HashMap<String, String>,
+}
+
+impl IndexLru {
+    /// Update the limit from the `[indexing] max-entries` setting.
+    pub(crate) fn set_limit(&self, max_entries: Option<usize>) {
+        self.max_entries
+            .store(max_entries.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// The configured limit, or `0` when unlimited.
+    pub(crate) fn limit(&self) -> usize {
+        self.max_entries.load(Ordering::Relaxed)
+    }
+
+    /// Whether a limit is configured.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.limit() > 0
+    }
+
+    /// Record that `uri` was just parsed and defines the classes `fqns`.
+    pub(crate) fn record_load(&self, uri: &str, fqns: impl IntoIterator<Item = String>) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut state = self.state.lock();
+        state.clock += 1;
+        let now = state.clock;
+        state.last_used.insert(uri.to_string(), now);
+        for fqn in fqns {
+            state.fqn_to_uri.insert(fqn, uri.to_string());
+        }
+    }
+
+    /// Record a lookup of the class `fqn`, refreshing its file.
+    pub(crate) fn touch_class(&self, fqn: &str) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut state = self.state.lock();
+        let Some(uri) = state.fqn_to_uri.get(fqn).cloned() else {
+            return;
+        };
+        state.clock += 1;
+        let now = state.clock;
+        state.last_used.insert(uri, now);
+    }
+
+    /// Stop tracking `uri` and the classes it defines, e.g. after the
+    /// file was dropped from the index by other means.
+    pub(crate) fn forget(&self, uri: &str) {
+        let mut state = self.state.lock();
+        state.last_used.remove(uri);
+        state.fqn_to_uri.retain(|_, u| u != uri);
+    }
+
+    /// Stop tracking every file, e.g. when the whole index is rebuilt.
+    pub(crate) fn clear(&self) {
+        let mut state = self.state.lock();
+        state.last_used.clear();
+        state.fqn_to_uri.clear();
+    }
+
+    /// Remove the least recently used files once more than the limit
+    /// are tracked, skipping files for which `is_pinned` returns `true`.
+    ///
+    /// Returns the evicted URIs, which are no longer tracked.
+    pub(crate) fn take_victims(&self, is_pinned: impl Fn(&str) -> bool) -> Vec<String> {
+        let limit = self.limit();
+        if limit == 0 {
+            return Vec::new();
+        }
+        let mut state = self.state.lock();
+        if state.last_used.len() <= limit {
+            return Vec::new();
+        }
+
+        let target = limit - limit / 10;
+        let excess = state.last_used.len() - target;
+        let mut candidates: Vec<(u64, &String)> = state
+            .last_used
+            .iter()
+            .filter(|(uri, _)| !is_pinned(uri))
+            .map(|(uri, &used)| (used, uri))
+            .collect();
+        candidates.sort_unstable();
+        let victims: Vec<String> = candidates
+            .into_iter()
+            .take(excess)
+            .map(|(_, uri)| uri.clone())
+            .collect();
+
+        let evicted: HashSet<&str> = victims.iter().map(String::as_str).collect();
+        state
+            .last_used
+            .retain(|uri, _| !evicted.contains(uri.as_str()));
+        state
+            .fqn_to_uri
+            .retain(|_, uri| !evicted.contains(uri.as_str()));
+        victims
+    }
+
+    /// Returns `true` the first time it is called, so that the eviction
+    /// warning is logged once per session.
+    pub(crate) fn should_warn(&self) -> bool {
+        !self.warned.swap(true, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lru_with_limit(limit: usize) -> IndexLru {
+        let lru = IndexLru::default();
+        lru.set_limit(Some(limit));
+        lru
+    }
+
+    #[test]
+    fn disabled_without_limit() {
+        let lru = IndexLru::default();
+        lru.record_load("file:///a.php", ["A".to_string()]);
+        lru.record_load("file:///b.php", ["B".to_string()]);
+        assert!(!lru.is_enabled());
+        assert!(lru.take_victims(|_| false).is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used_down_to_ninety_percent() {
+        let lru = lru_with_limit(10);
+        for i in 0..11 {
+            lru.record_load(&format!("file:///{i}.php"), [format!("C{i}")]);
+        }
+        // Looking up C0 makes file 0 the most recently used.
+        lru.touch_class("C0");
+
+        let victims = lru.take_victims(|_| false);
+        assert_eq!(victims, vec!["file:///1.php", "file:///2.php"]);
+        assert!(lru.take_victims(|_| false).is_empty());
+    }
+
+    #[test]
+    fn pinned_files_are_kept() {
+        let lru = lru_with_limit(1);
+        lru.record_load("file:///open.php", ["Open".to_string()]);
+        lru.record_load("file:///closed.php", ["Closed".to_string()]);
+
+        let victims = lru.take_victims(|uri| uri == "file:///open.php");
+        assert_eq!(victims, vec!["file:///closed.php"]);
+    }
+
+    #[test]
+    fn forgotten_files_do_not_count_toward_the_limit() {
+        let lru = lru_with_limit(1);
+        lru.record_load("file:///gone.php", ["Gone".to_string()]);
+        lru.record_load("file:///live.php", ["Live".to_string()]);
+        lru.forget("file:///gone.php");
+        assert!(lru.take_victims(|_| false).is_empty());
+
+        lru.clear();
+        lru.record_load("file:///a.php", ["A".to_string()]);
+        assert!(lru.take_victims(|_| false).is_empty());
+    }
+
+    #[test]
+    fn warns_once() {
+        let lru = IndexLru::default();
+        assert!(lru.should_warn());
+        assert!(!lru.should_warn());
+    }
+}
@@ -321,11 +321,13 @@ fn test_apply_substitution_to_method_modifies_return_and_params() {
         conditional_return: None,
         deprecation_message: None,
         deprecated_replacement: None,
+        is_internal: false,
         template_params: Vec::new(),
         template_param_bounds: Default::default(),
         template_bindings: Vec::new(),
         has_scope_attribute: false,
         is_abstract: false,
+        is_final: false,
         is_virtual: false,
         type_assertions: Vec::new(),
         throws: Vec::new(),
@@ -382,11 +384,13 @@ fn test_extends_generics_propagate_through_parent_use_generics() {
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: Vec::new(),
             template_param_bounds: Default::default(),
             template_bindings: Vec::new(),
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: false,
             type_assertions: Vec::new(),
             throws: Vec::new(),
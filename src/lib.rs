@@ -25,6 +25,16 @@
 //!   [`VirtualMemberProvider`](virtual_members::VirtualMemberProvider) trait and
 //!   merge logic for members synthesized from `@method`/`@property` tags,
 //!   `@mixin` classes, and framework-specific patterns (e.g. Laravel)
+//! - `workspace_edit` — Builds the `WorkspaceEdit` for multi-file features
+//!   (rename, auto-import), using versioned `document_changes` when the
+//!   client supports them
+//! - `status` — The custom `$/status` notification that reports indexing
+//!   state (`indexing`, `ready`, `error`) for editor status bars
+//! - `document_color` — Document colors (`textDocument/documentColor`,
+//!   `textDocument/colorPresentation`) for CSS color strings such as
+//!   `'#ff0000'` and `'rgb(255, 0, 0)'`
+//! - `symfony` — Symfony `config/services.yaml` parsing, used to type
+//!   `$container->get('service.id')` calls
 //! - `resolution` — Class and function lookup / name resolution (multi-phase:
 //!   class_index → ast_map → classmap → PSR-4 → stubs)
 //! - `subject_extraction` — Shared helpers for extracting the left-hand side of
@@ -98,6 +108,7 @@ pub(crate) type ParseErrorEntry = (String, u32, u32);
 pub mod analyse;
 pub mod atom;
 pub mod blade;
+mod call_hierarchy;
 pub mod classmap_scanner;
 mod code_actions;
 mod code_lens;
@@ -107,6 +118,7 @@ pub mod config;
 mod definition;
 pub mod diagnostics;
 pub mod docblock;
+mod document_color;
 mod document_links;
 mod document_symbols;
 pub mod fix;
@@ -114,6 +126,7 @@ mod folding;
 mod formatting;
 mod highlight;
 mod hover;
+mod index_lru;
 pub(crate) mod inheritance;
 mod inlay_hints;
 mod linked_editing;
@@ -132,17 +145,20 @@ mod selection_range;
 mod semantic_tokens;
 mod server;
 mod signature_help;
+mod status;
 pub mod stub_patches;
 pub mod stubs;
 pub mod subject_expr;
 pub(crate) mod subject_extraction;
 pub(crate) mod subject_resolution;
 pub(crate) mod symbol_map;
+mod symfony;
 pub(crate) mod toposort;
 mod type_hierarchy;
 pub mod types;
 mod util;
 pub(crate) mod virtual_members;
+mod workspace_edit;
 mod workspace_symbols;
 
 #[cfg(test)]
@@ -194,6 +210,10 @@ pub struct Backend {
     /// Empty string when the client does not report its identity.
     pub(crate) client_name: Mutex<String>,
     pub(crate) open_files: Arc<RwLock<HashMap<String, Arc<String>>>>,
+    /// Version numbers of open documents, as last reported by the client
+    /// in `didOpen` / `didChange`.  Used to tag `document_changes` in
+    /// workspace edits so the client can reject edits to stale buffers.
+    pub(crate) open_file_versions: Arc<RwLock<HashMap<String, i32>>>,
     /// Maps a file URI to a list of ClassInfo extracted from that file.
     pub(crate) ast_map: Arc<RwLock<HashMap<String, Vec<Arc<ClassInfo>>>>>,
     /// Per-file precomputed symbol location maps for O(log n) lookup.
@@ -208,12 +228,27 @@ pub struct Backend {
     /// Each entry is `(message, start_byte_offset, end_byte_offset)`.
     /// Populated during `update_ast` from `Program::errors` and consumed
     /// by the syntax-error diagnostic collector.  When the parser panics
-    /// (caught by `catch_unwind`), a single "Parse failed" entry is
-    /// stored instead.
+    /// (caught by `catch_unwind`), a single entry at offset 0 carrying
+    /// the panic message is stored instead.
     pub(crate) parse_errors: Arc<RwLock<HashMap<String, Vec<ParseErrorEntry>>>>,
+    /// Per-file `declare(strict_types=1)` flag.
+    ///
+    /// Populated during `update_ast` from the file's top-level `declare`
+    /// statements.  The type-error diagnostic collector reports argument
+    /// mismatches as errors in strict files and as warnings otherwise,
+    /// since PHP coerces scalar arguments in coercive mode.
+    pub(crate) strict_types_map: Arc<RwLock<HashMap<String, bool>>>,
     pub(crate) client: Option<Client>,
     /// The root directory of the workspace (set during `initialize`).
     pub(crate) workspace_root: Arc<RwLock<Option<PathBuf>>>,
+    /// Workspace folders other than `workspace_root`, in the order the
+    /// client reported them.
+    ///
+    /// Set from `InitializeParams::workspace_folders` and kept up to date
+    /// by `workspace/didChangeWorkspaceFolders`.  Each folder is indexed
+    /// as its own Composer project (or loose-file scan) and merged into
+    /// the shared indices, the same way monorepo subprojects are.
+    pub(crate) additional_workspace_roots: Arc<RwLock<Vec<PathBuf>>>,
     /// PSR-4 autoload mappings parsed from `composer.json`.
     pub(crate) psr4_mappings: Arc<RwLock<Vec<composer::Psr4Mapping>>>,
     /// Maps a file URI to its `use` statement mappings (short name → fully qualified name).
@@ -351,6 +386,9 @@ pub struct Backend {
     /// `find_implementors`).  Populated in `update_ast_inner` and
     /// `parse_and_cache_content_versioned`.
     pub(crate) parsed_uris: Arc<RwLock<HashSet<String>>>,
+    /// Recency tracking for lazily loaded files, used to enforce the
+    /// `[indexing] max-entries` limit.  See [`index_lru`].
+    pub(crate) index_lru: Arc<index_lru::IndexLru>,
     /// Set of file URIs currently being parsed by another thread.
     ///
     /// Used by [`parse_and_cache_file`](Self::parse_and_cache_file) to avoid
@@ -377,6 +415,17 @@ pub struct Backend {
     /// `parse_and_cache_content`) so that stale results never survive
     /// an edit.
     pub(crate) resolved_class_cache: virtual_members::ResolvedClassCache,
+    /// Documentation produced by `completionItem/resolve`, keyed by the
+    /// serialized item `data`.
+    ///
+    /// Editors re-resolve the highlighted item every time the user
+    /// moves through the completion popup, so the same member is often
+    /// resolved many times in a row.  Only the items of the current
+    /// popup are kept: the map is cleared at the start of every
+    /// `textDocument/completion` request, and in `update_ast` so that
+    /// documentation never outlives an edit.
+    pub(crate) completion_resolve_cache:
+        Arc<Mutex<HashMap<String, Option<tower_lsp::lsp_types::Documentation>>>>,
     /// Global method store: `(class_fqn, method_name)` → `Arc<MethodInfo>`.
     ///
     /// Populated alongside `fqn_index` whenever classes are parsed or
@@ -421,6 +470,12 @@ pub struct Backend {
     /// Wrapped in a `Mutex` so that `set_php_version` can be called
     /// during `initialized` (which receives `&self`, not `&mut self`).
     pub(crate) php_version: Mutex<types::PhpVersion>,
+    /// Entries taken out of `stub_index` by the last
+    /// [`set_php_version`](Self::set_php_version), put back before the
+    /// next filter so that raising the version again restores them.
+    pub(crate) removed_stub_classes: Mutex<HashMap<&'static str, &'static str>>,
+    /// Same as `removed_stub_classes`, for `stub_function_index`.
+    pub(crate) removed_stub_functions: Mutex<HashMap<&'static str, &'static str>>,
     // NOTE: php_version, vendor_uri_prefixes, vendor_dir_paths, config,
     // and diag_pending_uris use parking_lot::Mutex (not RwLock) because
     // they are rarely accessed or always written.
@@ -583,6 +638,13 @@ pub struct Backend {
     /// the rename response includes a `RenameFile` operation alongside the
     /// text edits so the file is renamed to match the new class name.
     pub(crate) supports_file_rename: Arc<std::sync::atomic::AtomicBool>,
+    /// Whether the client accepts `document_changes` in workspace edits.
+    ///
+    /// Set during `initialize` based on the client's
+    /// `workspace.workspaceEdit.documentChanges` capability.  When `true`,
+    /// rename and auto-import edits are sent as versioned
+    /// `TextDocumentEdit`s instead of the plain `changes` map.
+    pub(crate) supports_document_changes: Arc<std::sync::atomic::AtomicBool>,
     /// Whether the client supports server-initiated work-done progress.
     ///
     /// Set during `initialize` based on the client's
@@ -592,6 +654,23 @@ pub struct Backend {
     pub(crate) supports_work_done_progress: Arc<std::sync::atomic::AtomicBool>,
     /// Whether the client supports dynamic registration for type hierarchy.
     pub(crate) supports_type_hierarchy_dynamic_registration: Arc<std::sync::atomic::AtomicBool>,
+    /// Whether the client supports dynamic registration of file
+    /// watchers (`workspace/didChangeWatchedFiles`).  When `true`, a
+    /// watcher for `composer.json` is registered in `initialized`.
+    pub(crate) supports_watched_files_dynamic_registration: Arc<std::sync::atomic::AtomicBool>,
+    /// Whether the client answers `workspace/configuration` requests.
+    ///
+    /// Set during `initialize` from the client's `workspace.configuration`
+    /// capability.  When `false`, editor settings are never pulled and
+    /// `client_settings` stays empty.
+    pub(crate) supports_workspace_configuration: Arc<std::sync::atomic::AtomicBool>,
+    /// Whether the client implements the `phpantom.showReferences`
+    /// command used by reference-count code lenses.
+    ///
+    /// Set during `initialize` from the client's
+    /// `experimental.commands.commands` list.  When `false`, resolved
+    /// lenses only show their title.
+    pub(crate) supports_show_references_command: Arc<std::sync::atomic::AtomicBool>,
     /// Shared flag set to `true` when the LSP `shutdown` request is
     /// received.  Background workers (diagnostic, PHPStan, PHPCS) check this
     /// flag on each iteration and exit their loops.  The PHPStan
@@ -605,6 +684,22 @@ pub struct Backend {
     /// false-positive "class not found" / "function not found" errors.
     pub(crate) init_complete: Arc<std::sync::atomic::AtomicBool>,
     pub(crate) shutdown_flag: Arc<std::sync::atomic::AtomicBool>,
+    /// Set when the client cancels (`$/cancelRequest`) the request that
+    /// this `Backend` clone is serving.  Every [`clone_for_blocking`]
+    /// copy gets a fresh flag, so cancelling one request never stops
+    /// another.  Workspace scans poll it through
+    /// [`is_request_cancelled`](Self::is_request_cancelled) and bail out
+    /// early, since tower-lsp discards the result of a cancelled request
+    /// anyway.
+    ///
+    /// [`clone_for_blocking`]: Self::clone_for_blocking
+    pub(crate) request_cancelled: Arc<std::sync::atomic::AtomicBool>,
+    /// Set when the client cancels the workspace indexing progress
+    /// (`window/workDoneProgress/cancel`).  The indexing pipeline checks
+    /// it between phases and stops early, leaving a partial index that
+    /// `phpantom.reindex` can rebuild.  Reset at the start of each
+    /// indexing run.
+    pub(crate) indexing_cancelled: Arc<std::sync::atomic::AtomicBool>,
     // NOTE: resolved_class_cache uses parking_lot::Mutex because it is
     // frequently written (cache stores) and RwLock read→write upgrades
     // are error-prone.
@@ -616,6 +711,12 @@ pub struct Backend {
     /// (which receives `&self`) can set it after loading the file.
     /// The diagnostic worker snapshots the value at spawn time.
     pub(crate) config: Mutex<config::Config>,
+    /// Editor settings pulled through `workspace/configuration`, keyed
+    /// by workspace folder URI.
+    ///
+    /// Refreshed after initialization and on every
+    /// `workspace/didChangeConfiguration` notification.
+    pub(crate) client_settings: Arc<RwLock<HashMap<String, config::ClientSettings>>>,
     /// Virtual PHP content generated from Blade files.
     pub(crate) blade_virtual_content: Arc<RwLock<HashMap<String, String>>>,
     /// Source maps from virtual PHP back to original Blade positions.
@@ -624,6 +725,9 @@ pub struct Backend {
     /// URIs opened with `languageId == "blade"` that don't have a `.blade.php` extension.
     /// Allows editors to signal Blade files via languageId alone.
     pub(crate) blade_uris: Arc<RwLock<std::collections::HashSet<String>>>,
+    /// Parsed Symfony `config/services.yaml`, rebuilt when the file's
+    /// modification time changes.  See the `symfony` module.
+    pub(crate) symfony_services: Arc<Mutex<Option<symfony::ServiceMapCache>>>,
 }
 
 impl Backend {
@@ -642,11 +746,14 @@ impl Backend {
             version: env!("PHPANTOM_GIT_VERSION").to_string(),
             client_name: Mutex::new(String::new()),
             open_files: Arc::new(RwLock::new(HashMap::new())),
+            open_file_versions: Arc::new(RwLock::new(HashMap::new())),
             ast_map: Arc::new(RwLock::new(HashMap::new())),
             symbol_maps: Arc::new(RwLock::new(HashMap::new())),
             parse_errors: Arc::new(RwLock::new(HashMap::new())),
+            strict_types_map: Arc::new(RwLock::new(HashMap::new())),
             client: None,
             workspace_root: Arc::new(RwLock::new(None)),
+            additional_workspace_roots: Arc::new(RwLock::new(Vec::new())),
             vendor_uri_prefixes: Mutex::new(Vec::new()),
             vendor_dir_paths: Mutex::new(Vec::new()),
             psr4_mappings: Arc::new(RwLock::new(Vec::new())),
@@ -664,14 +771,18 @@ impl Backend {
             classmap: Arc::new(RwLock::new(HashMap::new())),
             phar_archives: Arc::new(RwLock::new(HashMap::new())),
             parsed_uris: Arc::new(RwLock::new(HashSet::new())),
+            index_lru: Arc::new(index_lru::IndexLru::default()),
             parse_inflight: Arc::new(Mutex::new(HashSet::new())),
             stub_index: RwLock::new(stubs::build_stub_class_index()),
             stub_function_index: RwLock::new(stubs::build_stub_function_index()),
             stub_constant_index: RwLock::new(stubs::build_stub_constant_index()),
             resolved_class_cache: virtual_members::new_resolved_class_cache(),
+            completion_resolve_cache: Arc::new(Mutex::new(HashMap::new())),
             method_store: Arc::new(RwLock::new(HashMap::new())),
             gti_index: Arc::new(RwLock::new(HashMap::new())),
             php_version: Mutex::new(types::PhpVersion::default()),
+            removed_stub_classes: Mutex::new(HashMap::new()),
+            removed_stub_functions: Mutex::new(HashMap::new()),
             diag_version: Arc::new(AtomicU64::new(0)),
             diag_notify: Arc::new(tokio::sync::Notify::new()),
             diag_pending_uris: Arc::new(Mutex::new(Vec::new())),
@@ -695,16 +806,26 @@ impl Backend {
             diag_suppressed: Arc::new(Mutex::new(Vec::new())),
             supports_pull_diagnostics: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supports_file_rename: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            supports_document_changes: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supports_work_done_progress: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supports_type_hierarchy_dynamic_registration: Arc::new(
                 std::sync::atomic::AtomicBool::new(false),
             ),
+            supports_watched_files_dynamic_registration: Arc::new(
+                std::sync::atomic::AtomicBool::new(false),
+            ),
+            supports_workspace_configuration: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            supports_show_references_command: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             init_complete: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             shutdown_flag: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            request_cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            indexing_cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             config: Mutex::new(config::Config::default()),
+            client_settings: Arc::new(RwLock::new(HashMap::new())),
             blade_virtual_content: Arc::new(RwLock::new(HashMap::new())),
             blade_source_maps: Arc::new(RwLock::new(HashMap::new())),
             blade_uris: Arc::new(RwLock::new(std::collections::HashSet::new())),
+            symfony_services: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -720,11 +841,14 @@ impl Backend {
             version: env!("PHPANTOM_GIT_VERSION").to_string(),
             client_name: Mutex::new(String::new()),
             open_files: Arc::new(RwLock::new(HashMap::new())),
+            open_file_versions: Arc::new(RwLock::new(HashMap::new())),
             ast_map: Arc::new(RwLock::new(HashMap::new())),
             symbol_maps: Arc::new(RwLock::new(HashMap::new())),
             parse_errors: Arc::new(RwLock::new(HashMap::new())),
+            strict_types_map: Arc::new(RwLock::new(HashMap::new())),
             client: None,
             workspace_root: Arc::new(RwLock::new(None)),
+            additional_workspace_roots: Arc::new(RwLock::new(Vec::new())),
             vendor_uri_prefixes: Mutex::new(Vec::new()),
             vendor_dir_paths: Mutex::new(Vec::new()),
             psr4_mappings: Arc::new(RwLock::new(Vec::new())),
@@ -742,14 +866,18 @@ impl Backend {
             classmap: Arc::new(RwLock::new(HashMap::new())),
             phar_archives: Arc::new(RwLock::new(HashMap::new())),
             parsed_uris: Arc::new(RwLock::new(HashSet::new())),
+            index_lru: Arc::new(index_lru::IndexLru::default()),
             parse_inflight: Arc::new(Mutex::new(HashSet::new())),
             stub_index: RwLock::new(HashMap::new()),
             stub_function_index: RwLock::new(HashMap::new()),
             stub_constant_index: RwLock::new(HashMap::new()),
             resolved_class_cache: virtual_members::new_resolved_class_cache(),
+            completion_resolve_cache: Arc::new(Mutex::new(HashMap::new())),
             method_store: Arc::new(RwLock::new(HashMap::new())),
             gti_index: Arc::new(RwLock::new(HashMap::new())),
             php_version: Mutex::new(types::PhpVersion::default()),
+            removed_stub_classes: Mutex::new(HashMap::new()),
+            removed_stub_functions: Mutex::new(HashMap::new()),
             diag_version: Arc::new(AtomicU64::new(0)),
             diag_notify: Arc::new(tokio::sync::Notify::new()),
             diag_pending_uris: Arc::new(Mutex::new(Vec::new())),
@@ -772,16 +900,26 @@ impl Backend {
             diag_suppressed: Arc::new(Mutex::new(Vec::new())),
             supports_pull_diagnostics: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supports_file_rename: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            supports_document_changes: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supports_work_done_progress: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             supports_type_hierarchy_dynamic_registration: Arc::new(
                 std::sync::atomic::AtomicBool::new(false),
             ),
+            supports_watched_files_dynamic_registration: Arc::new(
+                std::sync::atomic::AtomicBool::new(false),
+            ),
+            supports_workspace_configuration: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            supports_show_references_command: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             init_complete: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             shutdown_flag: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            request_cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            indexing_cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             config: Mutex::new(config::Config::default()),
+            client_settings: Arc::new(RwLock::new(HashMap::new())),
             blade_virtual_content: Arc::new(RwLock::new(HashMap::new())),
             blade_source_maps: Arc::new(RwLock::new(HashMap::new())),
             blade_uris: Arc::new(RwLock::new(std::collections::HashSet::new())),
+            symfony_services: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -793,6 +931,20 @@ impl Backend {
         }
     }
 
+    /// Create a `Backend` for an LSP client with a workspace root given
+    /// on the command line (`--workspace`).
+    ///
+    /// The root is kept when the client's `initialize` request names no
+    /// workspace (no `rootUri` and no `workspaceFolders`), so editors
+    /// that launch the server per project still get a full index.
+    pub fn new_with_workspace(client: Client, workspace_root: Option<PathBuf>) -> Self {
+        Self {
+            client: Some(client),
+            workspace_root: Arc::new(RwLock::new(workspace_root)),
+            ..Self::defaults()
+        }
+    }
+
     /// Create a `Backend` without an LSP client but with full embedded
     /// stub indices.
     ///
@@ -885,6 +1037,12 @@ impl Backend {
         &self.workspace_root
     }
 
+    /// Borrow the list of workspace folders other than the primary root
+    /// (used by integration tests to inspect multi-root workspaces).
+    pub fn additional_workspace_roots(&self) -> &Arc<RwLock<Vec<PathBuf>>> {
+        &self.additional_workspace_roots
+    }
+
     /// Borrow the global functions mutex (used by integration tests to
     /// inject user-defined functions or inspect the cache).
     pub fn global_functions(&self) -> &Arc<RwLock<HashMap<String, (String, FunctionInfo)>>> {
@@ -1071,13 +1229,16 @@ impl Backend {
             version: self.version.clone(),
             client_name: Mutex::new(self.client_name.lock().clone()),
             open_files: Arc::clone(&self.open_files),
+            open_file_versions: Arc::clone(&self.open_file_versions),
             ast_map: Arc::clone(&self.ast_map),
             symbol_maps: Arc::clone(&self.symbol_maps),
             parse_errors: Arc::clone(&self.parse_errors),
+            strict_types_map: Arc::clone(&self.strict_types_map),
             // RwLock fields are shared by Arc::clone — the diagnostic
             // worker reads them concurrently with the main Backend.
             client: self.client.clone(),
             workspace_root: Arc::clone(&self.workspace_root),
+            additional_workspace_roots: Arc::clone(&self.additional_workspace_roots),
             psr4_mappings: Arc::clone(&self.psr4_mappings),
             use_map: Arc::clone(&self.use_map),
             resolved_names: Arc::clone(&self.resolved_names),
@@ -1092,15 +1253,19 @@ impl Backend {
             classmap: Arc::clone(&self.classmap),
             phar_archives: Arc::clone(&self.phar_archives),
             parsed_uris: Arc::clone(&self.parsed_uris),
+            index_lru: Arc::clone(&self.index_lru),
             parse_inflight: Arc::clone(&self.parse_inflight),
             class_not_found_cache: Arc::clone(&self.class_not_found_cache),
             stub_index: RwLock::new(self.stub_index.read().clone()),
             resolved_class_cache: Arc::clone(&self.resolved_class_cache),
+            completion_resolve_cache: Arc::clone(&self.completion_resolve_cache),
             method_store: Arc::clone(&self.method_store),
             gti_index: Arc::clone(&self.gti_index),
             stub_function_index: RwLock::new(self.stub_function_index.read().clone()),
             stub_constant_index: RwLock::new(self.stub_constant_index.read().clone()),
             php_version: Mutex::new(self.php_version()),
+            removed_stub_classes: Mutex::new(self.removed_stub_classes.lock().clone()),
+            removed_stub_functions: Mutex::new(self.removed_stub_functions.lock().clone()),
             vendor_uri_prefixes: Mutex::new(self.vendor_uri_prefixes.lock().clone()),
             vendor_dir_paths: Mutex::new(self.vendor_dir_paths.lock().clone()),
             diag_version: Arc::clone(&self.diag_version),
@@ -1126,16 +1291,26 @@ impl Backend {
             diag_suppressed: Arc::clone(&self.diag_suppressed),
             supports_pull_diagnostics: Arc::clone(&self.supports_pull_diagnostics),
             supports_file_rename: Arc::clone(&self.supports_file_rename),
+            supports_document_changes: Arc::clone(&self.supports_document_changes),
             supports_work_done_progress: Arc::clone(&self.supports_work_done_progress),
             supports_type_hierarchy_dynamic_registration: Arc::clone(
                 &self.supports_type_hierarchy_dynamic_registration,
             ),
+            supports_watched_files_dynamic_registration: Arc::clone(
+                &self.supports_watched_files_dynamic_registration,
+            ),
+            supports_workspace_configuration: Arc::clone(&self.supports_workspace_configuration),
+            supports_show_references_command: Arc::clone(&self.supports_show_references_command),
             init_complete: Arc::clone(&self.init_complete),
             shutdown_flag: Arc::clone(&self.shutdown_flag),
+            request_cancelled: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            indexing_cancelled: Arc::clone(&self.indexing_cancelled),
             config: Mutex::new(self.config.lock().clone()),
+            client_settings: Arc::clone(&self.client_settings),
             blade_virtual_content: Arc::clone(&self.blade_virtual_content),
             blade_source_maps: Arc::clone(&self.blade_source_maps),
             blade_uris: Arc::clone(&self.blade_uris),
+            symfony_services: Arc::clone(&self.symfony_services),
         }
     }
 
@@ -1148,6 +1323,16 @@ impl Backend {
         self.clone_for_diagnostic_worker()
     }
 
+    /// Whether the request served by this clone has been cancelled by
+    /// the client, or the server is shutting down.
+    pub(crate) fn is_request_cancelled(&self) -> bool {
+        self.request_cancelled
+            .load(std::sync::atomic::Ordering::Acquire)
+            || self
+                .shutdown_flag
+                .load(std::sync::atomic::Ordering::Acquire)
+    }
+
     /// Return the current project configuration.
     ///
     /// Returns a clone of the [`Config`](config::Config) loaded from
@@ -1164,19 +1349,119 @@ impl Backend {
         *self.config.lock() = config;
     }
 
+    /// Store the editor settings for one workspace folder.
+    ///
+    /// Returns `true` when the value differs from what was cached.
+    /// Called after each `workspace/configuration` pull; integration
+    /// tests use it to inject settings without a client.
+    pub fn set_client_settings(&self, folder_uri: &str, settings: config::ClientSettings) -> bool {
+        let mut cache = self.client_settings.write();
+        if cache.get(folder_uri) == Some(&settings) {
+            return false;
+        }
+        cache.insert(folder_uri.to_string(), settings);
+        true
+    }
+
+    /// The editor settings of the primary workspace folder, or the
+    /// defaults when none were pulled.
+    pub(crate) fn root_client_settings(&self) -> config::ClientSettings {
+        let Some(root) = self.workspace_root.read().clone() else {
+            return config::ClientSettings::default();
+        };
+        let Ok(uri) = tower_lsp::lsp_types::Url::from_directory_path(&root) else {
+            return config::ClientSettings::default();
+        };
+        self.client_settings
+            .read()
+            .get(uri.as_str())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The URIs of the workspace folders, primary root first and the
+    /// others in the order they were added.
+    pub(crate) fn workspace_folder_uris(&self) -> Vec<tower_lsp::lsp_types::Url> {
+        self.workspace_root
+            .read()
+            .iter()
+            .chain(self.additional_workspace_roots.read().iter())
+            .filter_map(|p| tower_lsp::lsp_types::Url::from_directory_path(p).ok())
+            .collect()
+    }
+
+    /// Drop cached editor settings of folders that are no longer part
+    /// of the workspace.
+    ///
+    /// Returns `true` when an entry was removed.
+    pub(crate) fn prune_client_settings(&self) -> bool {
+        let folders = self.workspace_folder_uris();
+        let mut cache = self.client_settings.write();
+        let before = cache.len();
+        cache.retain(|uri, _| folders.iter().any(|f| f.as_str() == uri));
+        cache.len() != before
+    }
+
+    /// The `includePaths` of every workspace folder, resolved to
+    /// absolute paths.
+    pub(crate) fn client_include_paths(&self) -> Vec<PathBuf> {
+        let settings = self.client_settings.read();
+        let mut paths = Vec::new();
+        for (folder_uri, folder_settings) in settings.iter() {
+            let Some(folder) = tower_lsp::lsp_types::Url::parse(folder_uri)
+                .ok()
+                .and_then(|u| u.to_file_path().ok())
+            else {
+                continue;
+            };
+            for path in folder_settings.resolved_include_paths(&folder) {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        paths
+    }
+
+    /// Look up the class a configured alias stands for.
+    ///
+    /// `class_name` is matched with and without a leading backslash
+    /// against the `aliases` setting of every workspace folder, primary
+    /// root first, so the result does not depend on map order.
+    pub(crate) fn class_alias_target(&self, class_name: &str) -> Option<String> {
+        let name = class_name.trim_start_matches('\\');
+        let folders = self.workspace_folder_uris();
+        let settings = self.client_settings.read();
+        folders.iter().find_map(|folder| {
+            settings
+                .get(folder.as_str())?
+                .aliases
+                .iter()
+                .find(|(alias, _)| alias.trim_start_matches('\\') == name)
+                .map(|(_, target)| target.trim_start_matches('\\').to_string())
+        })
+    }
+
     /// Set the PHP version (used by integration tests and during
     /// server initialization after reading `composer.json`).
     ///
     /// Also filters `stub_function_index` and `stub_index` to remove
-    /// entries that do not exist in the given PHP version.
+    /// entries that do not exist in the given PHP version.  Entries
+    /// removed by an earlier call are restored first, so the version can
+    /// be changed at runtime in either direction.
     pub fn set_php_version(&self, version: types::PhpVersion) {
         *self.php_version.lock() = version;
-        self.stub_function_index
-            .write()
-            .retain(|name, source| !stubs::is_stub_function_removed(source, name, version));
-        self.stub_index
-            .write()
-            .retain(|name, source| !stubs::is_stub_class_removed(source, name, version));
+        stubs::filter_removed_stubs(
+            &mut self.stub_function_index.write(),
+            &mut self.removed_stub_functions.lock(),
+            |name, source| stubs::is_stub_function_removed(source, name, version),
+        );
+        stubs::filter_removed_stubs(
+            &mut self.stub_index.write(),
+            &mut self.removed_stub_classes.lock(),
+            |name, source| stubs::is_stub_class_removed(source, name, version),
+        );
     }
 
     /// Check whether a URI refers to a Blade template file.
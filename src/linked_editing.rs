@@ -27,28 +27,41 @@
 //! is never something users want to rename away, so excluding it costs
 //! nothing and prevents accidental edits.
 //!
-//! Only variables are supported. Class names, members, functions, and
-//! constants span multiple files and are better served by the full
-//! `textDocument/rename` flow.
+//! ## Heredoc delimiters
+//!
+//! When the cursor is on the opening label of a heredoc (`<<<EOT`) or
+//! nowdoc (`<<<'EOT'`), or on its closing label, both labels are
+//! returned so that renaming one keeps the other in sync.  The label
+//! offsets come from the symbol map, which records them from the AST's
+//! heredoc nodes.
+//!
+//! Apart from heredoc labels only variables are supported. Class names,
+//! members, functions, and constants span multiple files and are better
+//! served by the full `textDocument/rename` flow.
 
 use tower_lsp::lsp_types::*;
 
 use crate::Backend;
 use crate::symbol_map::{SymbolKind, SymbolMap, VarDefKind, VarDefSite};
-use crate::util::byte_range_to_lsp_range;
+use crate::util::{byte_range_to_lsp_range, position_to_offset};
 
 impl Backend {
     /// Compute linked editing ranges for the symbol under the cursor.
     ///
-    /// Returns `Some` only when the cursor is on a variable (not a
-    /// property declaration) that has at least two occurrences in its
-    /// definition region. A single occurrence offers nothing to link.
+    /// Returns `Some` when the cursor is on a heredoc or nowdoc label, or
+    /// on a variable (not a property declaration) that has at least two
+    /// occurrences in its definition region. A single occurrence offers
+    /// nothing to link.
     pub fn handle_linked_editing_range(
         &self,
         uri: &str,
         content: &str,
         position: Position,
     ) -> Option<LinkedEditingRanges> {
+        if let Some(ranges) = self.heredoc_label_ranges(uri, content, position) {
+            return Some(ranges);
+        }
+
         let span = self.lookup_symbol_at_position(uri, content, position)?;
 
         let maps = self.symbol_maps.read();
@@ -78,8 +91,33 @@ impl Backend {
             _ => None,
         }
     }
+
+    /// Return the opening and closing label ranges of the heredoc or
+    /// nowdoc whose delimiter label the cursor is on.
+    fn heredoc_label_ranges(
+        &self,
+        uri: &str,
+        content: &str,
+        position: Position,
+    ) -> Option<LinkedEditingRanges> {
+        let offset = position_to_offset(content, position);
+        let maps = self.symbol_maps.read();
+        let labels = maps.get(uri)?.find_heredoc_label(offset)?;
+
+        Some(LinkedEditingRanges {
+            ranges: vec![
+                byte_range_to_lsp_range(content, labels.open.0 as usize, labels.open.1 as usize),
+                byte_range_to_lsp_range(content, labels.close.0 as usize, labels.close.1 as usize),
+            ],
+            word_pattern: Some(HEREDOC_LABEL_PATTERN.to_string()),
+        })
+    }
 }
 
+/// Characters allowed in a heredoc label, so the editor ends linked
+/// editing when the label stops being a valid identifier.
+const HEREDOC_LABEL_PATTERN: &str = "[A-Za-z_][A-Za-z0-9_]*";
+
 /// A definition region identified by its owning [`VarDefSite`].
 ///
 /// The region covers:
@@ -38,6 +38,43 @@ struct Cli {
     /// connection and exits when the client disconnects.
     #[arg(long, value_name = "ADDR")]
     tcp: Option<String>,
+
+    /// Workspace root to index when the editor does not name one.
+    ///
+    /// Used when the `initialize` request carries neither `rootUri` nor
+    /// `workspaceFolders`. A root sent by the editor always wins.
+    #[arg(long, value_name = "DIR")]
+    workspace: Option<std::path::PathBuf>,
+
+    /// Default log level for messages written to stderr.
+    ///
+    /// `RUST_LOG` directives still apply on top of this level.
+    #[arg(long, value_name = "LEVEL", default_value = "error")]
+    log_level: LogLevelArg,
+}
+
+/// Log level for the language server's stderr output.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum LogLevelArg {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevelArg> for tracing_subscriber::filter::LevelFilter {
+    fn from(arg: LogLevelArg) -> Self {
+        match arg {
+            LogLevelArg::Off => Self::OFF,
+            LogLevelArg::Error => Self::ERROR,
+            LogLevelArg::Warn => Self::WARN,
+            LogLevelArg::Info => Self::INFO,
+            LogLevelArg::Debug => Self::DEBUG,
+            LogLevelArg::Trace => Self::TRACE,
+        }
+    }
 }
 
 #[derive(clap::Subcommand)]
@@ -258,11 +295,23 @@ async fn main() {
             std::process::exit(exit_code);
         }
         None => {
+            let level: tracing_subscriber::filter::LevelFilter = cli.log_level.into();
             tracing_subscriber::fmt()
-                .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+                .with_env_filter(
+                    tracing_subscriber::EnvFilter::builder()
+                        .with_default_directive(level.into())
+                        .from_env_lossy(),
+                )
                 .with_writer(std::io::stderr)
                 .init();
 
+            let workspace = cli.workspace.map(|dir| {
+                std::fs::canonicalize(&dir).unwrap_or_else(|e| {
+                    eprintln!("Error: invalid workspace '{}': {}", dir.display(), e);
+                    std::process::exit(1);
+                })
+            });
+
             if let Some(addr_str) = cli.tcp {
                 // TCP transport: accept a single connection and serve the LSP over it.
                 let addr = parse_tcp_address(&addr_str);
@@ -281,7 +330,13 @@ async fn main() {
                 eprintln!("Client connected from {}", peer);
 
                 let (read, write) = tokio::io::split(stream);
-                let (service, socket) = LspService::build(Backend::new).finish();
+                let (service, socket) =
+                    LspService::build(|client| Backend::new_with_workspace(client, workspace))
+                        .custom_method(
+                            "window/workDoneProgress/cancel",
+                            Backend::work_done_progress_cancel,
+                        )
+                        .finish();
                 Server::new(read, write, socket).serve(service).await;
                 // The serve loop exited (client disconnected or an
                 // internal error occurred).  Exit the process so the
@@ -294,7 +349,13 @@ async fn main() {
                 let stdin = tokio::io::stdin();
                 let stdout = tokio::io::stdout();
 
-                let (service, socket) = LspService::build(Backend::new).finish();
+                let (service, socket) =
+                    LspService::build(|client| Backend::new_with_workspace(client, workspace))
+                        .custom_method(
+                            "window/workDoneProgress/cancel",
+                            Backend::work_done_progress_cancel,
+                        )
+                        .finish();
                 Server::new(stdin, stdout, socket).serve(service).await;
                 // Same as above: the serve loop exited.  Without this
                 // explicit exit, the process hangs because the tokio
@@ -33,6 +33,8 @@ impl Backend {
     /// changed (or a class was added/removed), meaning other open files
     /// that reference those classes may have stale diagnostics.
     pub fn update_ast(&self, uri: &str, content: &str) -> bool {
+        self.completion_resolve_cache.lock().clear();
+
         let content_to_parse = if self.is_blade_file(uri) {
             let (virtual_php, source_map) = crate::blade::preprocessor::preprocess(content);
             self.blade_source_maps
@@ -46,31 +48,46 @@ impl Backend {
             content.to_string()
         };
 
-        // The mago-syntax parser contains `unreachable!()` and `.expect()`
-        // calls that can panic on malformed PHP (e.g. partially-written
-        // heredocs/nowdocs, which are common while editing).  Wrap the
-        // entire parse + extraction in `catch_unwind` so a parser panic
-        // doesn't crash the LSP server and produce a zombie process.
-        //
-        // On panic the file is simply skipped — no maps are updated, and
-        // the user gets stale (but not missing) completions until the
-        // file is saved in a parseable state.
-        let content_owned = content_to_parse;
-        let uri_owned = uri.to_string();
+        self.parse_guarded(uri, &content_to_parse, |uri, content| {
+            self.update_ast_inner(uri, content)
+        })
+    }
 
-        let result = crate::util::catch_panic_unwind_safe("parse", uri, None, || {
-            self.update_ast_inner(&uri_owned, &content_owned)
-        });
+    /// Run `parse` on `content`, turning a panic into a parse error.
+    ///
+    /// The mago-syntax parser contains `unreachable!()` and `.expect()`
+    /// calls that can panic on malformed PHP (e.g. partially-written
+    /// heredocs/nowdocs, which are common while editing).  Wrapping the
+    /// entire parse + extraction in `catch_unwind` keeps a parser panic
+    /// from crashing the LSP server and producing a zombie process.
+    ///
+    /// On panic the file is simply skipped — no maps are updated, and
+    /// the user gets stale (but not missing) completions until the
+    /// file is saved in a parseable state.  A single parse error naming
+    /// the panic reason is stored so the file shows why.
+    fn parse_guarded(
+        &self,
+        uri: &str,
+        content: &str,
+        parse: impl FnOnce(&str, &str) -> bool,
+    ) -> bool {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| parse(uri, content)));
 
         match result {
-            Some(changed) => changed,
-            None => {
-                // Parser panicked — store a single "Parse failed" error
-                // so the syntax-error diagnostic collector can report it.
-                self.parse_errors.write().insert(
-                    uri.to_string(),
-                    vec![("Parse failed (internal error)".to_string(), 0, 0)],
+            Ok(changed) => changed,
+            Err(payload) => {
+                let reason = crate::util::panic_message(payload.as_ref());
+                tracing::error!(
+                    "PHPantom: parser panicked on {}: {} (content starts with {:?})",
+                    uri,
+                    reason,
+                    content_snippet(content)
                 );
+                // Store a single error so the syntax-error diagnostic
+                // collector shows the user which file is affected.
+                self.parse_errors
+                    .write()
+                    .insert(uri.to_string(), vec![(parser_crash_message(&reason), 0, 0)]);
                 false
             }
         }
@@ -78,7 +95,7 @@ impl Backend {
 
     /// Inner implementation of [`update_ast`] that performs the actual
     /// parsing and map updates.  Separated so that [`update_ast`] can
-    /// wrap the call in [`std::panic::catch_unwind`].
+    /// run it under [`Self::parse_guarded`].
     ///
     /// Returns `true` when at least one class signature changed.
     fn update_ast_inner(&self, uri: &str, content: &str) -> bool {
@@ -116,6 +133,10 @@ impl Backend {
             self.parse_errors.write().insert(uri.to_string(), errors);
         }
 
+        self.strict_types_map
+            .write()
+            .insert(uri.to_string(), declares_strict_types(program, content));
+
         let doc_ctx = DocblockCtx {
             trivias: program.trivia.as_slice(),
             content,
@@ -1193,3 +1214,62 @@ impl Backend {
         }
     }
 }
+
+/// Syntax-error message stored for a file whose parse panicked.
+fn parser_crash_message(reason: &str) -> String {
+    format!("PHPantomLSP: parser crashed on this file: {}", reason)
+}
+
+/// The start of `content`, cut to a fixed number of characters, for
+/// log messages about files the parser could not handle.
+fn content_snippet(content: &str) -> String {
+    const MAX_CHARS: usize = 120;
+    let mut snippet: String = content.chars().take(MAX_CHARS).collect();
+    if content.chars().nth(MAX_CHARS).is_some() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// Whether the file opts into strict typing with a top-level
+/// `declare(strict_types=1)` statement.
+fn declares_strict_types(program: &Program<'_>, content: &str) -> bool {
+    program.statements.iter().any(|stmt| {
+        let Statement::Declare(declare) = stmt else {
+            return false;
+        };
+        declare.items.iter().any(|item| {
+            let value = item.value.span();
+            item.name.value.eq_ignore_ascii_case("strict_types")
+                && content
+                    .get(value.start.offset as usize..value.end.offset as usize)
+                    .is_some_and(|v| v.trim() == "1")
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Backend;
+
+    #[test]
+    fn parser_panic_is_reported_with_its_reason() {
+        let backend = Backend::new_test();
+        let uri = "file:///crash.php";
+        let content = "<?php\nclass Foo {}\n";
+
+        // Stand in for a mago-syntax crash on this content.
+        let changed = backend.parse_guarded(uri, content, |_, _| panic!("forced parser panic"));
+
+        assert!(!changed);
+        let mut out = Vec::new();
+        backend.collect_syntax_error_diagnostics(uri, content, &mut out);
+        assert_eq!(out.len(), 1);
+        assert_eq!(
+            out[0].message,
+            "PHPantomLSP: parser crashed on this file: forced parser panic"
+        );
+        assert_eq!(out[0].range.start.line, 0);
+        assert_eq!(out[0].range.start.character, 0);
+    }
+}
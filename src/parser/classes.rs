@@ -165,6 +165,8 @@ use super::{
 struct ClassDocblockInfo {
     /// Deprecation message from `@deprecated`, or `None` if not deprecated.
     deprecation_message: Option<String>,
+    /// Whether the docblock has an `@internal` tag.
+    is_internal: bool,
     /// `@template` parameters declared on the class-like.
     template_params: Vec<Atom>,
     /// Upper bounds for template parameters (`@template T of Bound`).
@@ -235,6 +237,7 @@ fn extract_class_docblock<'a>(
 
     ClassDocblockInfo {
         deprecation_message: docblock::extract_deprecation_message_from_info(&info),
+        is_internal: docblock::has_internal_tag_from_info(&info),
         template_params,
         template_param_bounds,
         template_param_defaults,
@@ -920,6 +923,7 @@ impl Backend {
                         is_abstract: class.modifiers.contains_abstract(),
                         deprecation_message: class_depr.message,
                         deprecated_replacement: class_depr.replacement,
+                        is_internal: doc_info.is_internal,
                         links: doc_info.links,
                         see_refs: doc_info.see_refs,
                         template_params: doc_info.template_params,
@@ -1020,6 +1024,7 @@ impl Backend {
                         is_abstract: false,
                         deprecation_message: iface_depr.message,
                         deprecated_replacement: iface_depr.replacement,
+                        is_internal: doc_info.is_internal,
                         links: doc_info.links,
                         see_refs: doc_info.see_refs,
                         template_params: doc_info.template_params,
@@ -1102,6 +1107,7 @@ impl Backend {
                         is_abstract: false,
                         deprecation_message: trait_depr.message,
                         deprecated_replacement: trait_depr.replacement,
+                        is_internal: doc_info.is_internal,
                         links: doc_info.links,
                         see_refs: doc_info.see_refs,
                         template_params: doc_info.template_params,
@@ -1210,6 +1216,7 @@ impl Backend {
                         is_abstract: false,
                         deprecation_message: enum_depr.message,
                         deprecated_replacement: enum_depr.replacement,
+                        is_internal: doc_info.is_internal,
                         links: doc_info.links,
                         see_refs: doc_info.see_refs,
                         template_params: vec![],
@@ -1317,6 +1324,7 @@ impl Backend {
             is_abstract: false,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: vec![],
             template_param_bounds: AtomMap::default(),
             template_param_defaults: AtomMap::default(),
@@ -2183,6 +2191,10 @@ impl Backend {
                         .map(docblock::extract_throws_tags_from_info)
                         .unwrap_or_default();
 
+                    let is_internal = method_docblock_info
+                        .as_ref()
+                        .is_some_and(docblock::has_internal_tag_from_info);
+
                     methods.push(MethodInfo {
                         name,
                         name_offset,
@@ -2198,11 +2210,13 @@ impl Backend {
                         conditional_return,
                         deprecation_message,
                         deprecated_replacement: method_deprecated_replacement,
+                        is_internal,
                         template_params: method_template_params,
                         template_param_bounds: method_template_param_bounds,
                         template_bindings: method_template_bindings,
                         has_scope_attribute: has_scope_attr,
                         is_abstract: method.is_abstract(),
+                        is_final: method.modifiers.contains_final(),
                         is_virtual: false,
                         type_assertions,
                         throws,
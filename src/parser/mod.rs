@@ -811,8 +811,12 @@ pub(crate) fn with_parsed_program<T: Default>(
 
     match result {
         Ok(value) => value,
-        Err(_) => {
-            tracing::error!("PHPantom: parser panicked in {}", method_name);
+        Err(payload) => {
+            tracing::error!(
+                "PHPantom: parser panicked in {}: {}",
+                method_name,
+                crate::util::panic_message(payload.as_ref())
+            );
             T::default()
         }
     }
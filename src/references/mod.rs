@@ -1293,7 +1293,7 @@ impl Backend {
 
     /// Ensure all workspace PHP files have been parsed and have symbol maps.
     ///
-    /// This lazily parses files that are in the workspace directory but
+    /// This lazily parses files that are in a workspace folder but
     /// have not been opened or indexed yet.  It also covers files known
     /// via the classmap and class_index.  The vendor directory (read from
     /// `composer.json` `config.vendor-dir`, defaulting to `vendor`) is
@@ -1337,37 +1337,44 @@ impl Backend {
         );
 
         // ── Phase 2: workspace directory scan ───────────────────────────
-        // Recursively discover PHP files in the workspace root that are
+        // Recursively discover PHP files in every workspace folder (the
+        // primary root and any added through workspaceFolders) that are
         // not yet indexed.  This catches files that are not in the
-        // classmap, class_index, or already opened.  The vendor directory
-        // is skipped — find references only reports user code.  The walk
+        // classmap, class_index, or already opened.  Vendor directories
+        // are skipped — find references only reports user code.  The walk
         // respects .gitignore so that generated/cached directories (e.g.
         // storage/framework/views/, var/cache/, node_modules/) are
         // automatically excluded.
-        let workspace_root = self.workspace_root.read().clone();
-
-        if let Some(root) = workspace_root {
-            let vendor_dir_paths = self.vendor_dir_paths.lock().clone();
-
-            // Re-read existing URIs after phase 1 may have added more.
-            let existing_uris: HashSet<String> = self.symbol_maps.read().keys().cloned().collect();
+        let roots: Vec<PathBuf> = self
+            .workspace_root
+            .read()
+            .iter()
+            .chain(self.additional_workspace_roots.read().iter())
+            .cloned()
+            .collect();
+        if roots.is_empty() {
+            return;
+        }
 
-            let php_files = collect_php_files_gitignore(&root, &vendor_dir_paths);
+        let vendor_dir_paths = self.vendor_dir_paths.lock().clone();
 
-            let phase2_work: Vec<(String, PathBuf)> = php_files
-                .into_iter()
-                .filter_map(|path| {
-                    let uri = crate::util::path_to_uri(&path);
-                    if existing_uris.contains(&uri) {
-                        None
-                    } else {
-                        Some((uri, path))
-                    }
-                })
-                .collect();
+        // Re-read existing URIs after phase 1 may have added more.
+        let mut seen_uris: HashSet<String> = self.symbol_maps.read().keys().cloned().collect();
 
-            self.parse_paths_parallel(&phase2_work);
+        let mut phase2_work: Vec<(String, PathBuf)> = Vec::new();
+        for root in &roots {
+            if self.is_request_cancelled() {
+                return;
+            }
+            for path in collect_php_files_gitignore(root, &vendor_dir_paths) {
+                let uri = crate::util::path_to_uri(&path);
+                if seen_uris.insert(uri.clone()) {
+                    phase2_work.push((uri, path));
+                }
+            }
         }
+
+        self.parse_paths_parallel(&phase2_work);
     }
 
     /// Parse a batch of files in parallel using OS threads.
@@ -1385,9 +1392,13 @@ impl Backend {
             return;
         }
 
-        // For very small batches, avoid thread overhead.
-        if files.len() <= 2 {
+        // For very small batches, avoid thread overhead.  Parsing is
+        // also sequential when `[indexing] parallel = false`.
+        if files.len() <= 2 || !self.config().indexing.parallel() {
             for (uri, content) in &files {
+                if self.is_request_cancelled() {
+                    return;
+                }
                 if let Some(c) = content {
                     self.update_ast(uri, c);
                 } else if let Some(c) = self.get_file_content(uri) {
@@ -1421,6 +1432,9 @@ impl Backend {
                     .stack_size(PARSE_STACK_SIZE)
                     .spawn_scoped(s, move || {
                         for (uri, content) in chunk {
+                            if self.is_request_cancelled() {
+                                return;
+                            }
                             if let Some(c) = content {
                                 self.update_ast(uri, c);
                             } else if let Some(c) = self.get_file_content(uri) {
@@ -1445,9 +1459,13 @@ impl Backend {
             return;
         }
 
-        // For very small batches, avoid thread overhead.
-        if files.len() <= 2 {
+        // For very small batches, avoid thread overhead.  Parsing is
+        // also sequential when `[indexing] parallel = false`.
+        if files.len() <= 2 || !self.config().indexing.parallel() {
             for (uri, path) in files {
+                if self.is_request_cancelled() {
+                    return;
+                }
                 if let Ok(content) = std::fs::read_to_string(path) {
                     self.update_ast(uri, &content);
                 }
@@ -1473,6 +1491,9 @@ impl Backend {
                     .stack_size(PARSE_STACK_SIZE)
                     .spawn_scoped(s, move || {
                         for (uri, path) in *chunk {
+                            if self.is_request_cancelled() {
+                                return;
+                            }
                             if let Ok(content) = std::fs::read_to_string(path) {
                                 self.update_ast(uri, &content);
                             }
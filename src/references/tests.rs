@@ -1391,3 +1391,33 @@ async fn test_this_method_references_excludes_unrelated() {
         lines
     );
 }
+
+#[test]
+fn cancelled_request_skips_workspace_scan() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("Unopened.php"),
+        "<?php\nclass Unopened {}\n",
+    )
+    .unwrap();
+    let backend = Backend::new_test_with_workspace(dir.path().to_path_buf(), Vec::new());
+
+    let cancelled = backend.clone_for_blocking();
+    cancelled
+        .request_cancelled
+        .store(true, std::sync::atomic::Ordering::Release);
+    assert!(cancelled.is_request_cancelled());
+    assert!(
+        !backend.is_request_cancelled(),
+        "cancelling one request clone must not affect the original"
+    );
+
+    cancelled.ensure_workspace_indexed();
+    assert!(
+        backend.symbol_maps.read().is_empty(),
+        "a cancelled request should not parse workspace files"
+    );
+
+    backend.clone_for_blocking().ensure_workspace_indexed();
+    assert_eq!(backend.symbol_maps.read().len(), 1);
+}
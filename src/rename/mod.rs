@@ -196,11 +196,7 @@ impl Backend {
                 .push(text_edit);
         }
 
-        Some(WorkspaceEdit {
-            changes: Some(changes),
-            document_changes: None,
-            change_annotations: None,
-        })
+        Some(self.build_workspace_edit(changes))
     }
 
     /// Resolve the fully-qualified class name for a class rename.
@@ -509,11 +505,7 @@ impl Backend {
             });
         }
 
-        Some(WorkspaceEdit {
-            changes: Some(changes),
-            document_changes: None,
-            change_annotations: None,
-        })
+        Some(self.build_workspace_edit(changes))
     }
 
     /// Extract the renameable symbol name and its source range.
@@ -784,11 +776,7 @@ impl Backend {
             });
         }
 
-        Some(WorkspaceEdit {
-            changes: Some(changes),
-            document_changes: None,
-            change_annotations: None,
-        })
+        Some(self.build_workspace_edit(changes))
     }
 
     /// Collect text edits for `namespace` declaration lines where the
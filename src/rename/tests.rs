@@ -2939,3 +2939,66 @@ async fn rename_namespace_multiple_files_same_namespace() {
         result_c
     );
 }
+
+// ─── Versioned document changes ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn rename_uses_versioned_document_changes_when_supported() {
+    let backend = Backend::new_test();
+    backend
+        .supports_document_changes
+        .store(true, std::sync::atomic::Ordering::Release);
+
+    let uri_a = Url::parse("file:///src/a.php").unwrap();
+    let uri_b = Url::parse("file:///src/b.php").unwrap();
+    let text_a = concat!("<?php\n", "function greet(): void {}\n");
+    let text_b = concat!("<?php\n", "greet();\n");
+
+    open_file(&backend, &uri_a, text_a).await;
+    open_file(&backend, &uri_b, text_b).await;
+    backend
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri_b.clone(),
+                version: 7,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: text_b.to_string(),
+            }],
+        })
+        .await;
+
+    let edit = rename(&backend, &uri_a, 1, 10, "welcome")
+        .await
+        .expect("Expected a workspace edit");
+    assert!(edit.changes.is_none(), "changes should be replaced");
+
+    let Some(DocumentChanges::Edits(edits)) = &edit.document_changes else {
+        panic!("Expected versioned document edits, got {:?}", edit);
+    };
+    let version_of = |uri: &Url| {
+        edits
+            .iter()
+            .find(|e| e.text_document.uri == *uri)
+            .map(|e| e.text_document.version)
+    };
+    assert_eq!(version_of(&uri_a), Some(Some(1)));
+    assert_eq!(version_of(&uri_b), Some(Some(7)));
+}
+
+#[tokio::test]
+async fn rename_uses_plain_changes_without_client_support() {
+    let backend = Backend::new_test();
+    let uri = Url::parse("file:///src/a.php").unwrap();
+    let text = concat!("<?php\n", "function greet(): void {}\n", "greet();\n");
+
+    open_file(&backend, &uri, text).await;
+
+    let edit = rename(&backend, &uri, 1, 10, "welcome")
+        .await
+        .expect("Expected a workspace edit");
+    assert!(edit.document_changes.is_none());
+    assert_eq!(edits_for_uri(&edit, &uri).len(), 2);
+}
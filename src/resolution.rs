@@ -204,6 +204,21 @@ impl Backend {
             }
         }
 
+        drop(stub_idx);
+
+        // ── Phase 4: Editor-configured class aliases ──
+        // Aliases registered at runtime with `class_alias()` are
+        // invisible to static analysis, so users can list them in the
+        // `phpantom.aliases` editor setting.  Only one level is
+        // followed: an alias pointing at another alias is ignored,
+        // which also rules out cycles.
+        if let Some(target) = self.class_alias_target(class_name)
+            && self.class_alias_target(&target).is_none()
+            && let Some(cls) = self.find_or_load_class_inner(&target)
+        {
+            return Some(cls);
+        }
+
         // Cache the negative result so subsequent lookups for the same
         // unknown class skip the expensive multi-phase search.
         self.class_not_found_cache
@@ -523,9 +538,83 @@ impl Backend {
             }
         }
 
+        // Track on-disk files for the `[indexing] max-entries` limit.
+        // Stub and phar URIs cannot be re-read from disk, so they stay.
+        self.index_lru
+            .set_limit(self.config.lock().indexing.max_entries);
+        if self.index_lru.is_enabled() && uri.starts_with("file://") {
+            self.index_lru.record_load(
+                uri,
+                arc_classes
+                    .iter()
+                    .filter(|c| !c.name.starts_with("__anonymous@"))
+                    .map(|c| c.fqn().to_string()),
+            );
+            self.enforce_index_limit();
+        }
+
         Some(arc_classes)
     }
 
+    /// Drop the least recently used lazily loaded files once the index
+    /// holds more than `[indexing] max-entries` of them.
+    ///
+    /// Files open in the editor are never dropped.  Evicted classes are
+    /// re-parsed from disk by [`find_or_load_class`](Self::find_or_load_class)
+    /// through the class index, classmap, or PSR-4 phases.
+    fn enforce_index_limit(&self) {
+        let victims = {
+            let open = self.open_files.read();
+            self.index_lru.take_victims(|uri| open.contains_key(uri))
+        };
+        if victims.is_empty() {
+            return;
+        }
+        if self.index_lru.should_warn() {
+            tracing::warn!(
+                "PHPantom: index exceeded {} files (`[indexing] max-entries`); \
+                 evicting least recently used files, which will be re-parsed on demand",
+                self.index_lru.limit()
+            );
+        }
+        for uri in &victims {
+            self.evict_lazily_loaded_file(uri);
+        }
+    }
+
+    /// Remove a lazily loaded file's classes from `ast_map`, `fqn_index`,
+    /// the method store, and the resolved-class cache.
+    fn evict_lazily_loaded_file(&self, uri: &str) {
+        let Some(classes) = self.ast_map.write().remove(uri) else {
+            return;
+        };
+        self.parsed_uris.write().remove(uri);
+
+        let fqns: Vec<String> = classes
+            .iter()
+            .filter(|c| !c.name.starts_with("__anonymous@"))
+            .map(|c| c.fqn().to_string())
+            .collect();
+        {
+            let mut fqn_idx = self.fqn_index.write();
+            for fqn in &fqns {
+                // Only drop the entry when it still points at this file's
+                // class; another file may have redefined the FQN since.
+                let is_ours = fqn_idx
+                    .get(fqn)
+                    .is_some_and(|cls| classes.iter().any(|c| Arc::ptr_eq(c, cls)));
+                if is_ours {
+                    fqn_idx.remove(fqn);
+                }
+            }
+        }
+        self.evict_methods_for_fqns(&fqns);
+        let mut cache = self.resolved_class_cache.lock();
+        for fqn in &fqns {
+            let _ = crate::virtual_members::evict_fqn(&mut cache, fqn);
+        }
+    }
+
     /// Try to find a standalone function by name, checking user-defined
     /// functions first, then falling back to embedded PHP stubs.
     ///
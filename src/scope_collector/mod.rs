@@ -128,6 +128,10 @@ pub(crate) struct Frame {
     /// before the frame's `start` and cannot be distinguished from
     /// outer-scope writes by offset alone).
     pub parameters: Vec<String>,
+    /// Whether the frame is a `static function () { … }` closure or a
+    /// `static fn () => …` arrow function, which can never be bound to
+    /// an object.
+    pub is_static: bool,
 }
 
 /// The kind of scope boundary a [`Frame`] represents.
@@ -515,6 +519,7 @@ pub(crate) fn collect_scope_with_resolver(
         kind: FrameKind::TopLevel,
         captures: Vec::new(),
         parameters: Vec::new(),
+        is_static: false,
     });
 
     for stmt in statements {
@@ -637,6 +642,7 @@ pub(crate) fn collect_function_scope_with_kind_and_resolver<'a>(
         kind,
         captures: Vec::new(),
         parameters: param_names,
+        is_static: false,
     });
 
     // Record parameters as writes.
@@ -806,6 +812,7 @@ fn walk_statement(stmt: &Statement<'_>, collector: &mut Collector<'_>) {
                     kind: FrameKind::Catch,
                     captures: Vec::new(),
                     parameters: catch_params,
+                    is_static: false,
                 });
                 if let Some(ref var) = catch.variable {
                     let name = var.name.to_string();
@@ -1639,6 +1646,7 @@ fn walk_closure(closure: &Closure<'_>, collector: &mut Collector<'_>) {
         kind: FrameKind::Closure,
         captures: captures.clone(),
         parameters: param_names,
+        is_static: closure.r#static.is_some(),
     });
 
     // Record parameters as writes in the closure frame.
@@ -1684,6 +1692,7 @@ fn walk_arrow_function(arrow: &ArrowFunction<'_>, collector: &mut Collector<'_>)
         kind: FrameKind::ArrowFunction,
         captures: Vec::new(), // Arrow functions capture implicitly.
         parameters: param_names,
+        is_static: arrow.r#static.is_some(),
     });
 
     // Record parameters as writes.
@@ -58,6 +58,21 @@ impl Backend {
                 len_b.cmp(&len_a).then(a.0.cmp(&b.0))
             });
 
+            // A cursor on the boundary between two adjacent nodes (e.g.
+            // `foo();|bar();`) lies inside both, so siblings can end up in
+            // the list.  Keep only spans nested inside the previous one so
+            // that every level strictly contains the next.
+            let mut nested: Vec<(u32, u32)> = Vec::with_capacity(spans.len());
+            for span in spans {
+                if nested
+                    .last()
+                    .is_none_or(|outer| outer.0 <= span.0 && span.1 <= outer.1)
+                {
+                    nested.push(span);
+                }
+            }
+            let spans = nested;
+
             // Build the linked list from outermost to innermost.
             let selection_range = build_selection_range(content, &spans);
             results.push(selection_range);
@@ -1101,6 +1116,19 @@ function test() {
         }
     }
 
+    #[test]
+    fn cursor_between_adjacent_statements_stays_nested() {
+        let content = "<?php\nfoo();bar();\n";
+        // Cursor right after `foo();`, which is also the start of `bar();`.
+        let results = selection_ranges(content, &[Position::new(1, 6)]);
+        assert_eq!(results.len(), 1);
+        let ranges = flatten(&results[0]);
+        assert_nested(&ranges);
+        for window in ranges.windows(2) {
+            assert_ne!(window[0], window[1], "levels must strictly grow");
+        }
+    }
+
     // ─── 1. Switch statement ────────────────────────────────────────────
 
     #[test]
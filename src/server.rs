@@ -39,18 +39,39 @@ use crate::composer;
 use crate::config::IndexingStrategy;
 use crate::formatting;
 use crate::phar;
+use crate::status::ServerStatus;
+use crate::util::INDEXING_PROGRESS_TOKEN;
+
+/// `workspace/executeCommand` name that rebuilds the workspace index.
+pub(crate) const REINDEX_COMMAND: &str = "phpantom.reindex";
+
+/// `workspace/executeCommand` name that drops all cached state and
+/// re-indexes.  PHPantom keeps no on-disk cache, so this is the same
+/// rebuild as [`REINDEX_COMMAND`] followed by a confirmation message.
+pub(crate) const CLEAR_CACHE_COMMAND: &str = "phpantom.clearCache";
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
-        // Extract and store the workspace root path
+        // Extract and store the workspace root path.  Clients that only
+        // send `workspaceFolders` get their first folder as the root.
+        let mut folders: Vec<PathBuf> = params
+            .workspace_folders
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|f| f.uri.to_file_path().ok())
+            .collect();
         let workspace_root = params
             .root_uri
             .as_ref()
-            .and_then(|uri| uri.to_file_path().ok());
+            .and_then(|uri| uri.to_file_path().ok())
+            .or_else(|| folders.first().cloned());
 
         if let Some(root) = workspace_root {
+            folders.retain(|f| *f != root);
             *self.workspace_root.write() = Some(root);
+            *self.additional_workspace_roots.write() = folders;
         }
 
         // Store the client name for quirks-mode adjustments.
@@ -81,6 +102,19 @@ impl LanguageServer for Backend {
         self.supports_file_rename
             .store(client_supports_file_rename, Ordering::Release);
 
+        // Detect whether the client accepts versioned `document_changes`
+        // in workspace edits.  Rename and auto-import use them to tag
+        // edits to open documents with the buffer version.
+        let client_supports_document_changes = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|ws| ws.workspace_edit.as_ref())
+            .and_then(|we| we.document_changes)
+            .unwrap_or(false);
+        self.supports_document_changes
+            .store(client_supports_document_changes, Ordering::Release);
+
         // Detect whether the client supports server-initiated work-done
         // progress (window/workDoneProgress/create).  Per the LSP spec,
         // we must not send that request unless the client opts in.
@@ -107,6 +141,48 @@ impl LanguageServer for Backend {
             Ordering::Release,
         );
 
+        // Detect whether the client lets us register file watchers, which
+        // is how `composer.json` edits reach us.
+        let client_supports_watched_files_dynamic_registration = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|ws| ws.did_change_watched_files.as_ref())
+            .and_then(|w| w.dynamic_registration)
+            .unwrap_or(false);
+        self.supports_watched_files_dynamic_registration.store(
+            client_supports_watched_files_dynamic_registration,
+            Ordering::Release,
+        );
+
+        // Detect whether the client answers `workspace/configuration`
+        // requests, which is how per-folder editor settings are pulled.
+        let client_supports_configuration = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|ws| ws.configuration)
+            .unwrap_or(false);
+        self.supports_workspace_configuration
+            .store(client_supports_configuration, Ordering::Release);
+
+        // Detect whether the client implements the command behind the
+        // reference-count code lenses.  Clients list the commands they
+        // handle under `experimental.commands.commands`.
+        let client_supports_show_references = params
+            .capabilities
+            .experimental
+            .as_ref()
+            .and_then(|e| e.pointer("/commands/commands"))
+            .and_then(|c| c.as_array())
+            .is_some_and(|commands| {
+                commands
+                    .iter()
+                    .any(|c| c.as_str() == Some(crate::code_lens::SHOW_REFERENCES_COMMAND))
+            });
+        self.supports_show_references_command
+            .store(client_supports_show_references, Ordering::Release);
+
         Ok(InitializeResult {
             offset_encoding: None,
             capabilities: ServerCapabilities {
@@ -131,6 +207,7 @@ impl LanguageServer for Backend {
                         "\\".to_string(),
                         "/".to_string(),
                         "*".to_string(),
+                        "%".to_string(),
                     ]),
                     all_commit_characters: None,
                     work_done_progress_options: WorkDoneProgressOptions {
@@ -146,6 +223,14 @@ impl LanguageServer for Backend {
                 definition_provider: Some(OneOf::Left(true)),
                 type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
                 implementation_provider: Some(ImplementationProviderCapability::Simple(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                workspace: Some(WorkspaceServerCapabilities {
+                    workspace_folders: Some(WorkspaceFoldersServerCapabilities {
+                        supported: Some(true),
+                        change_notifications: Some(OneOf::Left(true)),
+                    }),
+                    file_operations: None,
+                }),
                 references_provider: Some(OneOf::Left(true)),
                 document_highlight_provider: Some(OneOf::Left(true)),
                 linked_editing_range_provider: Some(LinkedEditingRangeServerCapabilities::Simple(
@@ -175,7 +260,7 @@ impl LanguageServer for Backend {
                 workspace_symbol_provider: Some(OneOf::Left(true)),
                 folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
                 code_lens_provider: Some(CodeLensOptions {
-                    resolve_provider: Some(false),
+                    resolve_provider: Some(true),
                 }),
                 selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
                 document_formatting_provider: Some(OneOf::Left(true)),
@@ -183,6 +268,7 @@ impl LanguageServer for Backend {
                     first_trigger_character: "\n".to_string(),
                     more_trigger_character: None,
                 }),
+                color_provider: Some(ColorProviderCapability::Simple(true)),
                 document_link_provider: Some(DocumentLinkOptions {
                     resolve_provider: Some(false),
                     work_done_progress_options: WorkDoneProgressOptions {
@@ -213,6 +299,13 @@ impl LanguageServer for Backend {
                 } else {
                     None
                 },
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![REINDEX_COMMAND.to_string(), CLEAR_CACHE_COMMAND.to_string()],
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                }),
+                experimental: Some(serde_json::json!({ "statusNotification": true })),
                 ..ServerCapabilities::default()
             },
             server_info: Some(ServerInfo {
@@ -244,64 +337,20 @@ impl LanguageServer for Backend {
                 }
             }
 
+            // Pull editor settings (PHP version, include paths, class
+            // aliases) before indexing so they shape the first index.
+            self.pull_client_settings().await;
+
             // Parse composer.json once up front.  The result is used for
             // PHP version detection and passed into init_single_project
             // so the file is never re-read during startup.
             let composer_package = composer::read_composer_package(&root);
 
-            // Detect the target PHP version.  The config file override
-            // takes precedence; otherwise fall back to composer.json.
-            let php_version = self
-                .config()
-                .php
-                .version
-                .as_deref()
-                .and_then(crate::types::PhpVersion::from_composer_constraint)
-                .unwrap_or_else(|| {
-                    composer_package
-                        .as_ref()
-                        .and_then(composer::detect_php_version_from_package)
-                        .unwrap_or_default()
-                });
+            let php_version = self.detect_php_version(composer_package.as_ref());
             self.set_php_version(php_version);
 
-            let has_composer_json = composer_package.is_some();
-
-            // ── Create a progress token for indexing feedback ────────
-            let progress_token = self.progress_create("phpantom/indexing").await;
-            if let Some(ref tok) = progress_token {
-                self.progress_begin(tok, "PHPantom: Indexing", Some("Starting".to_string()))
-                    .await;
-            }
-
-            if has_composer_json {
-                // ── Single-project path (root composer.json exists) ──────
-                self.init_single_project(
-                    &root,
-                    php_version,
-                    composer_package,
-                    progress_token.as_ref(),
-                )
+            self.index_workspace(&root, php_version, composer_package)
                 .await;
-            } else {
-                // ── Monorepo / non-Composer path ────────────────────────
-                let subprojects = composer::discover_subproject_roots(&root);
-
-                if !subprojects.is_empty() {
-                    self.init_monorepo(&root, &subprojects, php_version, progress_token.as_ref())
-                        .await;
-                } else {
-                    // No subprojects found — pure non-Composer workspace.
-                    self.init_no_composer(&root, php_version, progress_token.as_ref())
-                        .await;
-                }
-            }
-
-            if let Some(ref tok) = progress_token {
-                let classmap_count = self.classmap.read().len();
-                self.progress_end(tok, Some(format!("Indexed {} classes", classmap_count)))
-                    .await;
-            }
         } else {
             self.log(MessageType::INFO, "PHPantom initialized!".to_string())
                 .await;
@@ -371,6 +420,28 @@ impl LanguageServer for Backend {
                 .await;
         }
 
+        // Watch `composer.json` so that PSR-4 mapping edits take effect
+        // without a restart (see `did_change_watched_files`).
+        if self
+            .supports_watched_files_dynamic_registration
+            .load(Ordering::Acquire)
+            && let Some(client) = &self.client
+        {
+            let options = DidChangeWatchedFilesRegistrationOptions {
+                watchers: vec![FileSystemWatcher {
+                    glob_pattern: GlobPattern::String("**/composer.json".to_string()),
+                    kind: None,
+                }],
+            };
+            let _ = client
+                .register_capability(vec![Registration {
+                    id: "composer-json-watcher".to_string(),
+                    method: "workspace/didChangeWatchedFiles".to_string(),
+                    register_options: serde_json::to_value(options).ok(),
+                }])
+                .await;
+        }
+
         // Clear the negative class-resolution cache.  During startup,
         // `did_open` may have triggered `update_ast` → `find_or_load_class`
         // before the classmap / class_index was fully populated, caching
@@ -452,6 +523,9 @@ impl LanguageServer for Backend {
         self.open_files
             .write()
             .insert(uri.clone(), Arc::clone(&text));
+        self.open_file_versions
+            .write()
+            .insert(uri.clone(), doc.version);
 
         // Parse and update AST map, use map, and namespace map
         self.update_ast(&uri, &text);
@@ -501,6 +575,9 @@ impl LanguageServer for Backend {
         self.open_files
             .write()
             .insert(uri.clone(), Arc::clone(&text));
+        self.open_file_versions
+            .write()
+            .insert(uri.clone(), params.text_document.version);
 
         // Re-parse and update AST map, use map, and namespace map
         let signature_changed = self.update_ast(&uri, &text);
@@ -523,6 +600,7 @@ impl LanguageServer for Backend {
         let uri = params.text_document.uri.to_string();
 
         self.open_files.write().remove(&uri);
+        self.open_file_versions.write().remove(&uri);
 
         // Clean up Blade preprocessor state for the closed file.
         if self.is_blade_file(&uri) {
@@ -600,6 +678,7 @@ impl LanguageServer for Backend {
         //
         // Wrapped in tokio::spawn for cancellation safety (see references handler).
         let backend = self.clone_for_blocking();
+        let _cancel_guard = CancelOnDrop::new(&backend);
         let uri_clone = uri.clone();
         let result = tokio::spawn(async move {
             tokio::task::spawn_blocking(move || {
@@ -732,8 +811,11 @@ impl LanguageServer for Backend {
         // cancels this handler future via $/cancelRequest.  Without
         // this wrapper, dropping the handler future detaches the
         // spawn_blocking JoinHandle, and tower-lsp 0.20 may corrupt
-        // its internal state when the orphaned task completes.
+        // its internal state when the orphaned task completes.  The
+        // guard tells the blocking task to stop scanning once the
+        // handler has been dropped.
         let backend = self.clone_for_blocking();
+        let _cancel_guard = CancelOnDrop::new(&backend);
         let uri_clone = uri.clone();
         let result = tokio::spawn(async move {
             tokio::task::spawn_blocking(move || {
@@ -984,10 +1066,33 @@ impl LanguageServer for Backend {
     async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
         let uri = params.text_document.uri.to_string();
         self.handle_with_uri("code_lens", &uri, |content| {
-            self.handle_code_lens(&uri, content)
+            let mut lenses = self.handle_code_lens(&uri, content).unwrap_or_default();
+            lenses.extend(self.reference_code_lenses(&uri, content));
+            if lenses.is_empty() {
+                None
+            } else {
+                Some(lenses)
+            }
         })
     }
 
+    async fn code_lens_resolve(&self, params: CodeLens) -> Result<CodeLens> {
+        // Counting references may parse the whole workspace on first
+        // use, so run it on a blocking thread.
+        let backend = self.clone_for_blocking();
+        let _cancel_guard = CancelOnDrop::new(&backend);
+        let fallback = params.clone();
+        let resolved = tokio::spawn(async move {
+            tokio::task::spawn_blocking(move || backend.resolve_code_lens(params))
+                .await
+                .ok()
+        })
+        .await
+        .ok()
+        .flatten();
+        Ok(resolved.unwrap_or(fallback))
+    }
+
     async fn document_link(&self, params: DocumentLinkParams) -> Result<Option<Vec<DocumentLink>>> {
         let uri = params.text_document.uri.to_string();
         self.handle_with_uri("document_link", &uri, |content| {
@@ -995,6 +1100,25 @@ impl LanguageServer for Backend {
         })
     }
 
+    async fn document_color(&self, params: DocumentColorParams) -> Result<Vec<ColorInformation>> {
+        let uri = params.text_document.uri.to_string();
+        let colors = self.handle_with_uri("document_color", &uri, |content| {
+            self.handle_document_color(&uri, content)
+        })?;
+        Ok(colors.unwrap_or_default())
+    }
+
+    async fn color_presentation(
+        &self,
+        params: ColorPresentationParams,
+    ) -> Result<Vec<ColorPresentation>> {
+        let uri = params.text_document.uri.to_string();
+        let presentations = self.handle_with_uri("color_presentation", &uri, |content| {
+            Some(self.handle_color_presentation(content, params.color, params.range))
+        })?;
+        Ok(presentations.unwrap_or_default())
+    }
+
     async fn selection_range(
         &self,
         params: SelectionRangeParams,
@@ -1061,6 +1185,7 @@ impl LanguageServer for Backend {
         params: TypeHierarchySubtypesParams,
     ) -> Result<Option<Vec<TypeHierarchyItem>>> {
         let backend = self.clone_for_blocking();
+        let _cancel_guard = CancelOnDrop::new(&backend);
         let item = params.item;
         let token = match params.work_done_progress_params.work_done_token {
             Some(t) => Some(t),
@@ -1088,6 +1213,76 @@ impl LanguageServer for Backend {
         Ok(result)
     }
 
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<Option<Vec<CallHierarchyItem>>> {
+        let uri = params
+            .text_document_position_params
+            .text_document
+            .uri
+            .to_string();
+        let position = params.text_document_position_params.position;
+        self.handle_with_position("prepare_call_hierarchy", &uri, position, |content, pos| {
+            self.prepare_call_hierarchy_impl(&uri, content, pos)
+        })
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        let backend = self.clone_for_blocking();
+        let _cancel_guard = CancelOnDrop::new(&backend);
+        let item = params.item;
+        let token = match params.work_done_progress_params.work_done_token {
+            Some(t) => Some(t),
+            None => self.progress_create("call_hierarchy_incoming").await,
+        };
+
+        if let Some(ref tok) = token {
+            self.progress_begin(tok, "Incoming Calls", Some("Scanning…".to_string()))
+                .await;
+        }
+
+        // Wrapped in tokio::spawn for cancellation safety (see references handler).
+        let result = tokio::spawn(async move {
+            tokio::task::spawn_blocking(move || backend.incoming_calls_impl(&item))
+                .await
+                .unwrap_or(None)
+        })
+        .await
+        .unwrap_or(None);
+
+        if let Some(ref tok) = token {
+            self.progress_end(tok, Some("Done".to_string())).await;
+        }
+
+        Ok(result)
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        let backend = self.clone_for_blocking();
+        let _cancel_guard = CancelOnDrop::new(&backend);
+        let item = params.item;
+
+        // Every call site is resolved through go-to-definition, which
+        // may load classes from disk, so keep it off the async runtime.
+        // Wrapped in tokio::spawn for cancellation safety (see references handler).
+        let result = tokio::spawn(async move {
+            tokio::task::spawn_blocking(move || backend.outgoing_calls_impl(&item))
+                .await
+                .unwrap_or(None)
+        })
+        .await
+        .unwrap_or(None);
+
+        Ok(result)
+    }
+
     async fn on_type_formatting(
         &self,
         params: DocumentOnTypeFormattingParams,
@@ -1351,6 +1546,195 @@ impl LanguageServer for Backend {
             WorkspaceDiagnosticReport { items },
         ))
     }
+
+    async fn did_change_configuration(&self, _: DidChangeConfigurationParams) {
+        // The notification payload is client-specific (VS Code sends the
+        // whole settings tree, others send nothing), so re-pull through
+        // `workspace/configuration` instead of reading it.
+        if !self.pull_client_settings().await {
+            return;
+        }
+        let Some(root) = self.workspace_root.read().clone() else {
+            return;
+        };
+
+        let composer_package = composer::read_composer_package(&root);
+        self.set_php_version(self.detect_php_version(composer_package.as_ref()));
+
+        self.log(
+            MessageType::INFO,
+            "PHPantom: editor settings changed, re-indexing".to_string(),
+        )
+        .await;
+        self.reindex_workspace().await;
+    }
+
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        let to_paths = |folders: &[WorkspaceFolder]| -> Vec<PathBuf> {
+            folders
+                .iter()
+                .filter_map(|f| f.uri.to_file_path().ok())
+                .collect()
+        };
+        let removed = to_paths(&params.event.removed);
+        let added = to_paths(&params.event.added);
+        if removed.is_empty() && added.is_empty() {
+            return;
+        }
+
+        let (root_changed, added_extra) = {
+            let mut root = self.workspace_root.write();
+            let mut extra = self.additional_workspace_roots.write();
+            let old_root = root.clone();
+            extra.retain(|f| !removed.contains(f));
+            if root.as_ref().is_some_and(|r| removed.contains(r)) {
+                *root = (!extra.is_empty()).then(|| extra.remove(0));
+            }
+            let mut added_extra = Vec::new();
+            for folder in added {
+                if root.as_ref() == Some(&folder) || extra.contains(&folder) {
+                    continue;
+                }
+                if root.is_none() {
+                    *root = Some(folder);
+                } else {
+                    extra.push(folder.clone());
+                    added_extra.push(folder);
+                }
+            }
+            (*root != old_root, added_extra)
+        };
+
+        // Settings of removed folders are dropped here, along with the
+        // include paths they contributed.
+        let include_paths_before = self.client_include_paths();
+        self.pull_client_settings().await;
+
+        if self.workspace_root.read().is_none() {
+            self.clear_workspace_index();
+            return;
+        }
+
+        // The primary root owns the relative PSR-4 mappings, the vendor
+        // directory and the project config, so a new primary root means
+        // a full rebuild.  Any other change only touches the folders
+        // that came or went.
+        if root_changed {
+            self.log(
+                MessageType::INFO,
+                "PHPantom: workspace root changed, re-indexing".to_string(),
+            )
+            .await;
+            self.reindex_workspace().await;
+            return;
+        }
+
+        let include_paths = self.client_include_paths();
+        let is_workspace_folder = |path: &PathBuf| {
+            self.workspace_root.read().as_ref() == Some(path)
+                || self.additional_workspace_roots.read().contains(path)
+        };
+        let mut evicted = removed;
+        evicted.extend(
+            include_paths_before
+                .iter()
+                .filter(|p| !include_paths.contains(p) && !is_workspace_folder(p))
+                .cloned(),
+        );
+        let mut indexed = added_extra;
+        indexed.extend(
+            include_paths
+                .iter()
+                .filter(|p| !include_paths_before.contains(p) && p.is_dir())
+                .filter(|p| !is_workspace_folder(p))
+                .cloned(),
+        );
+
+        self.log(
+            MessageType::INFO,
+            format!(
+                "PHPantom: workspace folders changed ({} added, {} removed)",
+                indexed.len(),
+                evicted.len()
+            ),
+        )
+        .await;
+        for folder in &evicted {
+            self.evict_workspace_folder(folder);
+        }
+        for folder in &indexed {
+            self.index_additional_root(folder);
+        }
+        self.psr4_mappings
+            .write()
+            .sort_by_key(|b| std::cmp::Reverse(b.prefix.len()));
+
+        let open_files: Vec<(String, Arc<String>)> = self
+            .open_files
+            .read()
+            .iter()
+            .map(|(uri, content)| (uri.clone(), Arc::clone(content)))
+            .collect();
+        for (uri, content) in &open_files {
+            self.update_ast(uri, content);
+        }
+        self.schedule_diagnostics_for_open_files("");
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let Some(root) = self.workspace_root.read().clone() else {
+            return;
+        };
+        let root_composer_json = root.join("composer.json");
+        let touches_root_composer_json = params.changes.iter().any(|change| {
+            change
+                .uri
+                .to_file_path()
+                .is_ok_and(|path| path == root_composer_json)
+        });
+        if !touches_root_composer_json {
+            return;
+        }
+
+        let (added, removed) = self.refresh_psr4_mappings(&root).await;
+        if added == 0 && removed == 0 {
+            return;
+        }
+        self.log(
+            MessageType::INFO,
+            format!(
+                "PHPantom: composer.json changed ({} PSR-4 mapping(s) added, {} removed)",
+                added, removed
+            ),
+        )
+        .await;
+        self.schedule_diagnostics_for_open_files("");
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        match params.command.as_str() {
+            REINDEX_COMMAND => {
+                self.reindex_workspace().await;
+                Ok(None)
+            }
+            CLEAR_CACHE_COMMAND => {
+                self.reindex_workspace().await;
+                self.show_message(
+                    MessageType::INFO,
+                    "PHPantom: caches cleared and workspace re-indexed".to_string(),
+                )
+                .await;
+                Ok(None)
+            }
+            other => Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "Unknown command: {}",
+                other
+            ))),
+        }
+    }
 }
 
 /// Convert a `Vec<Location>` into a `GotoDefinitionResponse`.
@@ -1368,6 +1752,67 @@ fn wrap_locations(locations: Vec<Location>) -> Option<GotoDefinitionResponse> {
     }
 }
 
+/// Flags a blocking request clone as cancelled when dropped.
+///
+/// tower-lsp handles `$/cancelRequest` by dropping the handler future.
+/// Holding this guard in the handler turns that drop into a signal the
+/// blocking task can observe through
+/// [`Backend::is_request_cancelled`].  Setting the flag after the task
+/// has already finished is harmless.
+struct CancelOnDrop(Arc<std::sync::atomic::AtomicBool>);
+
+impl CancelOnDrop {
+    fn new(backend: &Backend) -> Self {
+        CancelOnDrop(Arc::clone(&backend.request_cancelled))
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// Sizes of the workspace index, reported once indexing finishes.
+struct IndexCounts {
+    files: usize,
+    classes: usize,
+    functions: usize,
+}
+
+/// Build the "indexing finished" message shown to the user once
+/// workspace indexing completes.
+///
+/// Returns `None` for small projects that index almost instantly
+/// (fewer than 20 files and under 500 ms), where a popup would only
+/// be noise.
+fn indexing_summary(counts: &IndexCounts, elapsed: std::time::Duration) -> Option<String> {
+    const MIN_FILES: usize = 20;
+    const MIN_ELAPSED: std::time::Duration = std::time::Duration::from_millis(500);
+
+    if counts.files < MIN_FILES && elapsed < MIN_ELAPSED {
+        return None;
+    }
+
+    Some(format!(
+        "PHPantom: indexed {} files, {} classes, {} functions",
+        counts.files, counts.classes, counts.functions
+    ))
+}
+
+/// URI prefixes (with a trailing `/`) of the files under `dir`, as
+/// given and canonicalized, since URIs in the index may use either form.
+fn dir_uri_prefixes(dir: &Path) -> Vec<String> {
+    let mut prefixes = vec![format!("{}/", crate::util::path_to_uri(dir))];
+    if let Ok(canonical) = dir.canonicalize() {
+        let prefix = format!("{}/", crate::util::path_to_uri(&canonical));
+        if !prefixes.contains(&prefix) {
+            prefixes.push(prefix);
+        }
+    }
+    prefixes
+}
+
 // ─── Self-scan helpers ──────────────────────────────────────────────────────
 
 impl Backend {
@@ -1442,6 +1887,490 @@ impl Backend {
 
     // ── Initialization helpers ───────────────────────────────────────────
 
+    /// Build the class, function and constant indices for the workspace
+    /// rooted at `root`, reporting `$/progress` along the way.
+    ///
+    /// Called from `initialized` and again by the `phpantom.reindex`
+    /// command after the previous index has been cleared.
+    pub(crate) async fn index_workspace(
+        &self,
+        root: &Path,
+        php_version: crate::types::PhpVersion,
+        composer_package: Option<composer::ComposerPackage>,
+    ) {
+        let has_composer_json = composer_package.is_some();
+        let indexing_started = std::time::Instant::now();
+        self.indexing_cancelled.store(false, Ordering::Release);
+
+        // ── Create a progress token for indexing feedback ────────
+        let progress_token = self.progress_create(INDEXING_PROGRESS_TOKEN).await;
+        if let Some(ref tok) = progress_token {
+            self.progress_begin(tok, "PHPantom: Indexing", Some("Starting".to_string()))
+                .await;
+        }
+        self.send_status(ServerStatus::Indexing { progress: 0 })
+            .await;
+
+        if has_composer_json {
+            // ── Single-project path (root composer.json exists) ──────
+            self.init_single_project(root, php_version, composer_package, progress_token.as_ref())
+                .await;
+        } else {
+            // ── Monorepo / non-Composer path ────────────────────────
+            let subprojects = composer::discover_subproject_roots(root);
+
+            if !subprojects.is_empty() {
+                self.init_monorepo(root, &subprojects, php_version, progress_token.as_ref())
+                    .await;
+            } else {
+                // No subprojects found — pure non-Composer workspace.
+                self.init_no_composer(root, php_version, progress_token.as_ref())
+                    .await;
+            }
+        }
+
+        let mut additional_roots = self.additional_workspace_roots.read().clone();
+        for path in self.client_include_paths() {
+            if path.is_dir() && !additional_roots.contains(&path) {
+                additional_roots.push(path);
+            }
+        }
+        if !additional_roots.is_empty() && !self.is_indexing_cancelled() {
+            self.report_indexing_progress(
+                progress_token.as_ref(),
+                95,
+                format!("Indexing {} more folder(s)", additional_roots.len()),
+            )
+            .await;
+            for folder in &additional_roots {
+                if self.is_indexing_cancelled() {
+                    break;
+                }
+                self.index_additional_root(folder);
+            }
+            self.psr4_mappings
+                .write()
+                .sort_by_key(|b| std::cmp::Reverse(b.prefix.len()));
+        }
+
+        if self.is_indexing_cancelled() {
+            if let Some(ref tok) = progress_token {
+                self.progress_end(tok, Some("Cancelled".to_string())).await;
+            }
+            self.send_status(ServerStatus::Error {
+                message: "Indexing was cancelled and the index is partial".to_string(),
+            })
+            .await;
+            self.show_message(
+                MessageType::WARNING,
+                format!(
+                    "PHPantom: indexing was cancelled and the index is partial. \
+                     Run the {} command to rebuild it.",
+                    REINDEX_COMMAND
+                ),
+            )
+            .await;
+            return;
+        }
+
+        if let Some(ref tok) = progress_token {
+            let classmap_count = self.classmap.read().len();
+            self.progress_end(tok, Some(format!("Indexed {} classes", classmap_count)))
+                .await;
+        }
+
+        let elapsed = indexing_started.elapsed();
+        let counts = self.index_counts();
+        self.send_status(ServerStatus::Ready {
+            files_indexed: counts.files,
+            classes_indexed: counts.classes,
+            functions_indexed: counts.functions,
+            duration_ms: elapsed.as_millis() as u64,
+        })
+        .await;
+
+        if let Some(summary) = indexing_summary(&counts, elapsed) {
+            self.show_message(MessageType::INFO, summary).await;
+        }
+    }
+
+    /// Report indexing progress as both `$/progress` (when the client
+    /// created a token) and a `$/status` notification.
+    async fn report_indexing_progress(
+        &self,
+        progress_token: Option<&NumberOrString>,
+        percentage: u32,
+        message: String,
+    ) {
+        self.send_status(ServerStatus::Indexing {
+            progress: percentage,
+        })
+        .await;
+        if let Some(tok) = progress_token {
+            self.progress_report(tok, percentage, Some(message)).await;
+        }
+    }
+
+    /// Determine the target PHP version.
+    ///
+    /// The `.phpantom.toml` override takes precedence, then the editor's
+    /// `phpantom.phpVersion` setting for the primary folder, and finally
+    /// the `require.php` constraint from `composer.json`.
+    fn detect_php_version(
+        &self,
+        composer_package: Option<&composer::ComposerPackage>,
+    ) -> crate::types::PhpVersion {
+        self.config()
+            .php
+            .version
+            .or(self.root_client_settings().php_version)
+            .as_deref()
+            .and_then(crate::types::PhpVersion::from_composer_constraint)
+            .unwrap_or_else(|| {
+                composer_package
+                    .and_then(composer::detect_php_version_from_package)
+                    .unwrap_or_default()
+            })
+    }
+
+    /// Pull the `phpantom` settings section for every workspace folder
+    /// through `workspace/configuration` and cache them.
+    ///
+    /// Settings cached for folders that are no longer part of the
+    /// workspace are dropped first.  Returns `true` when any folder's
+    /// settings changed or were dropped.  Pulls nothing when the client
+    /// lacks the capability.
+    pub(crate) async fn pull_client_settings(&self) -> bool {
+        let pruned = self.prune_client_settings();
+        if !self
+            .supports_workspace_configuration
+            .load(Ordering::Acquire)
+        {
+            return pruned;
+        }
+        let Some(client) = &self.client else {
+            return pruned;
+        };
+
+        let folders = self.workspace_folder_uris();
+        if folders.is_empty() {
+            return pruned;
+        }
+
+        let items = folders
+            .iter()
+            .map(|uri| ConfigurationItem {
+                scope_uri: Some(uri.clone()),
+                section: Some(crate::config::CLIENT_SETTINGS_SECTION.to_string()),
+            })
+            .collect();
+        let values = match client.configuration(items).await {
+            Ok(values) => values,
+            Err(e) => {
+                self.log(
+                    MessageType::WARNING,
+                    format!("PHPantom: failed to read editor settings: {}", e),
+                )
+                .await;
+                return pruned;
+            }
+        };
+
+        let mut changed = pruned;
+        for (uri, value) in folders.iter().zip(values) {
+            let settings = crate::config::ClientSettings::from_value(value);
+            changed |= self.set_client_settings(uri.as_str(), settings);
+        }
+        changed
+    }
+
+    /// Handle `window/workDoneProgress/cancel`.
+    ///
+    /// tower-lsp's `LanguageServer` trait has no hook for this
+    /// notification, so it is registered as a custom method in `main`.
+    /// Cancelling the indexing token stops the running index build.
+    pub async fn work_done_progress_cancel(&self, params: WorkDoneProgressCancelParams) {
+        if params.token == NumberOrString::String(INDEXING_PROGRESS_TOKEN.to_string()) {
+            self.indexing_cancelled.store(true, Ordering::Release);
+        }
+    }
+
+    /// Whether the client cancelled the current indexing run through
+    /// `window/workDoneProgress/cancel`.
+    pub(crate) fn is_indexing_cancelled(&self) -> bool {
+        self.indexing_cancelled.load(Ordering::Acquire)
+    }
+
+    /// Discard the workspace index and rebuild it from disk.
+    ///
+    /// Backs the `phpantom.reindex` command, the escape hatch for stale
+    /// indices after `composer install` or large code-generation runs.
+    /// Open files are re-parsed from their in-memory content afterwards
+    /// and their diagnostics are refreshed.
+    pub(crate) async fn reindex_workspace(&self) {
+        let Some(root) = self.workspace_root.read().clone() else {
+            return;
+        };
+
+        self.clear_workspace_index();
+
+        let composer_package = composer::read_composer_package(&root);
+        self.index_workspace(&root, self.php_version(), composer_package)
+            .await;
+
+        let open_files: Vec<(String, Arc<String>)> = self
+            .open_files
+            .read()
+            .iter()
+            .map(|(uri, content)| (uri.clone(), Arc::clone(content)))
+            .collect();
+        for (uri, content) in &open_files {
+            self.update_ast(uri, content);
+        }
+        self.schedule_diagnostics_for_open_files("");
+    }
+
+    /// Re-read the root `composer.json` and apply the difference in
+    /// PSR-4 mappings to the live index.
+    ///
+    /// Directories of added mappings are scanned on a blocking thread and
+    /// their classes added to the classmap.  Classmap entries that
+    /// belonged to a removed mapping (class name under the prefix, file
+    /// under the directory) are evicted, together with the classes,
+    /// functions and per-file maps already parsed from that directory,
+    /// so those classes stop resolving the way Composer's autoloader
+    /// would.  Mappings contributed by subprojects or additional
+    /// workspace folders are absolute and left untouched.
+    ///
+    /// Returns the number of mappings added and removed.
+    pub(crate) async fn refresh_psr4_mappings(&self, root: &Path) -> (usize, usize) {
+        let (new_mappings, vendor_dir) = composer::parse_composer_json(root);
+        let is_root_mapping = |m: &composer::Psr4Mapping| Path::new(&m.base_path).is_relative();
+        let same = |a: &composer::Psr4Mapping, b: &composer::Psr4Mapping| {
+            a.prefix == b.prefix && a.base_path == b.base_path
+        };
+
+        let old_mappings: Vec<composer::Psr4Mapping> = self
+            .psr4_mappings
+            .read()
+            .iter()
+            .filter(|m| is_root_mapping(m))
+            .cloned()
+            .collect();
+        let added: Vec<composer::Psr4Mapping> = new_mappings
+            .iter()
+            .filter(|n| !old_mappings.iter().any(|o| same(o, n)))
+            .cloned()
+            .collect();
+        let removed: Vec<composer::Psr4Mapping> = old_mappings
+            .into_iter()
+            .filter(|o| !new_mappings.iter().any(|n| same(o, n)))
+            .collect();
+        if added.is_empty() && removed.is_empty() {
+            return (0, 0);
+        }
+
+        {
+            let mut psr4 = self.psr4_mappings.write();
+            psr4.retain(|m| !removed.iter().any(|r| same(r, m)));
+            psr4.extend(added.iter().cloned());
+            psr4.sort_by_key(|b| std::cmp::Reverse(b.prefix.len()));
+        }
+
+        if !removed.is_empty() {
+            let removed_dirs: Vec<(&str, PathBuf)> = removed
+                .iter()
+                .map(|m| (m.prefix.as_str(), root.join(&m.base_path)))
+                .collect();
+            self.classmap.write().retain(|fqn, path| {
+                !removed_dirs
+                    .iter()
+                    .any(|(prefix, dir)| fqn.starts_with(prefix) && path.starts_with(dir))
+            });
+            for (_, dir) in &removed_dirs {
+                self.evict_parsed_files_under(dir);
+            }
+        }
+
+        if !added.is_empty() {
+            let psr4_dirs: Vec<(String, PathBuf)> = added
+                .iter()
+                .map(|m| (m.prefix.clone(), root.join(&m.base_path)))
+                .collect();
+            let vendor_path = root.join(&vendor_dir);
+            let scanned = tokio::task::spawn_blocking(move || {
+                classmap_scanner::scan_psr4_directories(&psr4_dirs, &[], &[vendor_path])
+            })
+            .await
+            .unwrap_or_default();
+            let mut classmap = self.classmap.write();
+            for (fqn, path) in scanned {
+                classmap.entry(fqn).or_insert(path);
+            }
+        }
+
+        self.class_not_found_cache.write().clear();
+        (added.len(), removed.len())
+    }
+
+    /// Clear every index built from workspace and vendor files: parsed
+    /// classes and symbol maps, the global function and constant maps,
+    /// the classmap and autoload indices, PSR-4 mappings, vendor paths,
+    /// and all resolution caches derived from them.
+    ///
+    /// Open-file contents, client capabilities and configuration are
+    /// kept.
+    pub(crate) fn clear_workspace_index(&self) {
+        self.ast_map.write().clear();
+        self.symbol_maps.write().clear();
+        self.parse_errors.write().clear();
+        self.strict_types_map.write().clear();
+        self.use_map.write().clear();
+        self.resolved_names.write().clear();
+        self.namespace_map.write().clear();
+        self.global_functions.write().clear();
+        self.global_defines.write().clear();
+        self.autoload_function_index.write().clear();
+        self.autoload_constant_index.write().clear();
+        self.autoload_file_paths.write().clear();
+        self.class_index.write().clear();
+        self.fqn_index.write().clear();
+        self.class_not_found_cache.write().clear();
+        self.classmap.write().clear();
+        self.parsed_uris.write().clear();
+        self.index_lru.clear();
+        self.method_store.write().clear();
+        self.gti_index.write().clear();
+        self.psr4_mappings.write().clear();
+        self.vendor_uri_prefixes.lock().clear();
+        self.vendor_dir_paths.lock().clear();
+        self.resolved_class_cache.lock().clear();
+        self.completion_resolve_cache.lock().clear();
+        *self.symfony_services.lock() = None;
+        crate::virtual_members::phpdoc::clear_mixin_cache();
+    }
+
+    /// Drop every index entry that points into `folder`.
+    ///
+    /// Used when a workspace folder is removed, so the folders that
+    /// remain keep their entries instead of being rebuilt.  Files still
+    /// open in the editor keep their per-file maps; they are re-parsed
+    /// from the buffer on the next edit either way.
+    pub(crate) fn evict_workspace_folder(&self, folder: &Path) {
+        let uri_prefixes = dir_uri_prefixes(folder);
+        self.classmap.write().retain(|_, p| !p.starts_with(folder));
+        self.autoload_function_index
+            .write()
+            .retain(|_, p| !p.starts_with(folder));
+        self.autoload_constant_index
+            .write()
+            .retain(|_, p| !p.starts_with(folder));
+        self.autoload_file_paths
+            .write()
+            .retain(|p| !p.starts_with(folder));
+        self.psr4_mappings
+            .write()
+            .retain(|m| !Path::new(&m.base_path).starts_with(folder));
+        self.vendor_dir_paths
+            .lock()
+            .retain(|p| !p.starts_with(folder));
+        self.vendor_uri_prefixes
+            .lock()
+            .retain(|p| !uri_prefixes.iter().any(|u| p.starts_with(u.as_str())));
+
+        self.evict_parsed_files_under(folder);
+    }
+
+    /// Drop the parsed classes, functions, constants and per-file maps
+    /// of every file under `dir`, together with the resolution caches
+    /// built from them.
+    ///
+    /// Path-based indices (classmap, autoload indices) are left to the
+    /// caller.  Files open in the editor are kept.
+    pub(crate) fn evict_parsed_files_under(&self, dir: &Path) {
+        let uri_prefixes = dir_uri_prefixes(dir);
+        let open_files: HashSet<String> = self.open_files.read().keys().cloned().collect();
+        let evicted = |uri: &str| {
+            uri_prefixes.iter().any(|p| uri.starts_with(p.as_str())) && !open_files.contains(uri)
+        };
+
+        let mut fqns: Vec<String> = Vec::new();
+        self.class_index.write().retain(|fqn, uri| {
+            let keep = !evicted(uri);
+            if !keep {
+                fqns.push(fqn.clone());
+            }
+            keep
+        });
+        let uris: Vec<String> = self
+            .ast_map
+            .read()
+            .keys()
+            .chain(self.parsed_uris.read().iter())
+            .filter(|uri| evicted(uri))
+            .cloned()
+            .collect();
+        for uri in &uris {
+            if let Some(classes) = self.ast_map.read().get(uri) {
+                fqns.extend(
+                    classes
+                        .iter()
+                        .filter(|c| !c.name.starts_with("__anonymous@"))
+                        .map(|c| c.fqn().to_string()),
+                );
+            }
+            self.clear_file_maps(uri);
+            self.parse_errors.write().remove(uri);
+            self.parsed_uris.write().remove(uri);
+            self.index_lru.forget(uri);
+        }
+        fqns.sort();
+        fqns.dedup();
+        {
+            let mut fqn_index = self.fqn_index.write();
+            for fqn in &fqns {
+                fqn_index.remove(fqn);
+            }
+        }
+        self.evict_methods_for_fqns(&fqns);
+        self.evict_gti_for_fqns(&fqns);
+
+        self.global_functions
+            .write()
+            .retain(|_, (uri, _)| !evicted(uri));
+        self.global_defines
+            .write()
+            .retain(|_, define| !evicted(&define.file_uri));
+
+        self.class_not_found_cache.write().clear();
+        self.resolved_class_cache.lock().clear();
+        self.completion_resolve_cache.lock().clear();
+        crate::virtual_members::phpdoc::clear_mixin_cache();
+    }
+
+    /// Count the files, classes and functions in the workspace index.
+    ///
+    /// Files are the distinct paths referenced by the classmap and the
+    /// autoload function and constant indices.
+    fn index_counts(&self) -> IndexCounts {
+        let classmap = self.classmap.read();
+        let function_index = self.autoload_function_index.read();
+        let constant_index = self.autoload_constant_index.read();
+
+        let files = classmap
+            .values()
+            .chain(function_index.values())
+            .chain(constant_index.values())
+            .collect::<HashSet<_>>()
+            .len();
+
+        IndexCounts {
+            files,
+            classes: classmap.len(),
+            functions: function_index.len(),
+        }
+    }
+
     /// Initialize a single-project workspace (root `composer.json` exists).
     ///
     /// This is the standard fast path: read PSR-4 mappings, build the
@@ -1454,10 +2383,8 @@ impl Backend {
         composer_json: Option<composer::ComposerPackage>,
         progress_token: Option<&NumberOrString>,
     ) {
-        if let Some(tok) = progress_token {
-            self.progress_report(tok, 10, Some("Reading composer.json".to_string()))
-                .await;
-        }
+        self.report_indexing_progress(progress_token, 10, "Reading composer.json".to_string())
+            .await;
 
         let (mappings, vendor_dir) = match &composer_json {
             Some(pkg) => {
@@ -1478,9 +2405,10 @@ impl Backend {
         // ── Build the classmap ──────────────────────────────────────
         let strategy = self.config().indexing.strategy();
 
-        if let Some(tok) = progress_token {
-            self.progress_report(tok, 20, Some("Building class index".to_string()))
-                .await;
+        self.report_indexing_progress(progress_token, 20, "Building class index".to_string())
+            .await;
+        if self.is_indexing_cancelled() {
+            return;
         }
 
         let (classmap, source_label) = match strategy {
@@ -1595,9 +2523,10 @@ impl Backend {
         }
 
         // ── Autoload files ──────────────────────────────────────────
-        if let Some(tok) = progress_token {
-            self.progress_report(tok, 70, Some("Scanning autoload files".to_string()))
-                .await;
+        self.report_indexing_progress(progress_token, 70, "Scanning autoload files".to_string())
+            .await;
+        if self.is_indexing_cancelled() {
+            return;
         }
 
         self.scan_autoload_files(root, &vendor_dir);
@@ -1660,76 +2589,28 @@ impl Backend {
         for (sub_idx, (sub_root, vendor_dir)) in subprojects.iter().enumerate() {
             // Report per-subproject progress.  Reserve 10..80 for the
             // subproject loop, leaving 80..95 for the loose-file scan.
-            if let Some(tok) = progress_token {
-                let pct = 10 + (sub_idx as u32 * 70) / sub_count.max(1) as u32;
-                let label = sub_root
-                    .strip_prefix(root)
-                    .unwrap_or(sub_root)
-                    .display()
-                    .to_string();
-                self.progress_report(
-                    tok,
-                    pct,
-                    Some(format!(
-                        "Indexing subproject {} / {}: {}",
-                        sub_idx + 1,
-                        sub_count,
-                        label
-                    )),
-                )
-                .await;
+            let pct = 10 + (sub_idx as u32 * 70) / sub_count.max(1) as u32;
+            let label = sub_root
+                .strip_prefix(root)
+                .unwrap_or(sub_root)
+                .display()
+                .to_string();
+            self.report_indexing_progress(
+                progress_token,
+                pct,
+                format!(
+                    "Indexing subproject {} / {}: {}",
+                    sub_idx + 1,
+                    sub_count,
+                    label
+                ),
+            )
+            .await;
+            if self.is_indexing_cancelled() {
+                break;
             }
             skip_dirs.insert(sub_root.clone());
-
-            // ── PSR-4 mappings ──────────────────────────────────────
-            let (mappings, _) = composer::parse_composer_json(sub_root);
-
-            // Resolve base_path values to absolute paths so that
-            // resolve_class_path works regardless of workspace_root.
-            let abs_mappings: Vec<composer::Psr4Mapping> = mappings
-                .into_iter()
-                .map(|m| {
-                    let abs_base = sub_root.join(&m.base_path).to_string_lossy().to_string();
-                    composer::Psr4Mapping {
-                        prefix: m.prefix,
-                        base_path: composer::normalise_path(&abs_base),
-                    }
-                })
-                .collect();
-            {
-                let mut psr4 = self.psr4_mappings.write();
-                psr4.extend(abs_mappings);
-            }
-
-            // ── Vendor dir tracking ─────────────────────────────────
-            let vendor_path = sub_root.join(vendor_dir);
-            self.add_vendor_dir(&vendor_path);
-
-            // ── Autoload files ──────────────────────────────────────
-            self.scan_autoload_files(sub_root, vendor_dir);
-
-            // ── Merged classmap + self-scan ──────────────────────────
-            // Load the subproject's Composer classmap as a skip set,
-            // then self-scan its PSR-4 directories and vendor packages
-            // for anything the classmap missed.
-            let mut sub_cm = composer::parse_autoload_classmap(sub_root, vendor_dir);
-            // Merge PSR-0 classes for this subproject.
-            let psr0_cm = composer::parse_autoload_namespaces(sub_root, vendor_dir);
-            for (fqn, path) in psr0_cm {
-                sub_cm.entry(fqn).or_insert(path);
-            }
-            let sub_skip: HashSet<PathBuf> = sub_cm.values().cloned().collect();
-            let scan = self.build_self_scan_composer(sub_root, vendor_dir, None, &sub_skip);
-            self.populate_autoload_indices(&scan);
-            {
-                let mut classmap = self.classmap.write();
-                for (fqcn, path) in sub_cm {
-                    classmap.entry(fqcn).or_insert(path);
-                }
-                for (fqcn, path) in scan.classmap {
-                    classmap.entry(fqcn).or_insert(path);
-                }
-            }
+            self.index_composer_subproject(sub_root, vendor_dir);
         }
 
         // Re-sort PSR-4 mappings by prefix length descending so
@@ -1742,9 +2623,10 @@ impl Backend {
         // ── Full-scan loose files ───────────────────────────────────
         // Walk the workspace for PHP files outside any subproject
         // directory, using gitignore-aware walking.
-        if let Some(tok) = progress_token {
-            self.progress_report(tok, 80, Some("Scanning loose PHP files".to_string()))
-                .await;
+        self.report_indexing_progress(progress_token, 80, "Scanning loose PHP files".to_string())
+            .await;
+        if self.is_indexing_cancelled() {
+            return;
         }
 
         let scan = classmap_scanner::scan_workspace_fallback_full(root, &skip_dirs);
@@ -1774,6 +2656,84 @@ impl Backend {
         .await;
     }
 
+    /// Index one Composer project inside a larger workspace: its PSR-4
+    /// mappings (made absolute), vendor directory, autoload files and
+    /// merged classmap.  Entries are added to the shared indices without
+    /// replacing what other projects contributed, so callers must re-sort
+    /// the PSR-4 mappings once all projects are indexed.
+    fn index_composer_subproject(&self, sub_root: &std::path::Path, vendor_dir: &str) {
+        // ── PSR-4 mappings ──────────────────────────────────────
+        let (mappings, _) = composer::parse_composer_json(sub_root);
+
+        // Resolve base_path values to absolute paths so that
+        // resolve_class_path works regardless of workspace_root.
+        let abs_mappings: Vec<composer::Psr4Mapping> = mappings
+            .into_iter()
+            .map(|m| {
+                let abs_base = sub_root.join(&m.base_path).to_string_lossy().to_string();
+                composer::Psr4Mapping {
+                    prefix: m.prefix,
+                    base_path: composer::normalise_path(&abs_base),
+                }
+            })
+            .collect();
+        {
+            let mut psr4 = self.psr4_mappings.write();
+            psr4.extend(abs_mappings);
+        }
+
+        // ── Vendor dir tracking ─────────────────────────────────
+        let vendor_path = sub_root.join(vendor_dir);
+        self.add_vendor_dir(&vendor_path);
+
+        // ── Autoload files ──────────────────────────────────────
+        self.scan_autoload_files(sub_root, vendor_dir);
+
+        // ── Merged classmap + self-scan ──────────────────────────
+        // Load the subproject's Composer classmap as a skip set,
+        // then self-scan its PSR-4 directories and vendor packages
+        // for anything the classmap missed.
+        let mut sub_cm = composer::parse_autoload_classmap(sub_root, vendor_dir);
+        // Merge PSR-0 classes for this subproject.
+        let psr0_cm = composer::parse_autoload_namespaces(sub_root, vendor_dir);
+        for (fqn, path) in psr0_cm {
+            sub_cm.entry(fqn).or_insert(path);
+        }
+        let sub_skip: HashSet<PathBuf> = sub_cm.values().cloned().collect();
+        let scan = self.build_self_scan_composer(sub_root, vendor_dir, None, &sub_skip);
+        self.populate_autoload_indices(&scan);
+        {
+            let mut classmap = self.classmap.write();
+            for (fqcn, path) in sub_cm {
+                classmap.entry(fqcn).or_insert(path);
+            }
+            for (fqcn, path) in scan.classmap {
+                classmap.entry(fqcn).or_insert(path);
+            }
+        }
+    }
+
+    /// Index a workspace folder other than the primary root.
+    ///
+    /// A folder with a `composer.json` is indexed like a monorepo
+    /// subproject; anything else gets a full loose-file scan.  Results
+    /// are merged into the shared indices, with classes already known
+    /// from an earlier folder taking precedence.
+    fn index_additional_root(&self, root: &std::path::Path) {
+        if let Some(package) = composer::read_composer_package(root) {
+            let vendor_dir = composer::get_vendor_dir(&package);
+            self.index_composer_subproject(root, &vendor_dir);
+            return;
+        }
+
+        let scan = classmap_scanner::scan_workspace_fallback_full(root, &HashSet::new());
+        self.populate_autoload_indices(&scan);
+        let mut classmap = self.classmap.write();
+        for (fqcn, path) in scan.classmap {
+            classmap.entry(fqcn).or_insert(path);
+        }
+    }
+
     /// Initialize a pure non-Composer workspace (no `composer.json`
     /// anywhere).  Full-scans all PHP files in the workspace.
     async fn init_no_composer(
@@ -1788,13 +2748,14 @@ impl Backend {
         )
         .await;
 
-        if let Some(tok) = progress_token {
-            self.progress_report(
-                tok,
-                20,
-                Some("Scanning workspace for PHP files".to_string()),
-            )
-            .await;
+        self.report_indexing_progress(
+            progress_token,
+            20,
+            "Scanning workspace for PHP files".to_string(),
+        )
+        .await;
+        if self.is_indexing_cancelled() {
+            return;
         }
 
         let skip_dirs = HashSet::new();
@@ -0,0 +1,97 @@
+//! Server status notification (`$/status`).
+//!
+//! A custom notification that tells the editor what the server is doing,
+//! so it can show a status bar indicator without interpreting
+//! `$/progress` titles.  It is sent while the workspace is indexed
+//! (`"indexing"` with a percentage), once indexing finishes (`"ready"`
+//! with index counts and the elapsed time), and when indexing stops
+//! early (`"error"` with a message).
+//!
+//! The server announces support under
+//! `capabilities.experimental.statusNotification` in the `initialize`
+//! response.  Unlike work-done progress, the notification needs no
+//! client opt-in, so it is sent to every client.
+
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::notification::Notification;
+
+use crate::Backend;
+
+/// The `$/status` notification.
+pub(crate) enum StatusNotification {}
+
+impl Notification for StatusNotification {
+    type Params = ServerStatus;
+    const METHOD: &'static str = "$/status";
+}
+
+/// Payload of `$/status`, tagged by its `state` field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub(crate) enum ServerStatus {
+    /// The workspace index is being built.
+    Indexing {
+        /// Completion percentage in the range 0..=100.
+        progress: u32,
+    },
+    /// Indexing finished and the server is fully ready.
+    #[serde(rename_all = "camelCase")]
+    Ready {
+        files_indexed: usize,
+        classes_indexed: usize,
+        functions_indexed: usize,
+        duration_ms: u64,
+    },
+    /// Indexing stopped before completing.
+    Error { message: String },
+}
+
+impl Backend {
+    /// Send a `$/status` notification to the client, if one is attached.
+    pub(crate) async fn send_status(&self, status: ServerStatus) {
+        if let Some(client) = &self.client {
+            client.send_notification::<StatusNotification>(status).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_with_state_tag_and_camel_case_fields() {
+        let ready = serde_json::to_value(ServerStatus::Ready {
+            files_indexed: 3,
+            classes_indexed: 5,
+            functions_indexed: 2,
+            duration_ms: 40,
+        })
+        .unwrap();
+        assert_eq!(
+            ready,
+            serde_json::json!({
+                "state": "ready",
+                "filesIndexed": 3,
+                "classesIndexed": 5,
+                "functionsIndexed": 2,
+                "durationMs": 40,
+            })
+        );
+
+        let indexing = serde_json::to_value(ServerStatus::Indexing { progress: 20 }).unwrap();
+        assert_eq!(
+            indexing,
+            serde_json::json!({ "state": "indexing", "progress": 20 })
+        );
+
+        let error = serde_json::to_value(ServerStatus::Error {
+            message: "cancelled".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            error,
+            serde_json::json!({ "state": "error", "message": "cancelled" })
+        );
+    }
+}
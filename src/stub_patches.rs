@@ -54,6 +54,15 @@
 //!    Same template params + `@extends FilterIterator<TKey, TValue, TIterator>`
 //!    + constructor binding.
 //!
+//! 6. **`Fiber`** -- phpstorm-stubs declare every method as returning
+//!    `mixed`.  PHPStan adds `@template TStart`, `@template TResume`,
+//!    `@template TReturn`, `@template TSuspend` with typed `start()`,
+//!    `resume()`, `getReturn()` and `suspend()`.  We also bind `TStart`
+//!    and `TReturn` from the constructor's callback so that
+//!    `new Fiber(function (int $x): string { ... })` infers both, and
+//!    type `Fiber::getCurrent()` as `?static`.
+//!    PHPStan ref: `stubs/Fiber.stub`
+//!
 //! ## Removing patches
 //!
 //! When phpstorm-stubs gains proper annotations for a patched symbol,
@@ -123,6 +132,7 @@ pub fn apply_class_stub_patches(class: &mut ClassInfo) {
         "LimitIterator" => patch_limit_iterator(class),
         "CallbackFilterIterator" => patch_callback_filter_iterator(class),
         "ArrayIterator" => patch_array_iterator(class),
+        "Fiber" => patch_fiber(class),
         _ => {}
     }
 }
@@ -315,6 +325,60 @@ fn patch_array_iterator(class: &mut ClassInfo) {
     }
 }
 
+/// Add `@template TStart, TResume, TReturn, TSuspend` to `Fiber` and
+/// type its methods against them.
+///
+/// `start()`, `resume()` and `throw()` return the value passed to the
+/// next `Fiber::suspend()` (or `null` once the fiber terminates), and
+/// `suspend()` returns the value later passed to `resume()`.  The
+/// constructor binds `TStart` and `TReturn` from the callback's first
+/// parameter type and return type.
+/// PHPStan ref: `stubs/Fiber.stub`
+fn patch_fiber(class: &mut ClassInfo) {
+    add_templates(
+        class,
+        &[
+            ("TStart", None),
+            ("TResume", None),
+            ("TReturn", None),
+            ("TSuspend", None),
+        ],
+    );
+
+    let suspend_or_null = PhpType::Nullable(Box::new(PhpType::Named("TSuspend".to_string())));
+    for method in ["start", "resume", "throw"] {
+        patch_method_return_type(class, method, suspend_or_null.clone());
+    }
+    patch_method_return_type(class, "getReturn", PhpType::Named("TReturn".to_string()));
+    patch_method_return_type(class, "suspend", PhpType::Named("TResume".to_string()));
+    patch_method_return_type(
+        class,
+        "getCurrent",
+        PhpType::Nullable(Box::new(PhpType::Named("static".to_string()))),
+    );
+
+    if let Some(ctor_idx) = class
+        .methods
+        .iter()
+        .position(|m| m.name.as_str() == "__construct")
+    {
+        let mut ctor = (*class.methods[ctor_idx]).clone();
+        for tpl_name in ["TStart", "TReturn"] {
+            let binding = (atom(tpl_name), atom("$callback"));
+            if !ctor.template_bindings.iter().any(|(t, _)| t == &binding.0) {
+                ctor.template_bindings.push(binding);
+            }
+        }
+        // `callable(TStart): TReturn` lets classify_template_binding
+        // pick CallableParamType for TStart and CallableReturnType for
+        // TReturn.
+        if let Some(param) = ctor.parameters.iter_mut().find(|p| p.name == "$callback") {
+            param.type_hint = Some(PhpType::parse("callable(TStart): TReturn"));
+        }
+        class.methods.make_mut()[ctor_idx] = std::sync::Arc::new(ctor);
+    }
+}
+
 /// Shared helper: add `@template TKey, TValue, TIterator` and
 /// `@extends <parent><TKey, TValue, TIterator>` to an `IteratorIterator`
 /// subclass (or sub-subclass like `CallbackFilterIterator`).
@@ -443,6 +507,7 @@ mod tests {
     use super::*;
     use crate::atom::atom;
     use crate::php_type::PhpType;
+    use crate::types::MethodInfo;
 
     fn empty_class(name: &str) -> ClassInfo {
         ClassInfo {
@@ -502,6 +567,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fiber_gets_templates_and_typed_methods() {
+        let mut class = empty_class("Fiber");
+        class.methods = vec![
+            std::sync::Arc::new(MethodInfo::virtual_method("getReturn", Some("mixed"))),
+            std::sync::Arc::new(MethodInfo::virtual_method("start", Some("mixed"))),
+            std::sync::Arc::new(MethodInfo::virtual_method("getCurrent", Some("?Fiber"))),
+        ]
+        .into();
+        apply_class_stub_patches(&mut class);
+
+        assert_eq!(
+            class.template_params,
+            vec![
+                atom("TStart"),
+                atom("TResume"),
+                atom("TReturn"),
+                atom("TSuspend")
+            ]
+        );
+        let return_of = |name: &str| {
+            class
+                .methods
+                .iter()
+                .find(|m| m.name == name)
+                .and_then(|m| m.return_type.as_ref())
+                .map(|t| t.to_string())
+        };
+        assert_eq!(return_of("getReturn").as_deref(), Some("TReturn"));
+        assert_eq!(return_of("start").as_deref(), Some("?TSuspend"));
+        assert_eq!(return_of("getCurrent").as_deref(), Some("?static"));
+    }
+
     #[test]
     fn range_gets_conditional_return() {
         let mut func = FunctionInfo {
@@ -43,16 +43,28 @@ include!(concat!(env!("OUT_DIR"), "/stub_map_generated.rs"));
 /// version file was missing, or `"none"` when stubs could not be fetched.
 pub const STUBS_VERSION: &str = env!("PHPANTOM_STUBS_VERSION");
 
+/// Framework classes whose stubs ship with PHPantom itself rather than
+/// with phpstorm-stubs, keyed by fully-qualified name.
+///
+/// These only take effect when the project does not provide the class,
+/// because the stub index is the last phase of class resolution.
+const BUNDLED_CLASS_STUBS: &[(&str, &str)] = &[(
+    "Doctrine\\ORM\\Mapping\\Column",
+    include_str!("stubs/doctrine/Column.php"),
+)];
+
 /// Build a lookup table mapping class/interface/trait short names to their
 /// embedded PHP source code.
 ///
 /// Called once during `Backend` construction.  The returned map is stored
 /// on the backend and consulted by `find_or_load_class` as a final
-/// fallback after the `ast_map` and PSR-4 resolution.
+/// fallback after the `ast_map` and PSR-4 resolution.  Includes the
+/// [`BUNDLED_CLASS_STUBS`] alongside the phpstorm-stubs classes.
 pub fn build_stub_class_index() -> HashMap<&'static str, &'static str> {
     STUB_CLASS_MAP
         .iter()
         .map(|&(name, idx)| (name, STUB_FILES[idx]))
+        .chain(BUNDLED_CLASS_STUBS.iter().copied())
         .collect()
 }
 
@@ -72,6 +84,26 @@ pub fn build_stub_function_index() -> HashMap<&'static str, &'static str> {
         .collect()
 }
 
+/// Move the entries of `index` that `is_removed` rejects into `removed`.
+///
+/// Entries already in `removed` are returned to `index` first, so the
+/// filter always runs over the full set of stubs.
+pub(crate) fn filter_removed_stubs(
+    index: &mut HashMap<&'static str, &'static str>,
+    removed: &mut HashMap<&'static str, &'static str>,
+    is_removed: impl Fn(&str, &str) -> bool,
+) {
+    index.extend(removed.drain());
+    index.retain(|name, source| {
+        if is_removed(name, source) {
+            removed.insert(name, source);
+            false
+        } else {
+            true
+        }
+    });
+}
+
 /// Quick byte-level check whether a stub function has been `@removed`
 /// at or before the given PHP version.
 ///
@@ -0,0 +1,46 @@
+<?php
+
+namespace Doctrine\ORM\Mapping;
+
+use Attribute;
+
+/**
+ * Maps a property to a database column.
+ *
+ * Bundled with PHPantom so that `#[Column(...)]` arguments can be
+ * completed in projects that have not installed doctrine/orm yet.
+ */
+#[Attribute(Attribute::TARGET_PROPERTY)]
+final class Column
+{
+    /**
+     * @param string|null $name The column name. Defaults to the property name.
+     * @param string|null $type The Doctrine DBAL type name (e.g. "string", "integer").
+     * @param int|null $length The column length. Applies only to string columns.
+     * @param int|null $precision The precision for a decimal column.
+     * @param int|null $scale The scale for a decimal column.
+     * @param bool $unique Whether a unique constraint is added to the column.
+     * @param bool $nullable Whether the column may contain NULL.
+     * @param bool $insertable Whether the column is included in INSERT statements.
+     * @param bool $updatable Whether the column is included in UPDATE statements.
+     * @param class-string<\BackedEnum>|null $enumType The backed enum the column value maps to.
+     * @param array<string, mixed> $options Platform specific column options.
+     * @param string|null $columnDefinition The raw SQL column definition.
+     * @param 'NEVER'|'INSERT'|'ALWAYS'|null $generated When the database generates the value.
+     */
+    public function __construct(
+        public readonly string|null $name = null,
+        public readonly string|null $type = null,
+        public readonly int|null $length = null,
+        public readonly int|null $precision = null,
+        public readonly int|null $scale = null,
+        public readonly bool $unique = false,
+        public readonly bool $nullable = false,
+        public readonly bool $insertable = true,
+        public readonly bool $updatable = true,
+        public readonly string|null $enumType = null,
+        public readonly array $options = [],
+        public readonly string|null $columnDefinition = null,
+        public readonly string|null $generated = null,
+    ) {}
+}
@@ -14,8 +14,8 @@ use super::docblock::{
     get_docblock_text_with_offset, is_navigable_type,
 };
 use super::{
-    CallSite, ClassRefContext, SelfStaticParentKind, SymbolKind, SymbolMap, SymbolSpan,
-    TemplateParamDef, UntypedClosureSite, VarDefKind, VarDefSite,
+    CallSite, ClassRefContext, HeredocLabels, SelfStaticParentKind, SymbolKind, SymbolMap,
+    SymbolSpan, TemplateParamDef, UntypedClosureSite, VarDefKind, VarDefSite,
 };
 use crate::util::strip_fqn_prefix;
 
@@ -66,6 +66,10 @@ struct ExtractionCtx<'a> {
     /// Closures and arrow functions passed as arguments to callable-typed
     /// parameters, used by inlay hints.
     untyped_closure_sites: Vec<UntypedClosureSite>,
+    /// Heredoc and nowdoc delimiter labels, used by linked editing.
+    heredoc_labels: Vec<HeredocLabels>,
+    /// Spans of plain string literals, used by the color provider.
+    string_literals: Vec<(u32, u32)>,
     /// Current conditional nesting depth (if/else, switch, while, for, etc.).
     /// Incremented when entering a conditional block, decremented when leaving.
     cond_nesting_depth: u16,
@@ -138,6 +142,8 @@ pub(crate) fn extract_symbol_map(program: &Program<'_>, content: &str) -> Symbol
         trivias: program.trivia.as_slice(),
         content,
         untyped_closure_sites: Vec::new(),
+        heredoc_labels: Vec::new(),
+        string_literals: Vec::new(),
         cond_nesting_depth: 0,
         cond_block_end_stack: Vec::new(),
     };
@@ -223,6 +229,8 @@ pub(crate) fn extract_symbol_map(program: &Program<'_>, content: &str) -> Symbol
     ctx.breakable_scopes.sort_by_key(|s| s.0);
     ctx.loop_scopes.sort_by_key(|s| s.0);
     ctx.switch_scopes.sort_by_key(|s| s.0);
+    ctx.heredoc_labels.sort_by_key(|h| h.open.0);
+    ctx.string_literals.sort_by_key(|s| s.0);
 
     SymbolMap {
         spans: ctx.spans,
@@ -237,6 +245,8 @@ pub(crate) fn extract_symbol_map(program: &Program<'_>, content: &str) -> Symbol
         loop_scopes: ctx.loop_scopes,
         switch_scopes: ctx.switch_scopes,
         untyped_closure_sites: ctx.untyped_closure_sites,
+        heredoc_labels: ctx.heredoc_labels,
+        string_literals: ctx.string_literals,
     }
 }
 
@@ -2487,6 +2497,9 @@ fn extract_from_expression<'a>(
         // ── Composite strings (interpolation) ──
         // `"Hello {$obj->method()}"`, heredocs, shell-exec backticks.
         Expression::CompositeString(composite) => {
+            if let CompositeString::Document(document) = composite {
+                record_heredoc_labels(document, ctx);
+            }
             for part in composite.parts().iter() {
                 match part {
                     StringPart::Expression(expr) => {
@@ -2586,7 +2599,15 @@ fn extract_from_expression<'a>(
             }
         },
 
-        // Non-navigable expressions (literals, etc.) are intentionally ignored.
+        // ── String literals ──
+        // Not navigable, but recorded for the color provider.
+        Expression::Literal(Literal::String(lit)) => {
+            let span = lit.span();
+            ctx.string_literals
+                .push((span.start.offset, span.end.offset));
+        }
+
+        // Other non-navigable expressions (numbers, etc.) are intentionally ignored.
         _ => {}
     }
 }
@@ -2726,6 +2747,35 @@ fn emit_class_expr_span<'a>(
     }
 }
 
+// ─── Heredoc labels ─────────────────────────────────────────────────────────
+
+/// Record the opening and closing label offsets of a heredoc or nowdoc.
+///
+/// The opening span covers `<<<` plus the (possibly quoted) label, and
+/// the closing span covers any indentation before the label, so the
+/// bare label is located inside each span by text search.
+fn record_heredoc_labels(document: &DocumentString<'_>, ctx: &mut ExtractionCtx<'_>) {
+    let label = document.label.trim_matches(|c| c == '\'' || c == '"');
+    if label.is_empty() {
+        return;
+    }
+    let content = ctx.content;
+    let locate = |span: mago_span::Span, from_end: bool| -> Option<(u32, u32)> {
+        let text = content.get(span.start.offset as usize..span.end.offset as usize)?;
+        let idx = if from_end {
+            text.rfind(label)?
+        } else {
+            text.find(label)?
+        };
+        let start = span.start.offset + idx as u32;
+        Some((start, start + label.len() as u32))
+    };
+    if let (Some(open), Some(close)) = (locate(document.open, false), locate(document.close, true))
+    {
+        ctx.heredoc_labels.push(HeredocLabels { open, close });
+    }
+}
+
 // ─── Call site emission ─────────────────────────────────────────────────────
 
 /// Build and push a [`CallSite`] from an argument list and its call expression string.
@@ -260,6 +260,20 @@ pub(crate) struct TemplateParamDef {
 
 // ─── Call site structures ───────────────────────────────────────────────────
 
+/// The opening and closing delimiter labels of a heredoc or nowdoc.
+///
+/// Stored in `SymbolMap::heredoc_labels`, sorted by `open`.  Both
+/// ranges are `(start, end)` byte offsets of the bare label, excluding
+/// the `<<<` marker and any quotes.  Used by linked editing to rename
+/// both delimiters together.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HeredocLabels {
+    /// The label after `<<<` on the opening line.
+    pub open: (u32, u32),
+    /// The label on the closing line.
+    pub close: (u32, u32),
+}
+
 /// A call expression site discovered during the AST walk.
 ///
 /// Stored in `SymbolMap::call_sites`, sorted by `args_start`.
@@ -479,6 +493,12 @@ pub(crate) struct SymbolMap {
     /// parameters.  Used by inlay hints to show inferred parameter types
     /// and return types from the enclosing callable signature.
     pub untyped_closure_sites: Vec<UntypedClosureSite>,
+    /// Heredoc and nowdoc delimiter labels, sorted by opening offset.
+    pub heredoc_labels: Vec<HeredocLabels>,
+    /// Plain (non-interpolated) string literal spans `(start, end)`,
+    /// including the quotes, sorted by start offset.  Used by the color
+    /// provider to find CSS color values.
+    pub string_literals: Vec<(u32, u32)>,
 }
 
 impl SymbolMap {
@@ -699,6 +719,15 @@ impl SymbolMap {
         })
     }
 
+    /// Find the heredoc or nowdoc whose opening or closing label
+    /// contains `offset` (the end of a label counts as inside it).
+    pub fn find_heredoc_label(&self, offset: u32) -> Option<&HeredocLabels> {
+        self.heredoc_labels.iter().find(|h| {
+            (offset >= h.open.0 && offset <= h.open.1)
+                || (offset >= h.close.0 && offset <= h.close.1)
+        })
+    }
+
     /// Find the innermost call site whose argument list contains `offset`.
     ///
     /// `call_sites` is sorted by `args_start`.  We want the innermost
@@ -2795,10 +2795,35 @@ fn first_class_callable_instance_method_produces_member_access() {
 
 // ── Echo tag (short open tag) ───────────────────────────────────────────────
 
-// Note: `<?= $expr ?>` parsing depends on the parser treating it as an
-// EchoTag statement. The extraction code handles it; a full integration
-// test would require a PHP file with short echo tags that the parser
-// recognises.
+#[test]
+fn echo_tag_extracts_member_access() {
+    let php = "<p><?= $user->name ?></p>\n";
+    let map = parse_and_extract(php);
+    let name_offset = php.find("name").unwrap() as u32;
+    let hit = map.lookup(name_offset);
+    assert!(hit.is_some(), "Should find member access inside <?= ?>");
+    if let SymbolKind::MemberAccess {
+        ref subject_text,
+        ref member_name,
+        ..
+    } = hit.unwrap().kind
+    {
+        assert_eq!(member_name, "name");
+        assert_eq!(subject_text, "$user");
+    } else {
+        panic!("Expected MemberAccess");
+    }
+}
+
+#[test]
+fn content_without_open_tag_is_inline_html() {
+    // Without `<?php` or `<?=` PHP emits the text verbatim, so nothing
+    // in it is code.
+    let php = "$user->name();\n";
+    let map = parse_and_extract(php);
+    let name_offset = php.find("name").unwrap() as u32;
+    assert!(map.lookup(name_offset).is_none());
+}
 
 // ── Declare statement ───────────────────────────────────────────────────────
 
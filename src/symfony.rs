@@ -0,0 +1,476 @@
+//! Symfony dependency-injection container support.
+//!
+//! Symfony applications register their services in
+//! `config/services.yaml`.  The container interfaces type `get()` as
+//! returning `?object`, so `$container->get('app.mailer')->` would
+//! otherwise offer no completions.  This module reads the service
+//! definitions into a map from service ID to class name and resolves
+//! container `get()` calls against it.
+//!
+//! # Supported definitions
+//!
+//! Only the subset of YAML that service files use in practice is
+//! understood.  Inside the top-level `services:` mapping:
+//!
+//! - `App\Mailer: ~` — a class-named service (the ID is the class).
+//! - `app.mailer: { class: App\Mailer }` or a block with a `class:` key.
+//! - `app.alias: '@app.mailer'` or a block with an `alias:` key.
+//!
+//! `_defaults`, `_instanceof`, and resource-based namespace entries
+//! (`App\: { resource: '../src/' }`) are skipped.  A service ID that is
+//! not in the map but names a class (as auto-registered services do) is
+//! resolved to that class directly.
+//!
+//! # Request flow
+//!
+//! [`Backend::activate_symfony_service_map`] loads the map (re-reading
+//! the file only when its modification time changes) and installs it in
+//! a thread-local for the duration of a completion, hover, or diagnostic
+//! request.  [`resolve_container_get`] is called from method return type
+//! resolution, which has no access to `Backend`.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::Backend;
+use crate::types::ClassInfo;
+
+/// Service definition files, relative to the workspace root, in the
+/// order they are tried.
+const SERVICES_FILES: &[&str] = &["config/services.yaml", "config/services.yml"];
+
+/// Container types whose `get()` method fetches a service by ID.
+const CONTAINER_FQNS: &[&str] = &[
+    "Psr\\Container\\ContainerInterface",
+    "Symfony\\Component\\DependencyInjection\\ContainerInterface",
+];
+
+/// Maximum number of alias hops followed when resolving a service ID.
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Maps a service ID to the class that implements it.
+pub(crate) type ServiceMap = HashMap<String, String>;
+
+/// A parsed service file, kept until the file changes on disk.
+pub(crate) struct ServiceMapCache {
+    path: PathBuf,
+    modified: SystemTime,
+    map: Arc<ServiceMap>,
+}
+
+thread_local! {
+    /// The service map for the current request.  Installed by
+    /// [`Backend::activate_symfony_service_map`], cleared on guard drop.
+    static SERVICE_MAP: RefCell<Option<Arc<ServiceMap>>> = const { RefCell::new(None) };
+}
+
+/// RAII guard that clears [`SERVICE_MAP`] on drop.
+pub(crate) struct ServiceMapGuard {
+    owns: bool,
+}
+
+impl Drop for ServiceMapGuard {
+    fn drop(&mut self) {
+        if self.owns {
+            SERVICE_MAP.with(|cell| {
+                *cell.borrow_mut() = None;
+            });
+        }
+    }
+}
+
+impl Backend {
+    /// Load the workspace's Symfony service map, if it has one.
+    ///
+    /// The parsed map is cached and only rebuilt when the service file's
+    /// modification time changes.
+    pub(crate) fn symfony_service_map(&self) -> Option<Arc<ServiceMap>> {
+        let root = self.workspace_root.read().clone()?;
+        let (path, modified) = SERVICES_FILES.iter().find_map(|rel| {
+            let path = root.join(rel);
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })?;
+
+        let mut cache = self.symfony_services.lock();
+        if let Some(cached) = cache.as_ref()
+            && cached.path == path
+            && cached.modified == modified
+        {
+            return Some(Arc::clone(&cached.map));
+        }
+
+        let content = std::fs::read_to_string(&path).ok()?;
+        let map = Arc::new(parse_services_yaml(&content));
+        *cache = Some(ServiceMapCache {
+            path,
+            modified,
+            map: Arc::clone(&map),
+        });
+        Some(map)
+    }
+
+    /// Install the workspace's service map for the current thread.
+    ///
+    /// Returns an RAII guard that removes it on drop.  Call this next to
+    /// [`Backend::activate_body_return_inferrer`] at request entry points.
+    pub(crate) fn activate_symfony_service_map(&self) -> ServiceMapGuard {
+        let already_active = SERVICE_MAP.with(|cell| cell.borrow().is_some());
+        if already_active {
+            return ServiceMapGuard { owns: false };
+        }
+        let Some(map) = self.symfony_service_map() else {
+            return ServiceMapGuard { owns: false };
+        };
+        SERVICE_MAP.with(|cell| {
+            *cell.borrow_mut() = Some(map);
+        });
+        ServiceMapGuard { owns: true }
+    }
+}
+
+/// Resolve `$container->get(<id>)` to the class of the fetched service.
+///
+/// Returns `None` unless `class_info` is (or extends) a container
+/// interface, `method_name` is `get`, and the first argument is a string
+/// literal or `Foo::class` naming a loadable class, either directly or
+/// through the service map.
+pub(crate) fn resolve_container_get(
+    class_info: &ClassInfo,
+    method_name: &str,
+    text_args: &str,
+    class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+) -> Option<Arc<ClassInfo>> {
+    if !method_name.eq_ignore_ascii_case("get") {
+        return None;
+    }
+    let first_arg = crate::completion::types::conditional::split_text_args(text_args)
+        .into_iter()
+        .next()?
+        .trim();
+
+    let class_name = if let Some(class) = first_arg.strip_suffix("::class") {
+        class.to_string()
+    } else {
+        let id = php_string_literal_value(first_arg)?;
+        SERVICE_MAP
+            .with(|cell| cell.borrow().as_ref().and_then(|map| map.get(&id).cloned()))
+            .unwrap_or(id)
+    };
+    let class_name = class_name.trim_start_matches('\\');
+    if class_name.is_empty() || !is_container(class_info, class_loader) {
+        return None;
+    }
+    class_loader(class_name)
+}
+
+/// Whether `class_info` is one of [`CONTAINER_FQNS`] or inherits from one.
+fn is_container(
+    class_info: &ClassInfo,
+    class_loader: &dyn Fn(&str) -> Option<Arc<ClassInfo>>,
+) -> bool {
+    let is_container_name = |name: &str| {
+        let name = name.trim_start_matches('\\');
+        CONTAINER_FQNS
+            .iter()
+            .any(|fqn| fqn.eq_ignore_ascii_case(name))
+    };
+    if is_container_name(class_info.fqn().as_str()) {
+        return true;
+    }
+
+    let mut pending: Vec<String> = class_info
+        .parent_class
+        .iter()
+        .chain(class_info.interfaces.iter())
+        .map(|name| name.to_string())
+        .collect();
+    let mut seen: HashSet<String> = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if is_container_name(&name) {
+            return true;
+        }
+        if seen.len() >= crate::types::MAX_INHERITANCE_DEPTH as usize || !seen.insert(name.clone())
+        {
+            continue;
+        }
+        if let Some(ancestor) = class_loader(&name) {
+            pending.extend(
+                ancestor
+                    .parent_class
+                    .iter()
+                    .chain(ancestor.interfaces.iter())
+                    .map(|name| name.to_string()),
+            );
+        }
+    }
+    false
+}
+
+/// The value of a quoted PHP string literal, with `\\` unescaped.
+fn php_string_literal_value(text: &str) -> Option<String> {
+    let inner = text
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| text.strip_prefix('"').and_then(|s| s.strip_suffix('"')))?;
+    Some(inner.replace("\\\\", "\\"))
+}
+
+// ─── services.yaml parsing ──────────────────────────────────────────────────
+
+/// Parse the `services:` section of a Symfony service file.
+///
+/// Returns a map from service ID to class name.  Aliases are followed,
+/// so `app.alias: '@app.mailer'` maps to `app.mailer`'s class (or to
+/// `app.mailer` itself when that is a class-named service).
+pub(crate) fn parse_services_yaml(content: &str) -> ServiceMap {
+    let mut classes: ServiceMap = HashMap::new();
+    let mut aliases: HashMap<String, String> = HashMap::new();
+
+    let mut in_services = false;
+    let mut entry_indent: Option<usize> = None;
+    let mut child_indent: Option<usize> = None;
+    let mut current: Option<String> = None;
+
+    for raw in content.lines() {
+        let line = strip_yaml_comment(raw);
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        if indent == 0 {
+            in_services = trimmed == "services:";
+            entry_indent = None;
+            child_indent = None;
+            current = None;
+            continue;
+        }
+        if !in_services || trimmed.starts_with('-') {
+            continue;
+        }
+        let Some((key, value)) = split_yaml_key_value(trimmed) else {
+            continue;
+        };
+        let key = unquote_yaml(key);
+
+        let entry_level = *entry_indent.get_or_insert(indent);
+        if indent <= entry_level {
+            entry_indent = Some(indent);
+            child_indent = None;
+            current = None;
+            if key.starts_with('_') || key.ends_with('\\') {
+                continue;
+            }
+            if let Some(target) = yaml_alias_target(value) {
+                aliases.insert(key, target);
+            } else if let Some(body) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+                for pair in body.split(',') {
+                    if let Some((k, v)) = split_yaml_key_value(pair.trim()) {
+                        record_service_key(&key, &unquote_yaml(k), v, &mut classes, &mut aliases);
+                    }
+                }
+            } else if value.is_empty() {
+                current = Some(key);
+            }
+            continue;
+        }
+
+        let Some(id) = &current else {
+            continue;
+        };
+        let child_level = *child_indent.get_or_insert(indent);
+        if indent == child_level {
+            record_service_key(id, &key, value, &mut classes, &mut aliases);
+        }
+    }
+
+    let mut services = classes.clone();
+    for (id, target) in &aliases {
+        let mut target = target.clone();
+        for _ in 0..MAX_ALIAS_DEPTH {
+            match aliases.get(&target) {
+                Some(next) if !classes.contains_key(&target) => target = next.clone(),
+                _ => break,
+            }
+        }
+        let class = classes.get(&target).cloned().unwrap_or(target);
+        services.insert(id.clone(), class);
+    }
+    services
+}
+
+/// Record a `class:` or `alias:` key found under service `id`.
+fn record_service_key(
+    id: &str,
+    key: &str,
+    value: &str,
+    classes: &mut ServiceMap,
+    aliases: &mut HashMap<String, String>,
+) {
+    match key {
+        "class" => {
+            let class = unquote_yaml(value);
+            if !class.is_empty() {
+                classes.insert(id.to_string(), class.trim_start_matches('\\').to_string());
+            }
+        }
+        "alias" => {
+            let target = unquote_yaml(value);
+            if !target.is_empty() {
+                aliases.insert(id.to_string(), target);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The service ID referenced by an `'@service.id'` value.
+fn yaml_alias_target(value: &str) -> Option<String> {
+    let value = unquote_yaml(value);
+    let target = value.strip_prefix('@')?;
+    let target = target.strip_prefix('?').unwrap_or(target);
+    (!target.is_empty() && !target.starts_with('@')).then(|| target.to_string())
+}
+
+/// Split `key: value` at the first colon outside quotes that is
+/// followed by whitespace or the end of the line.
+fn split_yaml_key_value(text: &str) -> Option<(&str, &str)> {
+    let mut quote: Option<char> = None;
+    let bytes = text.as_bytes();
+    for (i, ch) in text.char_indices() {
+        match (quote, ch) {
+            (None, '\'' | '"') => quote = Some(ch),
+            (Some(q), c) if c == q => quote = None,
+            (None, ':') if bytes.get(i + 1).is_none_or(|b| b.is_ascii_whitespace()) => {
+                return Some((text[..i].trim(), text[i + 1..].trim()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Remove a trailing `# comment` that is not inside quotes.
+fn strip_yaml_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut prev_is_space = true;
+    for (i, ch) in line.char_indices() {
+        match (quote, ch) {
+            (None, '\'' | '"') => quote = Some(ch),
+            (Some(q), c) if c == q => quote = None,
+            (None, '#') if prev_is_space => return &line[..i],
+            _ => {}
+        }
+        prev_is_space = ch.is_whitespace();
+    }
+    line
+}
+
+/// Strip YAML quotes from a scalar.  Double-quoted scalars have `\\`
+/// unescaped; single-quoted scalars have `''` unescaped.
+fn unquote_yaml(value: &str) -> String {
+    let value = value.trim();
+    if let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        inner.replace("\\\\", "\\")
+    } else if let Some(inner) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        inner.replace("''", "'")
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_class_key_and_class_named_services() {
+        let map = parse_services_yaml(concat!(
+            "parameters:\n",
+            "    app.locale: en\n",
+            "\n",
+            "services:\n",
+            "    _defaults:\n",
+            "        autowire: true\n",
+            "        autoconfigure: true\n",
+            "\n",
+            "    App\\:\n",
+            "        resource: '../src/'\n",
+            "\n",
+            "    app.mailer:\n",
+            "        class: App\\Mail\\Mailer # the SMTP mailer\n",
+            "        arguments:\n",
+            "            - '@logger'\n",
+            "\n",
+            "    app.cache: { class: 'App\\Cache\\Store', public: true }\n",
+            "    App\\Service\\Reporter: ~\n",
+        ));
+        assert_eq!(
+            map.get("app.mailer").map(String::as_str),
+            Some("App\\Mail\\Mailer")
+        );
+        assert_eq!(
+            map.get("app.cache").map(String::as_str),
+            Some("App\\Cache\\Store")
+        );
+        assert!(!map.contains_key("_defaults"));
+        assert!(!map.contains_key("App\\"));
+        assert!(!map.contains_key("app.locale"));
+    }
+
+    #[test]
+    fn follows_aliases() {
+        let map = parse_services_yaml(concat!(
+            "services:\n",
+            "    app.mailer:\n",
+            "        class: App\\Mail\\Mailer\n",
+            "    mailer: '@app.mailer'\n",
+            "    App\\Contract\\MailerInterface: '@mailer'\n",
+            "    app.reporter:\n",
+            "        alias: App\\Service\\Reporter\n",
+        ));
+        assert_eq!(
+            map.get("mailer").map(String::as_str),
+            Some("App\\Mail\\Mailer")
+        );
+        assert_eq!(
+            map.get("App\\Contract\\MailerInterface")
+                .map(String::as_str),
+            Some("App\\Mail\\Mailer")
+        );
+        assert_eq!(
+            map.get("app.reporter").map(String::as_str),
+            Some("App\\Service\\Reporter")
+        );
+    }
+
+    #[test]
+    fn ignores_other_top_level_sections() {
+        let map = parse_services_yaml(concat!(
+            "imports:\n",
+            "    - { resource: 'packages/' }\n",
+            "when@test:\n",
+            "    services:\n",
+            "        app.fake: { class: App\\Fake }\n",
+        ));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn php_string_literal_value_unescapes_backslashes() {
+        assert_eq!(
+            php_string_literal_value("'App\\\\Service\\\\Mailer'").as_deref(),
+            Some("App\\Service\\Mailer")
+        );
+        assert_eq!(
+            php_string_literal_value("\"app.mailer\"").as_deref(),
+            Some("app.mailer")
+        );
+        assert_eq!(php_string_literal_value("$id"), None);
+    }
+}
@@ -49,6 +49,7 @@ pub fn make_class(name: &str) -> ClassInfo {
         is_abstract: false,
         deprecation_message: None,
         deprecated_replacement: None,
+        is_internal: false,
         links: Vec::new(),
         see_refs: Vec::new(),
         template_params: Vec::new(),
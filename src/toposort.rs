@@ -235,6 +235,7 @@ mod tests {
             is_abstract: false,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             links: Vec::new(),
             see_refs: Vec::new(),
             template_params: Vec::new(),
@@ -534,6 +534,11 @@ pub struct MethodInfo {
     /// `%class%` that are expanded at call sites to offer a "replace
     /// deprecated call" code action.  `None` when no replacement is specified.
     pub deprecated_replacement: Option<String>,
+    /// Whether the method is marked `@internal` in its docblock.
+    ///
+    /// Internal methods are private to their package, so completion hides
+    /// them in files outside the declaring class's namespace.
+    pub is_internal: bool,
     /// Template parameter names declared via `@template` tags in the
     /// method-level docblock.
     ///
@@ -574,6 +579,11 @@ pub struct MethodInfo {
     /// implicitly abstract.  Used by the "Implement missing methods"
     /// code action to detect which inherited methods still need stubs.
     pub is_abstract: bool,
+    /// Whether this method is declared `final`.
+    ///
+    /// Subclasses cannot override a final method.  Used by the
+    /// final-override diagnostic.
+    pub is_final: bool,
     /// Whether this method is a virtual (synthesized) member.
     ///
     /// Virtual methods come from `@method` docblock tags, `@mixin` classes,
@@ -628,11 +638,13 @@ impl MethodInfo {
             && self.see_refs == other.see_refs
             && self.deprecation_message == other.deprecation_message
             && self.deprecated_replacement == other.deprecated_replacement
+            && self.is_internal == other.is_internal
             && self.template_params == other.template_params
             && self.template_param_bounds == other.template_param_bounds
             && self.template_bindings == other.template_bindings
             && self.has_scope_attribute == other.has_scope_attribute
             && self.is_abstract == other.is_abstract
+            && self.is_final == other.is_final
             && self.is_virtual == other.is_virtual
             && self.throws == other.throws
             && self.parameters.len() == other.parameters.len()
@@ -682,11 +694,13 @@ impl MethodInfo {
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: Vec::new(),
             template_param_bounds: AtomMap::default(),
             template_bindings: Vec::new(),
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: true,
             type_assertions: Vec::new(),
             throws: Vec::new(),
@@ -713,11 +727,13 @@ impl MethodInfo {
             conditional_return: None,
             deprecation_message: None,
             deprecated_replacement: None,
+            is_internal: false,
             template_params: Vec::new(),
             template_param_bounds: AtomMap::default(),
             template_bindings: Vec::new(),
             has_scope_attribute: false,
             is_abstract: false,
+            is_final: false,
             is_virtual: true,
             type_assertions: Vec::new(),
             throws: Vec::new(),
@@ -1505,6 +1521,11 @@ pub struct ClassInfo {
     ///
     /// `None` when no replacement is specified.
     pub deprecated_replacement: Option<String>,
+    /// Whether the class-like is marked `@internal` in its docblock.
+    ///
+    /// Internal classes are private to their package, so completion and
+    /// workspace symbol search hide them outside the class's namespace.
+    pub is_internal: bool,
     /// URLs from `@link` and `@see` tags in the class-level docblock.
     ///
     /// For `@link https://php.net/...` and `@see https://example.com/`,
@@ -1671,6 +1692,19 @@ impl ClassInfo {
         !self.method_index.is_empty() && self.methods.len() as u32 == self.indexed_method_count
     }
 
+    /// Whether an `insteadof` adaptation on this class excludes
+    /// `trait_name`'s version of `method_name`.
+    ///
+    /// For `use A, B { A::foo insteadof B; }` this is `true` for
+    /// `("B", "foo")`, so declaration lookups skip `B::foo` and land on
+    /// the method that actually wins.
+    pub fn trait_method_excluded(&self, trait_name: &str, method_name: &str) -> bool {
+        self.trait_precedences.iter().any(|p| {
+            p.method_name.eq_ignore_ascii_case(method_name)
+                && p.insteadof.iter().any(|t| t.as_str() == trait_name)
+        })
+    }
+
     /// Look up a method by exact name (case-sensitive).
     ///
     /// Uses the `method_index` for O(1) lookup when available,
@@ -1764,6 +1798,7 @@ impl ClassInfo {
             || self.is_abstract != other.is_abstract
             || self.deprecation_message != other.deprecation_message
             || self.deprecated_replacement != other.deprecated_replacement
+            || self.is_internal != other.is_internal
             || self.attribute_targets != other.attribute_targets
             || self.template_params != other.template_params
             || self.template_param_bounds != other.template_param_bounds
@@ -2337,8 +2372,9 @@ pub(crate) const MAX_INHERITANCE_DEPTH: u32 = 20;
 /// (a trait can itself `use` other traits).
 pub(crate) const MAX_TRAIT_DEPTH: u32 = 20;
 
-/// Maximum depth when recursing into `@mixin` class graphs.
-pub(crate) const MAX_MIXIN_DEPTH: u32 = 10;
+/// Maximum depth when recursing into `@mixin` class graphs
+/// (a mixin class can itself declare `@mixin`).
+pub(crate) const MAX_MIXIN_DEPTH: u32 = 5;
 
 /// Maximum depth when resolving `@phpstan-type` / `@psalm-type` aliases
 /// (an alias can reference another alias).
@@ -21,6 +21,10 @@ use mago_syntax::ast::ModifierSequenceExt;
 
 use crate::php_type::PhpType;
 
+/// Work-done progress token used for workspace indexing.  This is the
+/// only progress the user can cancel.
+pub(crate) const INDEXING_PROGRESS_TOKEN: &str = "phpantom/indexing";
+
 /// Resolve an unqualified or partially-qualified PHP class/function name
 /// to a fully-qualified name using the file's `use` map and namespace.
 ///
@@ -141,23 +145,40 @@ pub(crate) fn catch_panic<T>(
 ) -> Option<T> {
     match panic::catch_unwind(f) {
         Ok(value) => Some(value),
-        Err(_) => {
+        Err(payload) => {
+            let reason = panic_message(payload.as_ref());
             if let Some(pos) = position {
                 tracing::error!(
-                    "PHPantom: panic during {} at {}:{}:{}",
+                    "PHPantom: panic during {} at {}:{}:{}: {}",
                     label,
                     uri,
                     pos.line,
-                    pos.character
+                    pos.character,
+                    reason
                 );
             } else {
-                tracing::error!("PHPantom: panic during {} at {}", label, uri);
+                tracing::error!("PHPantom: panic during {} at {}: {}", label, uri, reason);
             }
             None
         }
     }
 }
 
+/// Extract a human-readable message from a panic payload.
+///
+/// `panic!("literal")` produces a `&'static str` payload and the
+/// formatting form produces a `String`; any other payload type is
+/// reported as `"unknown panic"`.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
 /// Convenience wrapper around [`catch_panic`] for closures that
 /// capture `&self` or other non-[`UnwindSafe`] references.
 ///
@@ -1439,6 +1460,7 @@ impl Backend {
         // itself.  For bare names (no backslash) the FQN equals the
         // short name, which is also stored in the index.
         if let Some(cls) = self.fqn_index.read().get(class_name) {
+            self.index_lru.touch_class(class_name);
             return Some(Arc::clone(cls));
         }
 
@@ -1676,6 +1698,7 @@ impl Backend {
         // when the ast_map entry is missing.
         self.ast_map.write().remove(uri);
         self.symbol_maps.write().remove(uri);
+        self.strict_types_map.write().remove(uri);
         self.use_map.write().remove(uri);
         self.resolved_names.write().remove(uri);
         self.namespace_map.write().remove(uri);
@@ -1692,8 +1715,23 @@ impl Backend {
         }
     }
 
+    /// Send a `window/showMessage` notification, which editors surface
+    /// as a popup rather than a log line.
+    pub(crate) async fn show_message(&self, typ: MessageType, message: String) {
+        if let Some(client) = &self.client {
+            client.show_message(typ, message).await;
+        }
+    }
+
     // ── Work-done progress helpers ──────────────────────────────────
 
+    /// Whether the progress reported under `token` may be cancelled by
+    /// the user.  Only workspace indexing honours
+    /// `window/workDoneProgress/cancel`.
+    fn progress_is_cancellable(token: &NumberOrString) -> bool {
+        matches!(token, NumberOrString::String(name) if name == INDEXING_PROGRESS_TOKEN)
+    }
+
     /// Create a server-initiated work-done progress token and send the
     /// `window/workDoneProgress/create` request to the client.
     ///
@@ -1755,7 +1793,7 @@ impl Backend {
                 value: ProgressParamsValue::WorkDone(WorkDoneProgress::Begin(
                     WorkDoneProgressBegin {
                         title: title.to_string(),
-                        cancellable: Some(false),
+                        cancellable: Some(Self::progress_is_cancellable(token)),
                         message,
                         percentage: Some(0),
                     },
@@ -1783,7 +1821,7 @@ impl Backend {
                 token: token.clone(),
                 value: ProgressParamsValue::WorkDone(WorkDoneProgress::Report(
                     WorkDoneProgressReport {
-                        cancellable: Some(false),
+                        cancellable: Some(Self::progress_is_cancellable(token)),
                         message,
                         percentage: Some(percentage),
                     },
@@ -2152,6 +2190,18 @@ pub fn push_unique_location(
 mod tests {
     use super::*;
 
+    #[test]
+    fn panic_message_reads_str_and_string_payloads() {
+        let literal = panic::catch_unwind(|| panic!("boom")).unwrap_err();
+        assert_eq!(panic_message(literal.as_ref()), "boom");
+
+        let formatted = panic::catch_unwind(|| panic!("bad offset {}", 42)).unwrap_err();
+        assert_eq!(panic_message(formatted.as_ref()), "bad offset 42");
+
+        let other = panic::catch_unwind(|| std::panic::panic_any(7_u8)).unwrap_err();
+        assert_eq!(panic_message(other.as_ref()), "unknown panic");
+    }
+
     #[test]
     fn is_self_or_static_matches_three() {
         assert!(is_self_or_static("self"));
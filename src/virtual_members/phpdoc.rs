@@ -45,6 +45,12 @@ thread_local! {
     /// name but different members.
     static MIXIN_CACHE: RefCell<HashMap<String, Arc<ClassInfo>>> =
         RefCell::new(HashMap::new());
+
+    /// Circular `@mixin` chains already reported on this thread, keyed
+    /// by their normalized cycle (see [`warn_mixin_cycle`]), so each
+    /// cycle is logged once rather than on every resolution of every
+    /// class that reaches it.
+    static WARNED_MIXIN_CYCLES: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
 }
 
 /// Clear the thread-local mixin resolution cache.
@@ -56,6 +62,36 @@ thread_local! {
 /// backend so that stale entries from a previous test do not leak.
 pub fn clear_mixin_cache() {
     MIXIN_CACHE.with(|cache| cache.borrow_mut().clear());
+    WARNED_MIXIN_CYCLES.with(|warned| warned.borrow_mut().clear());
+}
+
+/// Log a circular `@mixin` chain that closes at `key`, once per cycle.
+///
+/// `chain` is the current recursion path and contains `key`.  The
+/// cycle is the part of the path from `key` onwards, rotated to start
+/// at its smallest entry so that `A -> B -> A` and `B -> A -> B` count
+/// as the same cycle.  Returns `true` when the warning was emitted.
+fn warn_mixin_cycle(chain: &[String], key: &str) -> bool {
+    let start = chain.iter().position(|k| k == key).unwrap_or(0);
+    let mut cycle = chain[start..].to_vec();
+    if let Some(min) = cycle
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, k)| k.as_str())
+        .map(|(i, _)| i)
+    {
+        cycle.rotate_left(min);
+    }
+    let Some(first) = cycle.first() else {
+        return false;
+    };
+    let path = format!("{} -> {}", cycle.join(" -> "), first);
+
+    let is_new = WARNED_MIXIN_CYCLES.with(|warned| warned.borrow_mut().insert(path.clone()));
+    if is_new {
+        tracing::warn!("PHPantom: circular @mixin chain: {}", path);
+    }
+    is_new
 }
 
 /// Tracks member names already seen during mixin collection.
@@ -68,6 +104,50 @@ struct MixinCollector {
     properties: Vec<PropertyInfo>,
     constants: Vec<ConstantInfo>,
     dedup: MixinDedup,
+    /// Lowercased FQNs and generic arguments of the mixins already
+    /// collected, so that a mixin reached twice with the same arguments
+    /// (e.g. through two other mixins) is walked once, while `Box<A>`
+    /// and `Box<B>` are each walked.
+    visited: HashSet<String>,
+    /// Lowercased FQNs of the consuming class and of the mixins on the
+    /// current recursion path, used to detect circular `@mixin` chains.
+    chain: Vec<String>,
+}
+
+impl MixinCollector {
+    fn new(
+        methods: Vec<MethodInfo>,
+        properties: Vec<PropertyInfo>,
+        constants: Vec<ConstantInfo>,
+        dedup: MixinDedup,
+        consumer: &ClassInfo,
+    ) -> Self {
+        MixinCollector {
+            methods,
+            properties,
+            constants,
+            dedup,
+            visited: HashSet::new(),
+            chain: vec![mixin_key(consumer)],
+        }
+    }
+}
+
+/// Key identifying a class in [`MixinCollector::chain`].
+fn mixin_key(class: &ClassInfo) -> String {
+    class.fqn().to_ascii_lowercase()
+}
+
+/// Key identifying a mixin and its generic arguments in
+/// [`MixinCollector::visited`], e.g. `app\box<app\item>`.
+fn mixin_visit_key(key: &str, generic_args: Option<&[PhpType]>) -> String {
+    match generic_args {
+        Some(args) if !args.is_empty() => {
+            let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+            format!("{}<{}>", key, args.join(", ")).to_ascii_lowercase()
+        }
+        _ => key.to_string(),
+    }
 }
 
 /// Passed through [`collect_mixin_members`] (including recursive calls)
@@ -437,12 +517,7 @@ impl VirtualMemberProvider for PHPDocProvider {
             constants: seen_consts,
         };
 
-        let mut collector = MixinCollector {
-            methods,
-            properties,
-            constants,
-            dedup: mixin_dedup,
-        };
+        let mut collector = MixinCollector::new(methods, properties, constants, mixin_dedup, class);
 
         // Collect from the class's own mixins.
         //
@@ -542,7 +617,10 @@ impl VirtualMemberProvider for PHPDocProvider {
 /// is called take precedence over mixin members.
 ///
 /// Recurses into mixins declared on the mixin classes themselves, up to
-/// [`MAX_MIXIN_DEPTH`] levels.
+/// [`MAX_MIXIN_DEPTH`] levels.  A mixin that was already collected with
+/// the same generic arguments is skipped, and a mixin that leads back to
+/// a class on the current chain (e.g. `A` → `@mixin B` → `@mixin A`) is
+/// not followed; each such cycle is logged once per thread.
 ///
 /// Uses a thread-local cache so that `resolve_class_with_inheritance` is
 /// called at most once per unique mixin FQN across all `provide` calls
@@ -584,6 +662,12 @@ fn collect_mixin_members(
             continue;
         };
 
+        let key = mixin_key(&mixin_class);
+        if collector.chain.contains(&key) {
+            warn_mixin_cycle(&collector.chain, &key);
+            continue;
+        }
+
         // Find generic args for this mixin from the @mixin tag.
         // Check both the original name (e.g. "TWraps") and the resolved
         // name in case the mixin_generics were stored under either form.
@@ -597,6 +681,13 @@ fn collect_mixin_members(
             })
             .map(|(_, args)| args.as_slice());
 
+        if !collector
+            .visited
+            .insert(mixin_visit_key(&key, generic_args))
+        {
+            continue;
+        }
+
         // Resolve the mixin class with its own inheritance so we see
         // all of its inherited/trait members too.  Use base resolution
         // (not resolve_class_fully) to avoid circular provider calls.
@@ -742,17 +833,21 @@ fn collect_mixin_members(
             }
         }
 
-        // Recurse into mixins declared by the mixin class itself.
+        // Recurse into mixins declared by the mixin class itself, passing
+        // its generic arguments so that e.g. `@mixin T` on `Box<T>`
+        // resolves to the concrete class.
         if !mixin_class.mixins.is_empty() {
+            collector.chain.push(key);
             collect_mixin_members(
                 &mixin_class.mixins,
                 &mixin_class.mixin_generics,
                 class_loader,
                 collector,
-                &HashMap::new(),
+                &subs,
                 depth + 1,
                 cache,
             );
+            collector.chain.pop();
         }
     }
 }
@@ -816,12 +911,8 @@ pub fn resolve_template_param_mixins(
         constants: HashSet::new(),
     };
 
-    let mut collector = MixinCollector {
-        methods: Vec::new(),
-        properties: Vec::new(),
-        constants: Vec::new(),
-        dedup,
-    };
+    let mut collector =
+        MixinCollector::new(Vec::new(), Vec::new(), Vec::new(), dedup, original_class);
 
     collect_mixin_members(
         &template_mixins,
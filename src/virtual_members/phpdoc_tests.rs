@@ -428,6 +428,121 @@ fn mixin_leaves_this_return_type_as_is_for_consumer_resolution() {
     }
 }
 
+#[test]
+fn mixin_cycle_is_not_followed() {
+    let provider = PHPDocProvider;
+    let mut class = make_class("CycleRoot");
+    class.mixins = vec![atom("CycleA")];
+
+    let mut a = make_class("CycleA");
+    a.mixins = vec![atom("CycleB")];
+    a.methods
+        .push(Arc::new(make_method("aMethod", Some("void"))));
+
+    // `CycleB` points back at both the consumer and `CycleA`.
+    let mut b = make_class("CycleB");
+    b.mixins = vec![atom("CycleRoot"), atom("CycleA")];
+    b.methods
+        .push(Arc::new(make_method("bMethod", Some("void"))));
+
+    let mut root = class.clone();
+    root.methods
+        .push(Arc::new(make_method("rootOnlyOnLoad", Some("void"))));
+
+    let class_loader = move |name: &str| -> Option<Arc<ClassInfo>> {
+        match name {
+            "CycleRoot" => Some(Arc::new(root.clone())),
+            "CycleA" => Some(Arc::new(a.clone())),
+            "CycleB" => Some(Arc::new(b.clone())),
+            _ => None,
+        }
+    };
+
+    let result = provider.provide(&class, &class_loader, None);
+    let names: Vec<&str> = result.methods.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["aMethod", "bMethod"]);
+}
+
+#[test]
+fn mixin_cycle_is_warned_once() {
+    clear_mixin_cache();
+
+    let chain = |keys: &[&str]| -> Vec<String> { keys.iter().map(|k| k.to_string()).collect() };
+    assert!(warn_mixin_cycle(&chain(&["a", "b"]), "a"));
+    // The same cycle entered from another class and another member.
+    assert!(!warn_mixin_cycle(&chain(&["root", "b", "a"]), "b"));
+    assert!(!warn_mixin_cycle(&chain(&["a", "b"]), "a"));
+    // A different cycle is still reported.
+    assert!(warn_mixin_cycle(&chain(&["c", "d"]), "c"));
+}
+
+#[test]
+fn same_mixin_with_different_generic_args_is_walked_for_each() {
+    clear_mixin_cache();
+
+    let provider = PHPDocProvider;
+    let mut class = make_class("Consumer");
+    class.mixins = vec![atom("WrapFoo"), atom("WrapBar")];
+
+    let mut wrap_foo = make_class("WrapFoo");
+    wrap_foo.mixins = vec![atom("Box")];
+    wrap_foo.mixin_generics = vec![(atom("Box"), vec![PhpType::Named("Foo".to_string())])];
+
+    let mut wrap_bar = make_class("WrapBar");
+    wrap_bar.mixins = vec![atom("Box")];
+    wrap_bar.mixin_generics = vec![(atom("Box"), vec![PhpType::Named("Bar".to_string())])];
+
+    // `@template T` + `@mixin T`
+    let mut boxed = make_class("Box");
+    boxed.template_params = vec![atom("T")];
+    boxed.mixins = vec![atom("T")];
+
+    let mut foo = make_class("Foo");
+    foo.methods
+        .push(Arc::new(make_method("fooMethod", Some("void"))));
+    let mut bar = make_class("Bar");
+    bar.methods
+        .push(Arc::new(make_method("barMethod", Some("void"))));
+
+    let class_loader = move |name: &str| -> Option<Arc<ClassInfo>> {
+        match name {
+            "WrapFoo" => Some(Arc::new(wrap_foo.clone())),
+            "WrapBar" => Some(Arc::new(wrap_bar.clone())),
+            "Box" => Some(Arc::new(boxed.clone())),
+            "Foo" => Some(Arc::new(foo.clone())),
+            "Bar" => Some(Arc::new(bar.clone())),
+            _ => None,
+        }
+    };
+
+    let result = provider.provide(&class, &class_loader, None);
+    let names: Vec<&str> = result.methods.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["fooMethod", "barMethod"]);
+}
+
+#[test]
+fn mixin_chain_stops_at_max_depth() {
+    let provider = PHPDocProvider;
+    let mut class = make_class("DepthRoot");
+    class.mixins = vec![atom("Depth0")];
+
+    let class_loader = |name: &str| -> Option<Arc<ClassInfo>> {
+        let level: u32 = name.strip_prefix("Depth")?.parse().ok()?;
+        let mut mixin = make_class(name);
+        mixin.mixins = vec![atom(&format!("Depth{}", level + 1))];
+        mixin.methods.push(Arc::new(make_method(
+            &format!("level{}", level),
+            Some("void"),
+        )));
+        Some(Arc::new(mixin))
+    };
+
+    let result = provider.provide(&class, &class_loader, None);
+    assert_eq!(result.methods.len() as u32, MAX_MIXIN_DEPTH + 1);
+    assert!(result.methods.iter().any(|m| m.name == "level5"));
+    assert!(!result.methods.iter().any(|m| m.name == "level6"));
+}
+
 #[test]
 fn mixin_collects_from_ancestor_mixins() {
     let provider = PHPDocProvider;
@@ -0,0 +1,62 @@
+//! Workspace edit construction shared by multi-file features.
+//!
+//! Rename and auto-import compute their edits as a per-file map of
+//! `TextEdit`s.  Files that are not open in the editor are read from
+//! disk while the edits are computed, so the map already covers closed
+//! files.  This module turns that map into the `WorkspaceEdit` the
+//! client expects.
+//!
+//! When the client advertises `workspace.workspaceEdit.documentChanges`,
+//! the edit is sent as `document_changes`: one `TextDocumentEdit` per
+//! file, tagged with the buffer version for open documents and with no
+//! version for files read from disk.  The client can then refuse edits
+//! computed against a stale buffer.  Otherwise the plain `changes` map
+//! is used.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use tower_lsp::lsp_types::*;
+
+use crate::Backend;
+
+impl Backend {
+    /// Build a `WorkspaceEdit` from per-file text edits, using versioned
+    /// `document_changes` when the client supports them.
+    pub(crate) fn build_workspace_edit(
+        &self,
+        changes: HashMap<Url, Vec<TextEdit>>,
+    ) -> WorkspaceEdit {
+        if !self.supports_document_changes.load(Ordering::Acquire) {
+            return WorkspaceEdit {
+                changes: Some(changes),
+                document_changes: None,
+                change_annotations: None,
+            };
+        }
+
+        let mut edits: Vec<TextDocumentEdit> = changes
+            .into_iter()
+            .map(|(uri, edits)| TextDocumentEdit {
+                text_document: self.versioned_document(uri),
+                edits: edits.into_iter().map(OneOf::Left).collect(),
+            })
+            .collect();
+        // Sort by URI for a deterministic response.
+        edits.sort_by(|a, b| a.text_document.uri.cmp(&b.text_document.uri));
+
+        WorkspaceEdit {
+            changes: None,
+            document_changes: Some(DocumentChanges::Edits(edits)),
+            change_annotations: None,
+        }
+    }
+
+    /// Identify `uri` with the version of its open buffer, or with no
+    /// version when the file is not open (its edits were computed from
+    /// the content on disk).
+    pub(crate) fn versioned_document(&self, uri: Url) -> OptionalVersionedTextDocumentIdentifier {
+        let version = self.open_file_versions.read().get(uri.as_str()).copied();
+        OptionalVersionedTextDocumentIdentifier { uri, version }
+    }
+}
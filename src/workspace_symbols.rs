@@ -120,6 +120,12 @@ impl Backend {
         // don't produce duplicates for classes already in the ast_map.
         let mut seen_fqns: HashSet<String> = HashSet::new();
 
+        // `@internal` symbols in vendor packages are private to those
+        // packages and are left out.  The project's own internal
+        // symbols stay searchable.
+        let vendor_prefixes = self.vendor_uri_prefixes.lock().clone();
+        let is_vendor_uri = |uri: &str| vendor_prefixes.iter().any(|p| uri.starts_with(p.as_str()));
+
         // ── Classes, interfaces, traits, enums (from ast_map) ───────
         // Also emits methods, properties, and class constants.
         {
@@ -131,6 +137,10 @@ impl Backend {
                     if class.name.is_empty() || class.name.starts_with("anonymous@") {
                         continue;
                     }
+                    let vendor_file = is_vendor_uri(file_uri);
+                    if vendor_file && class.is_internal {
+                        continue;
+                    }
 
                     let fqn = class.fqn().to_string();
 
@@ -188,6 +198,9 @@ impl Backend {
                         if method.name_offset == 0 {
                             continue;
                         }
+                        if vendor_file && method.is_internal {
+                            continue;
+                        }
 
                         let tier = match match_tier(&method.name, &query_lower) {
                             Some(t) => t,
@@ -396,6 +409,11 @@ impl Backend {
                 if seen_fqns.contains(fqn) {
                     continue;
                 }
+                if is_vendor_uri(file_uri)
+                    && fqn_idx.get(fqn.as_str()).is_some_and(|c| c.is_internal)
+                {
+                    continue;
+                }
 
                 let fqn_short = short_name(fqn);
                 let tier = match match_tier(fqn, &query_lower)
@@ -439,6 +439,12 @@ fn types_match(expected: &str, actual: &str) -> bool {
         return true;
     }
 
+    // Hover shows `mixed` for a variable whose type could not be
+    // inferred, which is how PHPantom reports PHPStan's `*ERROR*`.
+    if ne == "*ERROR*" && na == "mixed" {
+        return true;
+    }
+
     // Generator<K, V> is semantically equivalent to Generator<K, V, mixed, mixed>.
     // Normalize both sides to compare without trailing `mixed` params.
     let ne_gen = normalize_generator_params(&ne);
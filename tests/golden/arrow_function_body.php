@@ -0,0 +1,12 @@
+<?php
+
+class Logger
+{
+    public function info(string $message): void {}
+    public function error(string $message): void {}
+}
+
+function run(Logger $logger): void
+{
+    $log = fn() => $logger->/*|*/
+}
@@ -0,0 +1,16 @@
+<?php
+
+class Greeter
+{
+    public string $name;
+    protected int $count;
+    private bool $enabled;
+
+    public function greet(string $who): string {}
+    protected function log(): void {}
+    private function reset(): void {}
+    public static function create(): Greeter {}
+}
+
+$greeter = new Greeter();
+$greeter->/*|*/
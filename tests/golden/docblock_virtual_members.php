@@ -0,0 +1,14 @@
+<?php
+
+/**
+ * @property string $email
+ * @property-read int $id
+ * @method bool save(array $options = [])
+ */
+class Model
+{
+    public function fresh(): Model {}
+}
+
+$model = new Model();
+$model->/*|*/
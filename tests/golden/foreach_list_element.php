@@ -0,0 +1,18 @@
+<?php
+
+class Order
+{
+    public int $total = 0;
+
+    public function ship(): void {}
+}
+
+/**
+ * @param list<Order> $orders
+ */
+function process(array $orders): void
+{
+    foreach ($orders as $order) {
+        $order->/*|*/
+    }
+}
@@ -0,0 +1,23 @@
+<?php
+
+class User
+{
+    public string $email = '';
+
+    public function isAdmin(): bool {}
+}
+
+/**
+ * @template T
+ */
+class Collection
+{
+    /** @return T */
+    public function first(): mixed {}
+}
+
+/** @param Collection<User> $users */
+function notify(Collection $users): void
+{
+    $users->first()->/*|*/
+}
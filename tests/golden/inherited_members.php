@@ -0,0 +1,19 @@
+<?php
+
+class Animal
+{
+    public string $name = '';
+
+    public function speak(): string {}
+    public function eat(): void {}
+}
+
+class Dog extends Animal
+{
+    public bool $goodBoy = true;
+
+    public function fetch(): void {}
+}
+
+$dog = new Dog();
+$dog->/*|*/
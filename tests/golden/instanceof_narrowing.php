@@ -0,0 +1,20 @@
+<?php
+
+class Circle
+{
+    public float $radius = 0.0;
+
+    public function area(): float {}
+}
+
+class Square
+{
+    public float $side = 0.0;
+}
+
+function describe(Circle|Square $shape): void
+{
+    if ($shape instanceof Circle) {
+        $shape->/*|*/
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+interface Readable
+{
+    public function read(int $length): string;
+}
+
+interface Stream extends Readable
+{
+    public function close(): void;
+}
+
+function consume(Stream $stream): void
+{
+    $stream->/*|*/
+}
@@ -0,0 +1,12 @@
+<?php
+
+interface Cache
+{
+    public function get(string $key): mixed;
+    public function set(string $key, mixed $value, int $ttl = 0): bool;
+}
+
+function warm(Cache $cache): void
+{
+    $cache->/*|*/
+}
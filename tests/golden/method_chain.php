@@ -0,0 +1,16 @@
+<?php
+
+class QueryBuilder
+{
+    public function where(string $column, mixed $value): QueryBuilder {}
+    public function limit(int $count): QueryBuilder {}
+    public function get(): array {}
+}
+
+class Repository
+{
+    public function query(): QueryBuilder {}
+}
+
+$repo = new Repository();
+$repo->query()->where('id', 1)->/*|*/
@@ -0,0 +1,18 @@
+<?php
+
+class Address
+{
+    public string $city = '';
+
+    public function format(): string {}
+}
+
+class Customer
+{
+    public function address(): ?Address {}
+}
+
+function show(Customer $customer): void
+{
+    $customer->address()?->/*|*/
+}
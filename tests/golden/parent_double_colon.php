@@ -0,0 +1,18 @@
+<?php
+
+class BaseController
+{
+    protected string $view = '';
+
+    public function render(): string {}
+    protected function authorize(string $ability): bool {}
+    private function secret(): void {}
+}
+
+class UserController extends BaseController
+{
+    public function render(): string
+    {
+        parent::/*|*/
+    }
+}
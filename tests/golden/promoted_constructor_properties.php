@@ -0,0 +1,15 @@
+<?php
+
+class Point
+{
+    public function __construct(
+        public readonly int $x,
+        public readonly int $y,
+        private int $scale = 1,
+    ) {}
+
+    public function length(): float {}
+}
+
+$point = new Point(1, 2);
+$point->/*|*/
@@ -0,0 +1,16 @@
+<?php
+
+class Mailer
+{
+    public function send(string $to): bool {}
+}
+
+class NotificationService
+{
+    private Mailer $mailer;
+
+    public function notify(): void
+    {
+        $this->mailer->/*|*/
+    }
+}
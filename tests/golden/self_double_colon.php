@@ -0,0 +1,16 @@
+<?php
+
+class Registry
+{
+    const DEFAULT_KEY = 'main';
+    private const LIMIT = 10;
+    private static array $items = [];
+
+    public static function put(string $key, mixed $value): void {}
+    public function all(): array {}
+
+    public function reset(): void
+    {
+        self::/*|*/
+    }
+}
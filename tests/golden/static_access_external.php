@@ -0,0 +1,14 @@
+<?php
+
+class Config
+{
+    public const VERSION = '1.0';
+    protected const SECRET = 'x';
+    public static int $loads = 0;
+    public string $path = '';
+
+    public static function load(string $file): Config {}
+    public function get(string $key): mixed {}
+}
+
+Config::/*|*/
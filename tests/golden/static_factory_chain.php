@@ -0,0 +1,11 @@
+<?php
+
+class Money
+{
+    public static function of(int $amount): static {}
+
+    public function plus(Money $other): Money {}
+    public function amount(): int {}
+}
+
+Money::of(5)->/*|*/
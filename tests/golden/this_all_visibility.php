@@ -0,0 +1,18 @@
+<?php
+
+class Counter
+{
+    public int $total = 0;
+    protected array $history = [];
+    private string $label = '';
+    public static int $instances = 0;
+
+    public function increment(int $by = 1): void
+    {
+        $this->/*|*/
+    }
+
+    protected function record(int $value): void {}
+    private function format(): string {}
+    public static function make(): Counter {}
+}
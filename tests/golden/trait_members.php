@@ -0,0 +1,20 @@
+<?php
+
+trait HasTimestamps
+{
+    public int $createdAt = 0;
+
+    public function touch(): void {}
+}
+
+class Post
+{
+    use HasTimestamps;
+
+    public string $title = '';
+
+    public function publish(): bool {}
+}
+
+$post = new Post();
+$post->/*|*/
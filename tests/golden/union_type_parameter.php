@@ -0,0 +1,18 @@
+<?php
+
+class Lamp
+{
+    public function turnOn(): void {}
+    public function dim(int $level): void {}
+}
+
+class Faucet
+{
+    public function turnOn(): void {}
+    public function drip(): void {}
+}
+
+function activate(Lamp|Faucet $device): void
+{
+    $device->/*|*/
+}
@@ -0,0 +1,206 @@
+//! Golden-file completion test runner.
+//!
+//! Each `.php` file in `tests/golden/` marks one or more cursor positions
+//! with `/*|*/`.  The runner strips the markers, opens the file, fires a
+//! completion request at every marker, and compares the returned items
+//! (label, kind, detail) against the sibling `.expected.json` file.
+//!
+//! Run with `UPDATE_GOLDEN=1 cargo test --test golden_runner` to rewrite
+//! the expected files from the current output.  Review the diff before
+//! committing.
+//!
+//! See `tests/golden/README.md` for the file format.
+
+use std::path::Path;
+
+use phpantom_lsp::Backend;
+use serde::{Deserialize, Serialize};
+use tower_lsp::LanguageServer;
+use tower_lsp::lsp_types::*;
+
+/// Cursor marker in golden `.php` files.
+const CURSOR: &str = "/*|*/";
+
+// ─── Golden format ──────────────────────────────────────────────────────────
+
+/// Expected completion items at one cursor position.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GoldenCursor {
+    /// Cursor line (0-based), after markers are stripped.
+    line: u32,
+    /// Cursor character (0-based, UTF-16 code units).
+    character: u32,
+    /// Items in `sort_text` order.
+    items: Vec<GoldenItem>,
+}
+
+/// The parts of a `CompletionItem` that golden files pin down.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GoldenItem {
+    label: String,
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+/// Strip every cursor marker from `source`, returning the clean source
+/// and the marker positions in order of appearance.
+fn strip_cursors(source: &str) -> (String, Vec<Position>) {
+    let mut content = String::with_capacity(source.len());
+    let mut cursors = Vec::new();
+
+    for (line_idx, line) in source.split_inclusive('\n').enumerate() {
+        let mut rest = line;
+        let mut stripped_line = String::new();
+        while let Some(pos) = rest.find(CURSOR) {
+            stripped_line.push_str(&rest[..pos]);
+            cursors.push(Position {
+                line: line_idx as u32,
+                character: stripped_line.encode_utf16().count() as u32,
+            });
+            rest = &rest[pos + CURSOR.len()..];
+        }
+        stripped_line.push_str(rest);
+        content.push_str(&stripped_line);
+    }
+
+    (content, cursors)
+}
+
+/// Short, stable name for a completion item kind.
+fn kind_name(kind: Option<CompletionItemKind>) -> String {
+    let Some(kind) = kind else {
+        return "none".to_string();
+    };
+    match kind {
+        CompletionItemKind::METHOD => "method",
+        CompletionItemKind::FUNCTION => "function",
+        CompletionItemKind::CONSTRUCTOR => "constructor",
+        CompletionItemKind::FIELD => "field",
+        CompletionItemKind::VARIABLE => "variable",
+        CompletionItemKind::CLASS => "class",
+        CompletionItemKind::INTERFACE => "interface",
+        CompletionItemKind::MODULE => "module",
+        CompletionItemKind::PROPERTY => "property",
+        CompletionItemKind::ENUM => "enum",
+        CompletionItemKind::KEYWORD => "keyword",
+        CompletionItemKind::SNIPPET => "snippet",
+        CompletionItemKind::CONSTANT => "constant",
+        CompletionItemKind::ENUM_MEMBER => "enum_member",
+        CompletionItemKind::STRUCT => "struct",
+        other => return format!("{other:?}"),
+    }
+    .to_string()
+}
+
+// ─── Test execution ─────────────────────────────────────────────────────────
+
+async fn complete_at(backend: &Backend, uri: &Url, position: Position) -> Vec<GoldenItem> {
+    let params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position,
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let mut items = match backend.completion(params).await {
+        Ok(Some(CompletionResponse::Array(items))) => items,
+        Ok(Some(CompletionResponse::List(list))) => list.items,
+        _ => Vec::new(),
+    };
+    // Order by what the editor shows; the label breaks ties so the
+    // output is deterministic when sort_text is absent.
+    items.sort_by(|a, b| {
+        a.sort_text
+            .as_deref()
+            .unwrap_or(&a.label)
+            .cmp(b.sort_text.as_deref().unwrap_or(&b.label))
+            .then_with(|| a.label.cmp(&b.label))
+    });
+
+    items
+        .into_iter()
+        .map(|item| GoldenItem {
+            kind: kind_name(item.kind),
+            label: item.label,
+            detail: item.detail,
+        })
+        .collect()
+}
+
+fn run_golden(path: &Path, source: String) -> datatest_stable::Result<()> {
+    let (content, cursors) = strip_cursors(&source);
+    if cursors.is_empty() {
+        return Err(format!("{} has no `{CURSOR}` cursor marker", path.display()).into());
+    }
+
+    let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+    let uri = Url::parse(&format!("file:///golden/{file_name}")).unwrap();
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to build tokio runtime: {e}"))?;
+
+    let actual: Vec<GoldenCursor> = rt.block_on(async {
+        let backend = Backend::new_test();
+        backend
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "php".to_string(),
+                    version: 1,
+                    text: content,
+                },
+            })
+            .await;
+
+        let mut result = Vec::with_capacity(cursors.len());
+        for position in cursors {
+            result.push(GoldenCursor {
+                line: position.line,
+                character: position.character,
+                items: complete_at(&backend, &uri, position).await,
+            });
+        }
+        result
+    });
+
+    let expected_path = path.with_file_name(file_name.replace(".php", ".expected.json"));
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let json = serde_json::to_string_pretty(&actual)? + "\n";
+        std::fs::write(&expected_path, json)?;
+        return Ok(());
+    }
+
+    let expected_json = std::fs::read_to_string(&expected_path).map_err(|e| {
+        format!(
+            "{}: {e} (run with UPDATE_GOLDEN=1 to create it)",
+            expected_path.display()
+        )
+    })?;
+    let expected: Vec<GoldenCursor> = serde_json::from_str(&expected_json)
+        .map_err(|e| format!("{}: invalid JSON: {e}", expected_path.display()))?;
+
+    if actual != expected {
+        return Err(format!(
+            "{} does not match {}\n\nactual:\n{}\n\n(run with UPDATE_GOLDEN=1 to accept)",
+            path.display(),
+            expected_path.display(),
+            serde_json::to_string_pretty(&actual)?,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+// ─── Harness ────────────────────────────────────────────────────────────────
+
+datatest_stable::harness! {
+    { test = run_golden, root = "tests/golden", pattern = r"\.php$" },
+}
@@ -0,0 +1,283 @@
+use crate::common::create_test_backend;
+use phpantom_lsp::Backend;
+use tower_lsp::LanguageServer;
+use tower_lsp::lsp_types::*;
+
+// ─── Helpers ────────────────────────────────────────────────────────────────
+
+async fn open(backend: &Backend, uri: &Url, text: &str) {
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+}
+
+async fn prepare_at(
+    backend: &Backend,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> Vec<CallHierarchyItem> {
+    let params = CallHierarchyPrepareParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    backend
+        .prepare_call_hierarchy(params)
+        .await
+        .unwrap()
+        .unwrap_or_default()
+}
+
+async fn incoming_of(
+    backend: &Backend,
+    item: &CallHierarchyItem,
+) -> Vec<CallHierarchyIncomingCall> {
+    let params = CallHierarchyIncomingCallsParams {
+        item: item.clone(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    backend
+        .incoming_calls(params)
+        .await
+        .unwrap()
+        .unwrap_or_default()
+}
+
+async fn outgoing_of(
+    backend: &Backend,
+    item: &CallHierarchyItem,
+) -> Vec<CallHierarchyOutgoingCall> {
+    let params = CallHierarchyOutgoingCallsParams {
+        item: item.clone(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    backend
+        .outgoing_calls(params)
+        .await
+        .unwrap()
+        .unwrap_or_default()
+}
+
+const SOURCE: &str = concat!(
+    "<?php\n",                               // 0
+    "namespace App;\n",                      // 1
+    "function helper(): void {}\n",          // 2
+    "class Service {\n",                     // 3
+    "    public function __construct() {\n", // 4
+    "        $this->boot();\n",              // 5
+    "    }\n",                               // 6
+    "    public function boot(): void {\n",  // 7
+    "        helper();\n",                   // 8
+    "        $this->load();\n",              // 9
+    "        $this->load();\n",              // 10
+    "    }\n",                               // 11
+    "    public function load(): void {\n",  // 12
+    "        helper();\n",                   // 13
+    "    }\n",                               // 14
+    "}\n",                                   // 15
+);
+
+// ─── Prepare ────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn prepare_on_method_declaration() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let items = prepare_at(&backend, &uri, 7, 22).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "boot");
+    assert_eq!(items[0].kind, SymbolKind::METHOD);
+    assert_eq!(items[0].detail.as_deref(), Some("App\\Service"));
+    assert_eq!(items[0].selection_range.start, Position::new(7, 20));
+    assert_eq!(items[0].range.end, Position::new(11, 5));
+}
+
+#[tokio::test]
+async fn prepare_on_call_site_resolves_declaration() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let items = prepare_at(&backend, &uri, 8, 9).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "helper");
+    assert_eq!(items[0].kind, SymbolKind::FUNCTION);
+    assert_eq!(items[0].selection_range.start, Position::new(2, 9));
+}
+
+#[tokio::test]
+async fn prepare_on_non_callable_returns_nothing() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    assert!(prepare_at(&backend, &uri, 3, 8).await.is_empty());
+}
+
+// ─── Incoming calls ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn incoming_calls_grouped_by_caller() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let item = prepare_at(&backend, &uri, 2, 10).await.remove(0);
+    let mut callers: Vec<(String, usize)> = incoming_of(&backend, &item)
+        .await
+        .into_iter()
+        .map(|c| (c.from.name, c.from_ranges.len()))
+        .collect();
+    callers.sort();
+    assert_eq!(
+        callers,
+        vec![("boot".to_string(), 1), ("load".to_string(), 1)]
+    );
+}
+
+#[tokio::test]
+async fn incoming_calls_collects_every_call_site() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let item = prepare_at(&backend, &uri, 12, 22).await.remove(0);
+    let calls = incoming_of(&backend, &item).await;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].from.name, "boot");
+    assert_eq!(calls[0].from_ranges.len(), 2);
+}
+
+#[tokio::test]
+async fn incoming_calls_to_method_from_constructor() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let item = prepare_at(&backend, &uri, 7, 22).await.remove(0);
+    let calls = incoming_of(&backend, &item).await;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].from.name, "__construct");
+    assert_eq!(calls[0].from.kind, SymbolKind::CONSTRUCTOR);
+}
+
+// ─── Outgoing calls ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn outgoing_calls_from_method_body() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let item = prepare_at(&backend, &uri, 7, 22).await.remove(0);
+    let mut callees: Vec<(String, usize)> = outgoing_of(&backend, &item)
+        .await
+        .into_iter()
+        .map(|c| (c.to.name, c.from_ranges.len()))
+        .collect();
+    callees.sort();
+    assert_eq!(
+        callees,
+        vec![("helper".to_string(), 1), ("load".to_string(), 2)]
+    );
+}
+
+#[tokio::test]
+async fn outgoing_calls_from_function_without_calls_is_empty() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///service.php").unwrap();
+    open(&backend, &uri, SOURCE).await;
+
+    let item = prepare_at(&backend, &uri, 2, 10).await.remove(0);
+    assert!(outgoing_of(&backend, &item).await.is_empty());
+}
+
+#[tokio::test]
+async fn outgoing_calls_include_constructor_of_new_expression() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///factory.php").unwrap();
+    let text = concat!(
+        "<?php\n",                                // 0
+        "class Base {\n",                         // 1
+        "    public function __construct() {}\n", // 2
+        "}\n",                                    // 3
+        "class Child extends Base {}\n",          // 4
+        "class Own {\n",                          // 5
+        "    public function __construct() {}\n", // 6
+        "}\n",                                    // 7
+        "function make(): void {\n",              // 8
+        "    new Own();\n",                       // 9
+        "    new Child();\n",                     // 10
+        "}\n",                                    // 11
+    );
+    open(&backend, &uri, text).await;
+
+    let item = prepare_at(&backend, &uri, 8, 10).await.remove(0);
+    let mut callees: Vec<(String, u32)> = outgoing_of(&backend, &item)
+        .await
+        .into_iter()
+        .map(|c| (c.to.name, c.to.selection_range.start.line))
+        .collect();
+    callees.sort();
+    assert_eq!(
+        callees,
+        vec![
+            ("__construct".to_string(), 2),
+            ("__construct".to_string(), 6)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn bodies_follow_abstract_and_interface_methods() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///abstract.php").unwrap();
+    let text = concat!(
+        "<?php\n",                                        // 0
+        "function ping(): void {}\n",                     // 1
+        "interface Runner {\n",                           // 2
+        "    public function run(): void;\n",             // 3
+        "}\n",                                            // 4
+        "$cb = function () { ping(); };\n",               // 5
+        "abstract class Job {\n",                         // 6
+        "    abstract public function handle(): void;\n", // 7
+        "    public function dispatch(): void {\n",       // 8
+        "        ping();\n",                              // 9
+        "    }\n",                                        // 10
+        "}\n",                                            // 11
+    );
+    open(&backend, &uri, text).await;
+
+    let run = prepare_at(&backend, &uri, 3, 22).await.remove(0);
+    assert_eq!(run.range.end, run.selection_range.end);
+    assert!(outgoing_of(&backend, &run).await.is_empty());
+
+    let handle = prepare_at(&backend, &uri, 7, 31).await.remove(0);
+    assert_eq!(handle.range.end, handle.selection_range.end);
+    assert!(outgoing_of(&backend, &handle).await.is_empty());
+
+    let ping = prepare_at(&backend, &uri, 1, 10).await.remove(0);
+    let callers: Vec<String> = incoming_of(&backend, &ping)
+        .await
+        .into_iter()
+        .map(|c| c.from.name)
+        .collect();
+    assert_eq!(callers, vec!["dispatch".to_string()]);
+}
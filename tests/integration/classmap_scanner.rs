@@ -603,6 +603,20 @@ namespace App\Services {
     );
 }
 
+#[test]
+fn scanner_handles_global_namespace_block_after_named_block() {
+    let content = br"<?php
+namespace App\Models {
+    class User {}
+}
+namespace {
+    class Helper {}
+}
+";
+    let classes = classmap_scanner::find_classes(content);
+    assert_eq!(classes, vec!["App\\Models\\User", "Helper"]);
+}
+
 #[test]
 fn first_class_wins_in_classmap() {
     // When two files define the same FQN, the first one scanned should win.
@@ -566,3 +566,157 @@ class Document implements Printable {
     assert_eq!(titles.len(), 1);
     assert_eq!(titles[0], "◆ Printable::print");
 }
+
+// ─── Reference Counts ───────────────────────────────────────────────────────
+
+/// Run `initialize`, advertising the `phpantom.showReferences` client
+/// command when `show_references` is set.
+async fn initialize(backend: &phpantom_lsp::Backend, show_references: bool) {
+    use tower_lsp::LanguageServer;
+
+    let experimental = show_references
+        .then(|| serde_json::json!({ "commands": { "commands": ["phpantom.showReferences"] } }));
+    backend
+        .initialize(InitializeParams {
+            capabilities: ClientCapabilities {
+                experimental,
+                ..ClientCapabilities::default()
+            },
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn public_methods_get_resolvable_reference_lenses() {
+    use tower_lsp::LanguageServer;
+
+    let backend = create_test_backend();
+    initialize(&backend, true).await;
+    let uri = Url::parse("file:///tmp/test_reference_lens.php").unwrap();
+    let content = r#"<?php
+class Counter {
+    public function increment(): void {}
+    public function reset(): void {}
+    private function log(): void {}
+
+    public function run(): void {
+        $this->increment();
+        $this->increment();
+        $this->log();
+    }
+}
+
+function useCounter(Counter $c): void {
+    $c->increment();
+}
+"#;
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: content.to_string(),
+            },
+        })
+        .await;
+
+    let lenses = backend
+        .code_lens(CodeLensParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap_or_default();
+
+    // One unresolved lens per public method; `log` is private.
+    let lines: Vec<u32> = lenses.iter().map(|l| l.range.start.line).collect();
+    assert_eq!(lines, vec![2, 3, 6]);
+    assert!(lenses.iter().all(|l| l.command.is_none()));
+
+    let mut titles = Vec::new();
+    for lens in lenses {
+        let resolved = backend.code_lens_resolve(lens).await.unwrap();
+        let command = resolved.command.expect("lens should be resolved");
+        assert_eq!(command.command, "phpantom.showReferences");
+        assert_eq!(
+            command.arguments.as_ref().unwrap()[0],
+            serde_json::json!(uri.as_str())
+        );
+        titles.push(command.title);
+    }
+    assert_eq!(titles, vec!["3 references", "0 references", "0 references"]);
+}
+
+#[tokio::test]
+async fn reference_lens_has_no_command_unless_client_advertises_it() {
+    use tower_lsp::LanguageServer;
+
+    let backend = create_test_backend();
+    initialize(&backend, false).await;
+    let uri = Url::parse("file:///tmp/test_reference_lens_plain.php").unwrap();
+    let content = r#"<?php
+class Counter {
+    public function increment(): void {}
+}
+
+function useCounter(Counter $c): void {
+    $c->increment();
+}
+"#;
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: content.to_string(),
+            },
+        })
+        .await;
+
+    let lenses = backend
+        .code_lens(CodeLensParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .await
+        .unwrap()
+        .unwrap_or_default();
+    assert_eq!(lenses.len(), 1);
+
+    let resolved = backend.code_lens_resolve(lenses[0].clone()).await.unwrap();
+    let command = resolved.command.expect("lens should be resolved");
+    assert_eq!(command.title, "1 reference");
+    assert_eq!(command.command, "");
+    assert_eq!(command.arguments, None);
+}
+
+#[tokio::test]
+async fn resolve_leaves_prototype_lenses_unchanged() {
+    use tower_lsp::LanguageServer;
+
+    let backend = create_test_backend();
+    let content = r#"<?php
+class Animal {
+    public function speak(): string { return ''; }
+}
+
+class Dog extends Animal {
+    public function speak(): string { return 'woof'; }
+}
+"#;
+    let uri = "file:///test.php";
+    let lenses = get_code_lenses(&backend, uri, content);
+    assert_eq!(lenses.len(), 1);
+
+    let resolved = backend.code_lens_resolve(lenses[0].clone()).await.unwrap();
+    assert_eq!(resolved, lenses[0]);
+}
@@ -421,6 +421,34 @@ async fn test_method_return_list_array_access() {
     assert_has_member(&items, "getLabel");
 }
 
+#[tokio::test]
+async fn test_variable_from_method_return_string_keyed_array_access() {
+    // $result = $repo->byName(); $result['key']-> should resolve to Foo
+    // when the method is annotated @return array<string, Foo>.
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///test_method_string_keyed_arr.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Foo {\n",
+        "    public function getLabel(): string { return ''; }\n",
+        "}\n",
+        "class Repository {\n",
+        "    /** @return array<string, Foo> */\n",
+        "    public function byName(): array { return []; }\n",
+        "}\n",
+        "class Consumer {\n",
+        "    public function run(Repository $repo): void {\n",
+        "        $result = $repo->byName();\n",
+        "        $result['key']->\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let result = complete_at(&backend, &uri, text, 11, 24).await;
+    let items = unwrap_items(result);
+    assert_has_member(&items, "getLabel");
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Array element type extraction from generic array property annotations
 // ═══════════════════════════════════════════════════════════════════════════
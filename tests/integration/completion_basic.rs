@@ -70,6 +70,71 @@ async fn test_completion_interface_type_hint_resolves_methods() {
     }
 }
 
+#[tokio::test]
+async fn test_completion_interface_type_hint_includes_parent_interface_methods() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///iface_chain.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "interface Countable {\n",
+        "    public function count(): int;\n",
+        "}\n",
+        "interface Named {\n",
+        "    public function getName(): string;\n",
+        "}\n",
+        "interface Collection extends Countable, Named {\n",
+        "    public function first(): mixed;\n",
+        "}\n",
+        "class Service {\n",
+        "    public function run(Collection $items): void {\n",
+        "        $items->\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Cursor right after `$items->` on line 12
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 12,
+                character: 16,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    let items = match result {
+        Some(CompletionResponse::Array(items)) => items,
+        Some(CompletionResponse::List(list)) => list.items,
+        None => panic!("Expected completions for interface-typed parameter"),
+    };
+    let names: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+
+    // Own method plus methods from every extended interface.
+    for method in ["first(", "count(", "getName("] {
+        assert!(
+            names.iter().any(|n| n.starts_with(method)),
+            "Should contain '{method}', got: {:?}",
+            names
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_completion_interface_constant_via_double_colon() {
     let backend = create_test_backend();
@@ -137,6 +202,72 @@ async fn test_completion_interface_constant_via_double_colon() {
     }
 }
 
+#[tokio::test]
+async fn test_completion_interface_constants_include_parent_interfaces() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///iface_const_parent.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "interface HasCode {\n",
+        "    const CODE_DEFAULT = 0;\n",
+        "}\n",
+        "interface HasStatus extends HasCode {\n",
+        "    const STATUS_ACTIVE = 1;\n",
+        "    public function getStatus(): int;\n",
+        "}\n",
+        "class Foo {\n",
+        "    public function bar(): void {\n",
+        "        HasStatus::\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Cursor right after `HasStatus::` on line 10
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 10,
+                character: 19,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    let items = match result {
+        Some(CompletionResponse::Array(items)) => items,
+        Some(CompletionResponse::List(list)) => list.items,
+        None => panic!("Expected completions for interface constant access"),
+    };
+
+    for name in ["STATUS_ACTIVE", "CODE_DEFAULT"] {
+        let item = items
+            .iter()
+            .find(|i| i.label == name)
+            .unwrap_or_else(|| panic!("Should contain constant '{name}'"));
+        assert_eq!(item.kind, Some(CompletionItemKind::CONSTANT));
+    }
+    // Abstract instance methods are not reachable through `::`.
+    assert!(
+        !items.iter().any(|i| i.label.starts_with("getStatus")),
+        "Instance method 'getStatus' should not be offered after '::'"
+    );
+}
+
 // ─── Basic Completion Tests ─────────────────────────────────────────────────
 
 #[tokio::test]
@@ -1393,3 +1524,189 @@ async fn test_completion_no_access_operator_shows_fallback() {
     // Without `->` or `::`, no class members should be suggested
     assert!(result.is_none(), "No access operator should return None");
 }
+
+// ─── Trigger character gating ───────────────────────────────────────────────
+
+/// Open `text` and request completion at `position` as if the user had
+/// just typed `trigger`.
+async fn complete_after_trigger(
+    text: &str,
+    position: Position,
+    trigger: &str,
+) -> Option<CompletionResponse> {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///trigger.php").unwrap();
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position,
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: Some(CompletionContext {
+                trigger_kind: CompletionTriggerKind::TRIGGER_CHARACTER,
+                trigger_character: Some(trigger.to_string()),
+            }),
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_gt_trigger_outside_arrow_returns_none() {
+    let text = concat!("<?php\n", "$map = ['a' =>\n",);
+    let result = complete_after_trigger(
+        text,
+        Position {
+            line: 1,
+            character: 14,
+        },
+        ">",
+    )
+    .await;
+    assert!(result.is_none(), "`=>` should not trigger completion");
+}
+
+#[tokio::test]
+async fn test_gt_trigger_on_unresolvable_subject_returns_none() {
+    let text = concat!("<?php\n", "function f($x) {\n", "    $x->\n", "}\n",);
+    let result = complete_after_trigger(
+        text,
+        Position {
+            line: 2,
+            character: 8,
+        },
+        ">",
+    )
+    .await;
+    assert!(
+        result.is_none(),
+        "Unresolvable `->` subject should produce no completions, got: {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_dollar_trigger_offers_only_variables() {
+    let text = concat!("<?php\n", "function f(string $name) {\n", "    $\n", "}\n",);
+    let result = complete_after_trigger(
+        text,
+        Position {
+            line: 2,
+            character: 5,
+        },
+        "$",
+    )
+    .await;
+    let items = match result {
+        Some(CompletionResponse::Array(items)) => items,
+        Some(CompletionResponse::List(list)) => list.items,
+        None => panic!("Expected variable completions"),
+    };
+    assert!(
+        items.iter().any(|i| i.label == "$name"),
+        "Should offer $name, got: {:?}",
+        items.iter().map(|i| &i.label).collect::<Vec<_>>()
+    );
+    assert!(
+        items.iter().all(|i| i.label.starts_with('$')),
+        "Only variables should be offered, got: {:?}",
+        items.iter().map(|i| &i.label).collect::<Vec<_>>()
+    );
+}
+
+// ─── Incomplete Expression Tests ────────────────────────────────────────────
+
+async fn complete_labels(text: &str, line: u32, character: u32) -> Vec<String> {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///incomplete.php").unwrap();
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    let result = backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position { line, character },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    match result {
+        Some(CompletionResponse::Array(items)) => items.into_iter().map(|i| i.label).collect(),
+        Some(CompletionResponse::List(list)) => list.items.into_iter().map(|i| i.label).collect(),
+        None => Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_completion_arrow_in_unterminated_arrow_fn() {
+    let text = concat!(
+        "<?php\n",
+        "class Mailer {\n",
+        "    public function send(): bool { return true; }\n",
+        "}\n",
+        "class Job {\n",
+        "    public function handle(Mailer $mailer): void {\n",
+        "        $fn = fn() => $mailer->\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let names = complete_labels(text, 6, 31).await;
+    assert!(
+        names.iter().any(|n| n.starts_with("send(")),
+        "Should contain 'send' despite the missing semicolon, got: {:?}",
+        names
+    );
+}
+
+#[tokio::test]
+async fn test_completion_double_colon_in_unterminated_arrow_fn() {
+    let text = concat!(
+        "<?php\n",
+        "class Config {\n",
+        "    const DEFAULT = 'x';\n",
+        "    public static function load(): self { return new self(); }\n",
+        "    public function build(): void {\n",
+        "        $fn = fn() => self::\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let names = complete_labels(text, 5, 28).await;
+    assert!(
+        names.iter().any(|n| n == "DEFAULT"),
+        "Should contain constant 'DEFAULT' despite the missing semicolon, got: {:?}",
+        names
+    );
+    assert!(
+        names.iter().any(|n| n.starts_with("load(")),
+        "Should contain static method 'load' despite the missing semicolon, got: {:?}",
+        names
+    );
+}
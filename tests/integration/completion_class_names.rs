@@ -664,6 +664,48 @@ async fn test_class_name_completion_from_class_index() {
     );
 }
 
+#[tokio::test]
+async fn test_internal_class_hidden_outside_its_namespace() {
+    let backend = create_test_backend_with_stubs();
+    backend.update_ast(
+        "file:///vendor/acme/src/Helper.php",
+        concat!(
+            "<?php\n",
+            "namespace Acme\\Pkg;\n",
+            "/** @internal */\n",
+            "class HelperInternal {}\n",
+            "class HelperPublic {}\n",
+        ),
+    );
+
+    let uri = Url::parse("file:///app.php").unwrap();
+    let text = concat!("<?php\n", "namespace App;\n", "new Helper\n",);
+    let items = complete_at(&backend, &uri, text, 2, 10).await;
+    let classes = class_items(&items);
+    let class_fqns = fqns(&classes);
+    assert!(
+        class_fqns.contains(&"Acme\\Pkg\\HelperPublic"),
+        "Public class should be offered, got: {:?}",
+        class_fqns
+    );
+    assert!(
+        !class_fqns.contains(&"Acme\\Pkg\\HelperInternal"),
+        "@internal class should be hidden outside Acme\\Pkg, got: {:?}",
+        class_fqns
+    );
+
+    let uri = Url::parse("file:///pkg.php").unwrap();
+    let text = concat!("<?php\n", "namespace Acme\\Pkg;\n", "new Helper\n",);
+    let items = complete_at(&backend, &uri, text, 2, 10).await;
+    let classes = class_items(&items);
+    let class_fqns = fqns(&classes);
+    assert!(
+        class_fqns.contains(&"Acme\\Pkg\\HelperInternal"),
+        "@internal class should be offered inside Acme\\Pkg, got: {:?}",
+        class_fqns
+    );
+}
+
 // ─── Deduplication tests ────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -1601,6 +1601,139 @@ async fn test_aliased_import_resolves_in_namespace() {
     }
 }
 
+/// Open `text` at `uri` and return the method names offered at the
+/// given position.
+async fn method_names_at(
+    backend: &Backend,
+    uri: Url,
+    text: &str,
+    line: u32,
+    character: u32,
+) -> Vec<String> {
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+    let result = backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position { line, character },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+    match result {
+        Some(CompletionResponse::Array(items)) => items
+            .iter()
+            .filter(|i| i.kind == Some(CompletionItemKind::METHOD))
+            .map(|i| i.filter_text.clone().unwrap_or_else(|| i.label.clone()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Two classes share the short name `User`.  An imported name resolves
+/// through the `use` map; an unimported one resolves in the current
+/// namespace.
+#[tokio::test]
+async fn test_same_short_name_disambiguated_by_use_and_namespace() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": {
+                    "App\\": "src/",
+                    "Admin\\": "admin/"
+                }
+            }
+        }"#,
+        &[
+            (
+                "src/Models/User.php",
+                concat!(
+                    "<?php\n",
+                    "namespace App\\Models;\n",
+                    "class User {\n",
+                    "    public function appOnly(): void {}\n",
+                    "}\n",
+                ),
+            ),
+            (
+                "admin/Models/User.php",
+                concat!(
+                    "<?php\n",
+                    "namespace Admin\\Models;\n",
+                    "class User {\n",
+                    "    public function adminOnly(): void {}\n",
+                    "}\n",
+                ),
+            ),
+        ],
+    );
+
+    // Imported: `use App\Models\User;` from inside the Admin namespace.
+    let imported = method_names_at(
+        &backend,
+        Url::parse("file:///admin_imported.php").unwrap(),
+        concat!(
+            "<?php\n",
+            "namespace Admin\\Http;\n",
+            "use App\\Models\\User;\n",
+            "function show(User $user) {\n",
+            "    $user->\n",
+            "}\n",
+        ),
+        4,
+        11,
+    )
+    .await;
+    assert!(
+        imported.iter().any(|m| m == "appOnly"),
+        "Imported User should be App\\Models\\User, got {:?}",
+        imported
+    );
+    assert!(
+        !imported.iter().any(|m| m == "adminOnly"),
+        "Imported User must not resolve to Admin\\Models\\User, got {:?}",
+        imported
+    );
+
+    // Not imported: the current namespace `Admin\Models` wins.
+    let same_namespace = method_names_at(
+        &backend,
+        Url::parse("file:///admin_same_namespace.php").unwrap(),
+        concat!(
+            "<?php\n",
+            "namespace Admin\\Models;\n",
+            "function show(User $user) {\n",
+            "    $user->\n",
+            "}\n",
+        ),
+        3,
+        11,
+    )
+    .await;
+    assert!(
+        same_namespace.iter().any(|m| m == "adminOnly"),
+        "Unimported User should resolve in the current namespace, got {:?}",
+        same_namespace
+    );
+    assert!(
+        !same_namespace.iter().any(|m| m == "appOnly"),
+        "Unimported User must not resolve to App\\Models\\User, got {:?}",
+        same_namespace
+    );
+}
+
 /// Variable resolution inside a standalone function (not a class method)
 /// with a cross-file class loaded via `use` + PSR-4.
 #[tokio::test]
@@ -1929,3 +2062,160 @@ async fn test_cross_file_static_chain_variable_in_standalone_function() {
         top_methods
     );
 }
+
+/// `@internal` methods are only offered in the declaring class's namespace.
+#[tokio::test]
+async fn test_internal_method_hidden_outside_declaring_namespace() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": {
+                    "Acme\\": "src/"
+                }
+            }
+        }"#,
+        &[(
+            "src/Pkg/Client.php",
+            concat!(
+                "<?php\n",
+                "namespace Acme\\Pkg;\n",
+                "class Client {\n",
+                "    public function send(): void {}\n",
+                "    /** @internal */\n",
+                "    public function rawSocket(): void {}\n",
+                "}\n",
+            ),
+        )],
+    );
+
+    let outside = method_names_at(
+        &backend,
+        Url::parse("file:///app_client.php").unwrap(),
+        concat!(
+            "<?php\n",
+            "namespace App;\n",
+            "use Acme\\Pkg\\Client;\n",
+            "function run(Client $c) {\n",
+            "    $c->\n",
+            "}\n",
+        ),
+        4,
+        8,
+    )
+    .await;
+    assert!(
+        outside.iter().any(|m| m == "send"),
+        "Public method should be offered, got {:?}",
+        outside
+    );
+    assert!(
+        !outside.iter().any(|m| m == "rawSocket"),
+        "@internal method should be hidden outside Acme\\Pkg, got {:?}",
+        outside
+    );
+
+    let inside = method_names_at(
+        &backend,
+        Url::parse("file:///pkg_client.php").unwrap(),
+        concat!(
+            "<?php\n",
+            "namespace Acme\\Pkg;\n",
+            "function run(Client $c) {\n",
+            "    $c->\n",
+            "}\n",
+        ),
+        3,
+        8,
+    )
+    .await;
+    assert!(
+        inside.iter().any(|m| m == "rawSocket"),
+        "@internal method should be offered inside Acme\\Pkg, got {:?}",
+        inside
+    );
+}
+
+/// An inherited `@internal` method is scoped to the namespace of the
+/// parent that declares it, not to the subclass it is reached through.
+#[tokio::test]
+async fn test_internal_method_scoped_to_declaring_parent_namespace() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": {
+                    "Acme\\": "src/",
+                    "App\\": "app/"
+                }
+            }
+        }"#,
+        &[
+            (
+                "src/Pkg/Client.php",
+                concat!(
+                    "<?php\n",
+                    "namespace Acme\\Pkg;\n",
+                    "class Client {\n",
+                    "    public function send(): void {}\n",
+                    "    /** @internal */\n",
+                    "    public function rawSocket(): void {}\n",
+                    "}\n",
+                ),
+            ),
+            (
+                "app/Http/ApiClient.php",
+                concat!(
+                    "<?php\n",
+                    "namespace App\\Http;\n",
+                    "use Acme\\Pkg\\Client;\n",
+                    "class ApiClient extends Client {}\n",
+                ),
+            ),
+        ],
+    );
+
+    let in_subclass_namespace = method_names_at(
+        &backend,
+        Url::parse("file:///app_api.php").unwrap(),
+        concat!(
+            "<?php\n",
+            "namespace App\\Http;\n",
+            "function run(ApiClient $c) {\n",
+            "    $c->\n",
+            "}\n",
+        ),
+        3,
+        8,
+    )
+    .await;
+    assert!(
+        in_subclass_namespace.iter().any(|m| m == "send"),
+        "Public method should be offered, got {:?}",
+        in_subclass_namespace
+    );
+    assert!(
+        !in_subclass_namespace.iter().any(|m| m == "rawSocket"),
+        "@internal method of Acme\\Pkg should be hidden in App\\Http, got {:?}",
+        in_subclass_namespace
+    );
+
+    let in_parent_namespace = method_names_at(
+        &backend,
+        Url::parse("file:///pkg_api.php").unwrap(),
+        concat!(
+            "<?php\n",
+            "namespace Acme\\Pkg;\n",
+            "use App\\Http\\ApiClient;\n",
+            "function run(ApiClient $c) {\n",
+            "    $c->\n",
+            "}\n",
+        ),
+        4,
+        8,
+    )
+    .await;
+    assert!(
+        in_parent_namespace.iter().any(|m| m == "rawSocket"),
+        "@internal method should be offered inside Acme\\Pkg, got {:?}",
+        in_parent_namespace
+    );
+}
@@ -0,0 +1,182 @@
+use crate::common::create_test_backend_with_full_stubs;
+use phpantom_lsp::Backend;
+use tower_lsp::LanguageServer;
+use tower_lsp::lsp_types::*;
+
+// ─── Helper ─────────────────────────────────────────────────────────────────
+
+async fn complete_at(
+    backend: &Backend,
+    uri: &Url,
+    text: &str,
+    line: u32,
+    character: u32,
+) -> Vec<CompletionItem> {
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    let result = backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position { line, character },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    match result {
+        Some(CompletionResponse::Array(items)) => items,
+        Some(CompletionResponse::List(list)) => list.items,
+        None => vec![],
+    }
+}
+
+fn filter_texts(items: &[CompletionItem]) -> Vec<&str> {
+    items
+        .iter()
+        .filter_map(|i| i.filter_text.as_deref())
+        .collect()
+}
+
+// ─── Attribute form ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn column_attribute_offers_named_arguments_from_bundled_stub() {
+    let backend = create_test_backend_with_full_stubs();
+    let uri = Url::parse("file:///entity.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "namespace App\\Entity;\n",
+        "use Doctrine\\ORM\\Mapping as ORM;\n",
+        "class Product {\n",
+        "    #[ORM\\Column()]\n",
+        "    private string $name;\n",
+        "}\n",
+    );
+
+    let items = complete_at(&backend, &uri, text, 4, 17).await;
+    let names = filter_texts(&items);
+    for expected in [
+        "type",
+        "name",
+        "nullable",
+        "length",
+        "precision",
+        "scale",
+        "unique",
+    ] {
+        assert!(
+            names.contains(&expected),
+            "expected named argument {expected}, got {names:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn column_attribute_type_offers_doctrine_types() {
+    let backend = create_test_backend_with_full_stubs();
+    let uri = Url::parse("file:///entity.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "namespace App\\Entity;\n",
+        "use Doctrine\\ORM\\Mapping\\Column;\n",
+        "class Product {\n",
+        "    #[Column(nullable: true, type: 'da')]\n",
+        "    private ?\\DateTimeImmutable $createdAt;\n",
+        "}\n",
+    );
+
+    let items = complete_at(&backend, &uri, text, 4, 38).await;
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert!(labels.contains(&"datetime_immutable"), "got {labels:?}");
+    assert!(labels.contains(&"string"), "got {labels:?}");
+
+    let item = items.iter().find(|i| i.label == "datetime").unwrap();
+    assert_eq!(item.kind, Some(CompletionItemKind::VALUE));
+    let Some(CompletionTextEdit::Edit(edit)) = &item.text_edit else {
+        panic!("expected a text edit");
+    };
+    assert_eq!(edit.range.start, Position::new(4, 36));
+    assert_eq!(edit.range.end, Position::new(4, 38));
+}
+
+#[tokio::test]
+async fn unrelated_column_call_gets_no_doctrine_types() {
+    let backend = create_test_backend_with_full_stubs();
+    let uri = Url::parse("file:///entity.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "namespace App;\n",
+        "function Column(string $type) {}\n",
+        "Column(type: '');\n",
+    );
+
+    let items = complete_at(&backend, &uri, text, 3, 14).await;
+    assert!(
+        !items.iter().any(|i| i.label == "datetime"),
+        "Doctrine types leaked into an unrelated call"
+    );
+}
+
+// ─── Annotation form ────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn column_annotation_offers_argument_names() {
+    let backend = create_test_backend_with_full_stubs();
+    let uri = Url::parse("file:///entity.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "namespace App\\Entity;\n",
+        "use Doctrine\\ORM\\Mapping as ORM;\n",
+        "class Product {\n",
+        "    /**\n",
+        "     * @ORM\\Column(length=255, n\n",
+        "     */\n",
+        "    private string $name;\n",
+        "}\n",
+    );
+
+    let items = complete_at(&backend, &uri, text, 5, 32).await;
+    let inserts: Vec<&str> = items
+        .iter()
+        .filter_map(|i| i.insert_text.as_deref())
+        .collect();
+    assert!(inserts.contains(&"name="), "got {inserts:?}");
+    assert!(inserts.contains(&"nullable="), "got {inserts:?}");
+    assert!(!inserts.contains(&"length="), "already used: {inserts:?}");
+    assert!(!inserts.contains(&"type="), "prefix mismatch: {inserts:?}");
+}
+
+#[tokio::test]
+async fn column_annotation_type_offers_doctrine_types() {
+    let backend = create_test_backend_with_full_stubs();
+    let uri = Url::parse("file:///entity.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "namespace App\\Entity;\n",
+        "use Doctrine\\ORM\\Mapping as ORM;\n",
+        "class Product {\n",
+        "    /**\n",
+        "     * @ORM\\Column(type=\"\n",
+        "     */\n",
+        "    private int $stock;\n",
+        "}\n",
+    );
+
+    let items = complete_at(&backend, &uri, text, 5, 25).await;
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert!(labels.contains(&"integer"), "got {labels:?}");
+    assert!(labels.contains(&"boolean"), "got {labels:?}");
+}
@@ -1362,6 +1362,96 @@ async fn test_completion_enum_implements_interface() {
     }
 }
 
+/// Test: Members of an interface implemented by an enum (loaded from
+/// another file) are offered on the enum, including methods the enum
+/// has not declared yet and interface constants.
+#[tokio::test]
+async fn test_completion_enum_merges_cross_file_interface_members() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+        &[(
+            "src/HasLabel.php",
+            concat!(
+                "<?php\n",
+                "namespace App;\n",
+                "interface HasLabel {\n",
+                "    const PREFIX = 'label:';\n",
+                "    public function label(): string;\n",
+                "}\n",
+            ),
+        )],
+    );
+
+    let uri = Url::parse("file:///enum_cross_iface.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "namespace App;\n",
+        "enum Status implements HasLabel {\n",
+        "    case Active;\n",
+        "}\n",
+        "function show(Status $s): void {\n",
+        "    $s->\n",
+        "    Status::\n",
+        "}\n",
+    );
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    let complete = |line: u32, character: u32| {
+        let uri = uri.clone();
+        let backend = &backend;
+        async move {
+            match backend
+                .completion(CompletionParams {
+                    text_document_position: TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier { uri },
+                        position: Position { line, character },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                    context: None,
+                })
+                .await
+                .unwrap()
+            {
+                Some(CompletionResponse::Array(items)) => items
+                    .into_iter()
+                    .map(|i| i.filter_text.unwrap_or(i.label))
+                    .collect::<Vec<_>>(),
+                _ => vec![],
+            }
+        }
+    };
+
+    let instance = complete(6, 8).await;
+    assert!(
+        instance.iter().any(|n| n == "label"),
+        "Interface method should be offered on the enum instance, got: {:?}",
+        instance
+    );
+
+    let statics = complete(7, 12).await;
+    assert!(
+        statics.iter().any(|n| n == "PREFIX"),
+        "Interface constant should be offered on the enum, got: {:?}",
+        statics
+    );
+    assert!(
+        statics.iter().any(|n| n == "Active"),
+        "Enum case should still be offered, got: {:?}",
+        statics
+    );
+}
+
 // ─── Goto-definition for the enum name itself ───────────────────────────────
 
 /// Test: Clicking on an enum name (e.g., `Status` in `Status::Active`)
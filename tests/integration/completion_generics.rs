@@ -96,6 +96,87 @@ async fn test_generic_extends_resolves_return_type() {
     }
 }
 
+/// A `@return static` method on a generic parent keeps the subclass's
+/// `@extends` binding, so `UserCollection::map()` returns a
+/// `UserCollection` whose `first()` still resolves `T` to `User`.
+#[tokio::test]
+async fn test_generic_extends_static_return_keeps_binding() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///generics_static_map.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "/**\n",
+        " * @template T\n",
+        " */\n",
+        "class Collection {\n",
+        "    /** @return T */\n",
+        "    public function first() {}\n",
+        "    /** @return static */\n",
+        "    public function map(callable $fn) {}\n",
+        "}\n",
+        "\n",
+        "class User {\n",
+        "    public function getEmail(): string {}\n",
+        "}\n",
+        "\n",
+        "/**\n",
+        " * @extends Collection<User>\n",
+        " */\n",
+        "class UserCollection extends Collection {\n",
+        "    public function active(): static {}\n",
+        "}\n",
+        "\n",
+        "function test(UserCollection $users) {\n",
+        "    $users->map(fn($u) => $u)->first()->\n",
+        "}\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Line 23: `    $users->map(fn($u) => $u)->first()->`
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 23,
+                character: 40,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    assert!(result.is_some(), "Completion should return results");
+
+    match result.unwrap() {
+        CompletionResponse::Array(items) => {
+            let method_names: Vec<&str> = items
+                .iter()
+                .filter(|i| i.kind == Some(CompletionItemKind::METHOD))
+                .map(|i| i.filter_text.as_deref().unwrap_or(&i.label))
+                .collect();
+
+            assert!(
+                method_names.contains(&"getEmail"),
+                "map() should return UserCollection with T=User, got: {:?}",
+                method_names
+            );
+        }
+        _ => panic!("Expected CompletionResponse::Array"),
+    }
+}
+
 /// Test with two template parameters (like Collection<TKey, TValue>).
 #[tokio::test]
 async fn test_generic_extends_two_params_resolves() {
@@ -3403,3 +3403,240 @@ async fn test_inherited_grandparent_return_type_enables_array_element_completion
         _ => panic!("Expected CompletionResponse::Array"),
     }
 }
+
+// ─── Member origin sort order ───────────────────────────────────────────────
+
+/// Own methods sort before trait methods, which sort before methods
+/// inherited from the parent class.
+#[tokio::test]
+async fn test_own_members_sort_before_trait_and_inherited() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///sort_origin.php").unwrap();
+    let text = concat!(
+        "<?php\n",                                // 0
+        "trait Logs {\n",                         // 1
+        "    public function log(): void {}\n",   // 2
+        "}\n",                                    // 3
+        "class Base {\n",                         // 4
+        "    public function alpha(): void {}\n", // 5
+        "}\n",                                    // 6
+        "class Child extends Base {\n",           // 7
+        "    use Logs;\n",                        // 8
+        "    public function zulu(): void {}\n",  // 9
+        "    public function test(): void {\n",   // 10
+        "        $this->\n",                      // 11
+        "    }\n",                                // 12
+        "}\n",                                    // 13
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 11,
+                character: 15,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    let items = match result {
+        Some(CompletionResponse::Array(items)) => items,
+        other => panic!("Expected CompletionResponse::Array, got {:?}", other),
+    };
+
+    let sort_text_of = |name: &str| -> String {
+        items
+            .iter()
+            .find(|i| i.filter_text.as_deref() == Some(name))
+            .and_then(|i| i.sort_text.clone())
+            .unwrap_or_else(|| panic!("Missing completion for {name}"))
+    };
+
+    let own = sort_text_of("zulu");
+    let from_trait = sort_text_of("log");
+    let inherited = sort_text_of("alpha");
+
+    assert!(own.starts_with('0'), "own sort_text: {own}");
+    assert!(from_trait.starts_with('1'), "trait sort_text: {from_trait}");
+    assert!(
+        inherited.starts_with('2'),
+        "inherited sort_text: {inherited}"
+    );
+    assert!(own < from_trait && from_trait < inherited);
+}
+
+#[tokio::test]
+async fn test_unimplemented_interface_methods_are_marked_with_interface() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///contract.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "interface ContractInterface {\n",
+        "    public function handle(string $job): bool;\n",
+        "    public function describe(): string;\n",
+        "}\n",
+        "abstract class Worker implements ContractInterface {\n",
+        "    public function handle(string $job): bool { return true; }\n",
+        "    function test() {\n",
+        "        $this->\n",
+        "    }\n",
+        "}\n",
+    );
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    let result = backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position {
+                    line: 8,
+                    character: 15,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    let Some(CompletionResponse::Array(items)) = result else {
+        panic!("expected completion items");
+    };
+    let method = |name: &str| {
+        items
+            .iter()
+            .find(|i| i.filter_text.as_deref() == Some(name))
+            .unwrap_or_else(|| panic!("missing {}", name))
+    };
+
+    assert_eq!(
+        method("describe").detail.as_deref(),
+        Some("string [interface ContractInterface]")
+    );
+    // Implemented by the class itself: no interface marker.
+    assert_eq!(method("handle").detail.as_deref(), Some("bool"));
+}
+
+#[tokio::test]
+async fn test_cross_file_interface_chain_for_param_and_property() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": {
+                    "App\\": "src/"
+                }
+            }
+        }"#,
+        &[
+            (
+                "src/Contracts/ServiceInterface.php",
+                concat!(
+                    "<?php\n",
+                    "namespace App\\Contracts;\n",
+                    "interface ServiceInterface extends Bootable {\n",
+                    "    public function handle(string $job): bool;\n",
+                    "}\n",
+                ),
+            ),
+            (
+                "src/Contracts/Bootable.php",
+                concat!(
+                    "<?php\n",
+                    "namespace App\\Contracts;\n",
+                    "interface Bootable {\n",
+                    "    public function boot(): void;\n",
+                    "}\n",
+                ),
+            ),
+        ],
+    );
+
+    let uri = Url::parse("file:///runner.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "use App\\Contracts\\ServiceInterface;\n",
+        "class Runner {\n",
+        "    private ServiceInterface $fallback;\n",
+        "    public function run(ServiceInterface $service): void {\n",
+        "        $service->\n",
+        "    }\n",
+        "    public function retry(): void {\n",
+        "        $this->fallback->\n",
+        "    }\n",
+        "}\n",
+    );
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    for (line, character) in [(5, 18), (8, 25)] {
+        let result = backend
+            .completion(CompletionParams {
+                text_document_position: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri: uri.clone() },
+                    position: Position { line, character },
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+                context: None,
+            })
+            .await
+            .unwrap();
+
+        let Some(CompletionResponse::Array(items)) = result else {
+            panic!("expected completion items on line {}", line);
+        };
+        let method_names: Vec<&str> = items
+            .iter()
+            .filter(|i| i.kind == Some(CompletionItemKind::METHOD))
+            .map(|i| i.filter_text.as_deref().unwrap())
+            .collect();
+        assert!(
+            method_names.contains(&"handle"),
+            "line {}: should include 'handle' from ServiceInterface, got: {:?}",
+            line,
+            method_names
+        );
+        assert!(
+            method_names.contains(&"boot"),
+            "line {}: should include 'boot' from the extended Bootable interface, got: {:?}",
+            line,
+            method_names
+        );
+    }
+}
@@ -4126,6 +4126,43 @@ class Profile extends Model {
     );
 }
 
+#[tokio::test]
+async fn test_body_inferred_has_many_property_is_collection_of_related() {
+    let user_php = "\
+<?php
+namespace App\\Models;
+use Illuminate\\Database\\Eloquent\\Model;
+class User extends Model {
+    public function posts() { return $this->hasMany(Post::class); }
+    public function test() {
+        $user = new User();
+        $user->posts->first()->
+    }
+}
+";
+    let post_php = "\
+<?php
+namespace App\\Models;
+use Illuminate\\Database\\Eloquent\\Model;
+class Post extends Model {
+    public function getTitle(): string { return ''; }
+}
+";
+    let (backend, dir) = make_workspace(&[
+        ("src/Models/User.php", user_php),
+        ("src/Models/Post.php", post_php),
+    ]);
+
+    let items = complete_at(&backend, &dir, "src/Models/User.php", user_php, 7, 31).await;
+    let methods = method_names(&items);
+
+    assert!(
+        methods.contains(&"getTitle"),
+        "Body-inferred hasMany property should be a Collection of the related model, got: {:?}",
+        methods
+    );
+}
+
 #[tokio::test]
 async fn test_body_inferred_fqn_class_argument() {
     let user_php = "\
@@ -4825,6 +4862,45 @@ class User extends Model {
     );
 }
 
+#[tokio::test]
+async fn test_casts_marked_in_label_details() {
+    let user_php = "\
+<?php
+namespace App\\Models;
+use Illuminate\\Database\\Eloquent\\Model;
+class User extends Model {
+    protected $fillable = ['name'];
+    protected $casts = [
+        'verified_at' => 'datetime',
+    ];
+    public function test() {
+        $user = new User();
+        $user->
+    }
+}
+";
+    let (backend, dir) = make_workspace(&[("src/Models/User.php", user_php)]);
+
+    let items = complete_at(&backend, &dir, "src/Models/User.php", user_php, 10, 15).await;
+    let label_detail = |name: &str| {
+        items
+            .iter()
+            .find(|i| i.kind == Some(CompletionItemKind::PROPERTY) && i.label == name)
+            .and_then(|i| i.label_details.as_ref())
+            .and_then(|ld| ld.detail.clone())
+    };
+    assert_eq!(
+        label_detail("verified_at").as_deref(),
+        Some(" [cast]"),
+        "cast column should carry the [cast] marker"
+    );
+    assert_eq!(
+        label_detail("name"),
+        None,
+        "$fillable column is not a cast and should not be marked"
+    );
+}
+
 #[tokio::test]
 async fn test_casts_integer_and_float() {
     let user_php = "\
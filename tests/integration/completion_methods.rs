@@ -829,3 +829,91 @@ class Consumer {
         doc
     );
 }
+
+#[tokio::test]
+async fn test_completion_resolve_reflects_edited_docblock() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///resolve_cache.php").unwrap();
+    let source = |summary: &str| {
+        format!(
+            "<?php\nclass Lamp {{\n    /** {summary} */\n    public function dim(): void {{}}\n}}\n$lamp = new Lamp();\n$lamp->\n"
+        )
+    };
+
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: source("Lower the brightness."),
+            },
+        })
+        .await;
+
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position {
+                line: 6,
+                character: 7,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let resolve_dim = |response: Option<CompletionResponse>| {
+        let items = match response {
+            Some(CompletionResponse::Array(items)) => items,
+            Some(CompletionResponse::List(list)) => list.items,
+            None => panic!("Expected completion results"),
+        };
+        items
+            .into_iter()
+            .find(|i| i.filter_text.as_deref() == Some("dim"))
+            .expect("Should have dim in completions")
+    };
+
+    let dim = resolve_dim(backend.completion(completion_params.clone()).await.unwrap());
+    let first = backend.completion_resolve(dim.clone()).await.unwrap();
+    let again = backend.completion_resolve(dim).await.unwrap();
+    assert_eq!(first.documentation, again.documentation);
+    match first.documentation {
+        Some(Documentation::MarkupContent(mc)) => assert!(
+            mc.value.contains("Lower the brightness."),
+            "got: {}",
+            mc.value
+        ),
+        other => panic!("Expected MarkupContent documentation, got: {:?}", other),
+    }
+
+    backend
+        .did_change(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version: 2,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: source("Dim to half brightness."),
+            }],
+        })
+        .await;
+
+    let dim = resolve_dim(backend.completion(completion_params).await.unwrap());
+    let resolved = backend.completion_resolve(dim).await.unwrap();
+    match resolved.documentation {
+        Some(Documentation::MarkupContent(mc)) => {
+            assert!(
+                mc.value.contains("Dim to half brightness."),
+                "resolve should not serve documentation from before the edit, got: {}",
+                mc.value
+            );
+        }
+        other => panic!("Expected MarkupContent documentation, got: {:?}", other),
+    }
+}
@@ -599,3 +599,41 @@ namespace B {
         labels
     );
 }
+
+/// Each class in a multi-namespace file is indexed under the namespace
+/// block that contains it, including a trailing global `namespace { }`
+/// block.
+#[test]
+fn test_class_index_uses_per_block_namespace() {
+    let backend = create_test_backend();
+
+    let uri = "file:///multi_ns_index.php";
+    let text = r#"<?php
+namespace A {
+    class Foo {}
+}
+namespace B\C {
+    class Foo {}
+    interface Bar {}
+}
+namespace {
+    class GlobalThing {}
+}
+"#;
+    backend.update_ast(uri, text);
+
+    let idx = backend.class_index().read();
+    for fqn in ["A\\Foo", "B\\C\\Foo", "B\\C\\Bar", "GlobalThing"] {
+        assert_eq!(
+            idx.get(fqn).map(String::as_str),
+            Some(uri),
+            "Expected {fqn} in class_index, got: {:?}",
+            idx.keys().collect::<Vec<_>>()
+        );
+    }
+    assert!(
+        !idx.contains_key("A\\GlobalThing") && !idx.contains_key("A\\Bar"),
+        "Classes must not inherit the first block's namespace, got: {:?}",
+        idx.keys().collect::<Vec<_>>()
+    );
+}
@@ -425,6 +425,73 @@ async fn test_object_shape_value_type_chain_resolution() {
     }
 }
 
+/// Object shape property chained directly off the method call:
+/// `$h->process()->user->` should resolve to User members.
+#[tokio::test]
+async fn test_object_shape_method_call_chain_resolution() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///object_shape_call_chain.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class User {\n",
+        "    public string $name;\n",
+        "    public function getEmail(): string {}\n",
+        "}\n",
+        "class Handler {\n",
+        "    /**\n",
+        "     * @return object{name: string, user: User}\n",
+        "     */\n",
+        "    public function objectShapeMethod(): object {\n",
+        "        return (object) [];\n",
+        "    }\n",
+        "}\n",
+        "$h = new Handler();\n",
+        "$h->objectShapeMethod()->user->\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Cursor right after `$h->objectShapeMethod()->user->` (line 14, char 31)
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 14,
+                character: 31,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    match result {
+        Some(CompletionResponse::Array(items)) => {
+            let method_names: Vec<&str> = items
+                .iter()
+                .filter(|i| i.kind == Some(CompletionItemKind::METHOD))
+                .map(|i| i.filter_text.as_deref().unwrap_or(&i.label))
+                .collect();
+            assert!(
+                method_names.contains(&"getEmail"),
+                "Should suggest User::getEmail(), got {:?}",
+                method_names
+            );
+        }
+        other => panic!("Expected CompletionResponse::Array, got {:?}", other),
+    }
+}
+
 /// Object shape via inline @var annotation on a variable.
 #[tokio::test]
 async fn test_object_shape_var_annotation() {
@@ -270,8 +270,8 @@ async fn test_completion_constant_detail_with_type_hint() {
             let label_const = constants.iter().find(|c| c.label == "LABEL").unwrap();
             assert_eq!(
                 label_const.detail.as_deref(),
-                Some("'hello'"),
-                "LABEL detail should show the value, got: {:?}",
+                Some("string = 'hello'"),
+                "LABEL detail should show the type hint and value, got: {:?}",
                 label_const.detail
             );
 
@@ -1854,6 +1854,90 @@ class Controller {
     }
 }
 
+/// A native-typed private property whose class lives in another PSR-4
+/// file: `$this->mailer->` must load `Mailer` through the class loader
+/// rather than only searching the current file's classes.
+#[tokio::test]
+async fn test_completion_this_native_property_type_cross_file() {
+    let composer_json = r#"{
+        "autoload": {
+            "psr-4": {
+                "App\\": "src/"
+            }
+        }
+    }"#;
+
+    let mailer_php = "\
+<?php
+namespace App\\Mail;
+class Mailer {
+    public function send(string $to): bool { return true; }
+    public function queue(string $to): void {}
+}
+";
+
+    let service_php = "\
+<?php
+namespace App\\Services;
+use App\\Mail\\Mailer;
+class Notifier {
+    private Mailer $mailer;
+    public function notify(): void {
+        $this->mailer->
+    }
+}
+";
+
+    let files: Vec<(&str, &str)> = vec![
+        ("src/Mail/Mailer.php", mailer_php),
+        ("src/Services/Notifier.php", service_php),
+    ];
+
+    let (backend, _dir) = create_psr4_workspace(composer_json, &files);
+
+    let uri = Url::from_file_path(_dir.path().join("src/Services/Notifier.php")).unwrap();
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: service_php.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Cursor right after `$this->mailer->` on line 6
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 6,
+                character: 23,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    match result {
+        Some(CompletionResponse::Array(items)) => {
+            let names: Vec<&str> = items
+                .iter()
+                .filter(|i| i.kind == Some(CompletionItemKind::METHOD))
+                .map(|i| i.filter_text.as_deref().unwrap_or(&i.label))
+                .collect();
+            assert!(
+                names.contains(&"send") && names.contains(&"queue"),
+                "Should offer Mailer members from the other file, got: {:?}",
+                names
+            );
+        }
+        other => panic!("Expected CompletionResponse::Array, got: {:?}", other),
+    }
+}
+
 /// Cross-file variant where `@property` uses a SHORT class name (`Carbon`)
 /// that is imported via `use Carbon\Carbon` in the model file but NOT in the
 /// consuming controller file.  The type resolution for the property chain
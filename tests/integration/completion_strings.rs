@@ -339,6 +339,43 @@ async fn completion_works_with_nullsafe_simple_interpolation() {
     );
 }
 
+#[tokio::test]
+async fn completion_works_after_method_call_in_brace_interpolation() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///str_double_brace_chain.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Owner {\n",
+        "    public string $email = '';\n",
+        "    public function notify(): void {}\n",
+        "}\n",
+        "class Foo {\n",
+        "    public function owner(): Owner { return new Owner(); }\n",
+        "}\n",
+        "$f = new Foo();\n",
+        "$s = \"mail {$f->owner()->}\";\n",
+    );
+
+    // Cursor right after `$f->owner()->` — line 9, col 25
+    let items = complete_at_raw(&backend, &uri, text, 9, 25)
+        .await
+        .expect("Brace interpolation after a method call should return items");
+    assert!(
+        items
+            .iter()
+            .any(|i| i.label == "email" && i.kind == Some(CompletionItemKind::PROPERTY)),
+        "Should suggest 'email' as a property. Got: {:?}",
+        items.iter().map(|i| &i.label).collect::<Vec<_>>()
+    );
+    assert!(
+        items
+            .iter()
+            .any(|i| i.label.starts_with("notify") && i.kind == Some(CompletionItemKind::METHOD)),
+        "Should suggest 'notify' as a method. Got: {:?}",
+        items.iter().map(|i| &i.label).collect::<Vec<_>>()
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Heredoc — suppress plain text, allow interpolation
 // ═══════════════════════════════════════════════════════════════════════════
@@ -955,6 +992,97 @@ async fn multiple_strings_on_same_line() {
     );
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+//  Format strings — offer `printf` specifiers
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn format_specifiers_inside_sprintf_format_string() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///str_sprintf_format.php").unwrap();
+    let text = concat!("<?php\n", "$s = sprintf('Total: %', $n);\n",);
+
+    // Cursor right after `%` — line 1, col 22
+    let items = complete_at_raw(&backend, &uri, text, 1, 22)
+        .await
+        .expect("Format string should offer specifiers");
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert!(
+        labels.contains(&"%s") && labels.contains(&"%d") && labels.contains(&"%%"),
+        "Should offer format specifiers. Got: {:?}",
+        labels
+    );
+    assert!(
+        items
+            .iter()
+            .all(|i| i.kind == Some(CompletionItemKind::SNIPPET)),
+        "Specifiers should be snippet items"
+    );
+}
+
+#[tokio::test]
+async fn no_format_specifiers_in_plain_string() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///str_plain_percent.php").unwrap();
+    let text = concat!("<?php\n", "$s = strtoupper('100%');\n",);
+
+    // Cursor right after `%` — line 1, col 21
+    let result = complete_at_raw(&backend, &uri, text, 1, 21).await;
+    assert!(
+        result.is_none(),
+        "Should not offer specifiers outside a format string, got: {:?}",
+        result
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+//  WordPress hook names — offer core hooks
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn hook_names_inside_add_action_first_argument() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///str_add_action.php").unwrap();
+    let text = concat!("<?php\n", "add_action('wp_', 'my_callback');\n",);
+
+    // Cursor after `wp_` — line 1, col 15
+    let items = complete_at_raw(&backend, &uri, text, 1, 15)
+        .await
+        .expect("Hook name string should offer hook names");
+    let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+    assert!(
+        labels.contains(&"wp_enqueue_scripts") && labels.contains(&"init"),
+        "Should offer action hooks. Got: {:?}",
+        labels
+    );
+    assert!(
+        !labels.contains(&"the_content"),
+        "Should not offer filter hooks for add_action. Got: {:?}",
+        labels
+    );
+    assert!(
+        items
+            .iter()
+            .all(|i| i.kind == Some(CompletionItemKind::VALUE)),
+        "Hook names should be value items"
+    );
+}
+
+#[tokio::test]
+async fn no_hook_names_in_add_filter_callback_argument() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///str_add_filter_callback.php").unwrap();
+    let text = concat!("<?php\n", "add_filter('the_content', 'my_');\n",);
+
+    // Cursor after `my_` in the callback string — line 1, col 30
+    let result = complete_at_raw(&backend, &uri, text, 1, 30).await;
+    assert!(
+        result.is_none(),
+        "Should not offer hook names outside the hook name argument, got: {:?}",
+        result
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Unit tests for classify_string_context
 // ═══════════════════════════════════════════════════════════════════════════
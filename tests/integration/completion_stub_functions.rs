@@ -1209,6 +1209,45 @@ async fn test_removed_function_filtered_out() {
     );
 }
 
+/// Verify that a stub filtered out by `@removed` comes back when the
+/// PHP version is changed to one that still has it.
+#[tokio::test]
+async fn test_removed_function_restored_when_version_changes() {
+    let stub_content: &'static str = concat!(
+        "<?php\n",
+        "/**\n",
+        " * @return string\n",
+        " * @removed 7.0\n",
+        " */\n",
+        "function mysql_tablename($result, $i) {}\n",
+    );
+
+    let mut function_stubs: std::collections::HashMap<&'static str, &'static str> =
+        std::collections::HashMap::new();
+    function_stubs.insert("mysql_tablename", stub_content);
+
+    let backend = phpantom_lsp::Backend::new_test_with_all_stubs(
+        std::collections::HashMap::new(),
+        function_stubs,
+        std::collections::HashMap::new(),
+    );
+    assert!(
+        backend
+            .find_or_load_function(&["mysql_tablename"])
+            .is_none(),
+        "mysql_tablename (@removed 7.0) should be filtered out for PHP 8.5"
+    );
+
+    backend.set_php_version(phpantom_lsp::types::PhpVersion::new(8, 4));
+    backend.set_php_version(phpantom_lsp::types::PhpVersion::new(5, 6));
+    assert!(
+        backend
+            .find_or_load_function(&["mysql_tablename"])
+            .is_some(),
+        "mysql_tablename should be available again for PHP 5.6"
+    );
+}
+
 /// Verify that `@removed` does not filter out functions when the target
 /// PHP version is older than the removal version.
 ///
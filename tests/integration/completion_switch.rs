@@ -801,3 +801,77 @@ async fn test_completion_switch_nested_in_if() {
         _ => panic!("Expected CompletionResponse::Array"),
     }
 }
+
+/// Inside a `case Enum::Case:` branch, the switch subject should be
+/// narrowed to the enum named by the case label.
+#[tokio::test]
+async fn test_completion_switch_case_narrows_enum_union() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///switch_enum_narrowing.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "enum Status: string {\n",
+        "    case Active = 'active';\n",
+        "    public function label(): string { return ''; }\n",
+        "}\n",
+        "enum Suit {\n",
+        "    case Hearts;\n",
+        "    public function color(): string { return ''; }\n",
+        "}\n",
+        "function test(Status|Suit $value): void {\n",
+        "    switch ($value) {\n",
+        "        case Status::Active:\n",
+        "            $value->\n",
+        "            break;\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Cursor after `$value->` on line 12
+    let params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 12,
+                character: 20,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(params).await.unwrap();
+    assert!(
+        result.is_some(),
+        "Completion should return results for $value-> inside switch case"
+    );
+
+    match result.unwrap() {
+        CompletionResponse::Array(items) => {
+            let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+            assert!(
+                labels.iter().any(|l| l.starts_with("label")),
+                "Should include label from Status, got: {:?}",
+                labels
+            );
+            assert!(
+                !labels.iter().any(|l| l.starts_with("color")),
+                "Should not include color from Suit, got: {:?}",
+                labels
+            );
+        }
+        _ => panic!("Expected CompletionResponse::Array"),
+    }
+}
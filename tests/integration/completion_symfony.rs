@@ -0,0 +1,154 @@
+use crate::common::create_psr4_workspace;
+use phpantom_lsp::Backend;
+use tower_lsp::LanguageServer;
+use tower_lsp::lsp_types::*;
+
+// ─── Symfony container service resolution ───────────────────────────────────
+
+const COMPOSER_JSON: &str = r#"{
+    "autoload": {
+        "psr-4": {
+            "App\\": "src/",
+            "Psr\\Container\\": "lib/psr-container/"
+        }
+    }
+}"#;
+
+const CONTAINER_INTERFACE: &str = concat!(
+    "<?php\n",
+    "namespace Psr\\Container;\n",
+    "interface ContainerInterface {\n",
+    "    /** @return mixed */\n",
+    "    public function get(string $id);\n",
+    "    public function has(string $id): bool;\n",
+    "}\n",
+);
+
+const MAILER: &str = concat!(
+    "<?php\n",
+    "namespace App\\Mail;\n",
+    "class Mailer {\n",
+    "    public function send(string $to): void {}\n",
+    "}\n",
+);
+
+const SERVICES_YAML: &str = concat!(
+    "services:\n",
+    "    _defaults:\n",
+    "        autowire: true\n",
+    "\n",
+    "    app.mailer:\n",
+    "        class: App\\Mail\\Mailer\n",
+    "\n",
+    "    mailer: '@app.mailer'\n",
+);
+
+fn make_workspace() -> (Backend, tempfile::TempDir) {
+    create_psr4_workspace(
+        COMPOSER_JSON,
+        &[
+            (
+                "lib/psr-container/ContainerInterface.php",
+                CONTAINER_INTERFACE,
+            ),
+            ("src/Mail/Mailer.php", MAILER),
+            ("config/services.yaml", SERVICES_YAML),
+        ],
+    )
+}
+
+async fn complete_labels(backend: &Backend, text: &str, line: u32, character: u32) -> Vec<String> {
+    let uri = Url::parse("file:///controller.php").unwrap();
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: text.to_string(),
+            },
+        })
+        .await;
+
+    let result = backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position { line, character },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    match result {
+        Some(CompletionResponse::Array(items)) => items.into_iter().map(|i| i.label).collect(),
+        Some(CompletionResponse::List(list)) => list.items.into_iter().map(|i| i.label).collect(),
+        None => vec![],
+    }
+}
+
+/// `$container->get('app.mailer')` resolves through the `class:` key
+/// in `config/services.yaml`.
+#[tokio::test]
+async fn test_container_get_service_id_uses_services_yaml() {
+    let (backend, _dir) = make_workspace();
+    let text = concat!(
+        "<?php\n",
+        "use Psr\\Container\\ContainerInterface;\n",
+        "function handle(ContainerInterface $container): void {\n",
+        "    $container->get('app.mailer')->\n",
+        "}\n",
+    );
+
+    let labels = complete_labels(&backend, text, 3, 35).await;
+    assert!(
+        labels.iter().any(|l| l.starts_with("send")),
+        "Should include send from Mailer, got: {:?}",
+        labels
+    );
+}
+
+/// An alias (`mailer: '@app.mailer'`) resolves to the aliased service's
+/// class when the result is assigned to a variable.
+#[tokio::test]
+async fn test_container_get_alias_assigned_to_variable() {
+    let (backend, _dir) = make_workspace();
+    let text = concat!(
+        "<?php\n",
+        "use Psr\\Container\\ContainerInterface;\n",
+        "function handle(ContainerInterface $container): void {\n",
+        "    $mailer = $container->get('mailer');\n",
+        "    $mailer->\n",
+        "}\n",
+    );
+
+    let labels = complete_labels(&backend, text, 4, 13).await;
+    assert!(
+        labels.iter().any(|l| l.starts_with("send")),
+        "Should include send from Mailer, got: {:?}",
+        labels
+    );
+}
+
+/// A class-named service ID needs no definition: the ID is the class.
+#[tokio::test]
+async fn test_container_get_class_named_service_id() {
+    let (backend, _dir) = make_workspace();
+    let text = concat!(
+        "<?php\n",
+        "use Psr\\Container\\ContainerInterface;\n",
+        "function handle(ContainerInterface $container): void {\n",
+        "    $container->get('App\\\\Mail\\\\Mailer')->\n",
+        "}\n",
+    );
+
+    let labels = complete_labels(&backend, text, 3, 42).await;
+    assert!(
+        labels.iter().any(|l| l.starts_with("send")),
+        "Should include send from Mailer, got: {:?}",
+        labels
+    );
+}
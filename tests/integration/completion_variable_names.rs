@@ -903,6 +903,37 @@ async fn test_completion_argc_argv() {
     );
 }
 
+/// `$argc` and `$argv` are only offered in script files, not in files
+/// that declare classes.
+#[tokio::test]
+async fn test_completion_argc_argv_not_offered_in_class_file() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///var_cli_class.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Command {\n",
+        "    public function run() {\n",
+        "        $arg\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let items = complete_at(&backend, &uri, text, 3, 12).await;
+
+    let var_labels: Vec<&str> = items
+        .iter()
+        .filter(|i| i.kind == Some(CompletionItemKind::VARIABLE))
+        .map(|i| i.label.as_str())
+        .collect();
+
+    assert!(
+        !var_labels.contains(&"$argc") && !var_labels.contains(&"$argv"),
+        "Should not suggest CLI globals in a class file. Got: {:?}",
+        var_labels
+    );
+}
+
 /// Variable completion should work inside an if block.
 #[tokio::test]
 async fn test_completion_variable_inside_if_block() {
@@ -13105,6 +13105,73 @@ async fn test_chained_method_call_new_no_parens_then_method() {
     }
 }
 
+#[tokio::test]
+async fn test_chained_method_call_new_no_parens_multi_step_chain() {
+    // new Builder()->where()->get()-> — PHP 8.4 unparenthesised `new`
+    // followed by a fluent chain, completed directly at the cursor.
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///chain_new_no_parens_multi.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Collection {\n",
+        "    public function first(): mixed { return null; }\n",
+        "    public function count(): int { return 0; }\n",
+        "}\n",
+        "\n",
+        "class Builder {\n",
+        "    public function where(string $column, mixed $value): static { return $this; }\n",
+        "    public function get(): Collection { return new Collection(); }\n",
+        "}\n",
+        "\n",
+        "function run(): void {\n",
+        "    new Builder()->where('active', true)->get()->\n",
+        "}\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // Cursor right after the final `->` on line 12
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 12,
+                character: 49,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    match result {
+        Some(CompletionResponse::Array(items)) => {
+            let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+            assert!(
+                labels.iter().any(|l| l.starts_with("first")),
+                "Should include first from Collection via new Builder()->where()->get(), got: {:?}",
+                labels
+            );
+            assert!(
+                !labels.iter().any(|l| l.starts_with("where")),
+                "Builder members should not leak past get(), got: {:?}",
+                labels
+            );
+        }
+        other => panic!("Expected CompletionResponse::Array, got: {:?}", other),
+    }
+}
+
 #[tokio::test]
 async fn test_chained_method_call_extract_raw_type_chain() {
     // Verifies that extract_raw_type_from_assignment_text handles chained
@@ -20768,6 +20835,67 @@ async fn test_completion_foreach_variadic_param_class_type() {
     }
 }
 
+/// Foreach over a variadic parameter documented as `@param User[] ...$users`
+/// resolves the loop variable to `User`, not `User[]`.
+#[tokio::test]
+async fn test_completion_foreach_variadic_param_docblock_array_type() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///variadic_docblock_foreach.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class User {\n",
+        "    public function getEmail(): string { return ''; }\n",
+        "}\n",
+        "class Mailer {\n",
+        "    /**\n",
+        "     * @param User[] ...$users\n",
+        "     */\n",
+        "    public function send(...$users): void {\n",
+        "        foreach ($users as $user) {\n",
+        "            $user->\n",
+        "        }\n",
+        "    }\n",
+        "}\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    let completion_params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position {
+                line: 10,
+                character: 19,
+            },
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+
+    let result = backend.completion(completion_params).await.unwrap();
+    match result {
+        Some(CompletionResponse::Array(items)) => {
+            let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+            assert!(
+                labels.iter().any(|l| l.starts_with("getEmail")),
+                "Should include getEmail method from User, got: {:?}",
+                labels
+            );
+        }
+        other => panic!("Expected CompletionResponse::Array, got {:?}", other),
+    }
+}
+
 /// Foreach over a variadic parameter with a union type containing a
 /// class resolves the loop variable so that instanceof narrowing can
 /// work on it.
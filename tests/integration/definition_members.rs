@@ -3696,6 +3696,76 @@ async fn test_goto_definition_trait_alias_when_class_overrides_original_method()
     }
 }
 
+/// `TraitA::shared insteadof TraitB` must win even when `TraitB` is
+/// listed first in the `use` statement, and a qualified alias
+/// `TraitB::shared as sharedFromB` must jump to the losing trait.
+#[tokio::test]
+async fn test_goto_definition_method_call_honours_insteadof_and_alias() {
+    let backend = create_test_backend();
+
+    let uri = Url::parse("file:///trait_insteadof_call.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "trait TraitB {\n",
+        "    public function shared(): string { return 'b'; }\n",
+        "}\n",
+        "trait TraitA {\n",
+        "    public function shared(): string { return 'a'; }\n",
+        "}\n",
+        "class Widget {\n",
+        "    use TraitB, TraitA {\n",
+        "        TraitA::shared insteadof TraitB;\n",
+        "        TraitB::shared as sharedFromB;\n",
+        "    }\n",
+        "}\n",
+        "$w = new Widget();\n",
+        "$w->shared();\n",
+        "$w->sharedFromB();\n",
+    );
+
+    let open_params = DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: uri.clone(),
+            language_id: "php".to_string(),
+            version: 1,
+            text: text.to_string(),
+        },
+    };
+    backend.did_open(open_params).await;
+
+    // (call line, expected declaration line)
+    for (line, expected) in [(14u32, 5u32), (15, 2)] {
+        let col = text
+            .lines()
+            .nth(line as usize)
+            .unwrap()
+            .find("shared")
+            .unwrap();
+        let params = GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line,
+                    character: col as u32 + 1,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        let result = backend.goto_definition(params).await.unwrap();
+        match result {
+            Some(GotoDefinitionResponse::Scalar(location)) => {
+                assert_eq!(
+                    location.range.start.line, expected,
+                    "Call on line {line} should jump to line {expected}"
+                );
+            }
+            other => panic!("Expected Scalar location for line {line}, got: {:?}", other),
+        }
+    }
+}
+
 /// Clicking on the alias name in `foo as __foo` when the class also
 /// declares its own `foo()` should jump to the trait's `foo()` method.
 #[tokio::test]
@@ -402,6 +402,8 @@ function test(): void {
 #[test]
 fn diagnostic_has_correct_code_and_severity() {
     let php = r#"<?php
+declare(strict_types=1);
+
 function takes_int(int $x): void {}
 
 function test(): void {
@@ -425,7 +427,7 @@ function test(): void {
     assert_eq!(
         type_diags[0].severity,
         Some(DiagnosticSeverity::ERROR),
-        "Type error should be ERROR severity"
+        "Type error in a strict_types file should be ERROR severity"
     );
     assert_eq!(
         type_diags[0].source.as_deref(),
@@ -434,6 +436,65 @@ function test(): void {
     );
 }
 
+#[test]
+fn type_error_is_warning_without_strict_types() {
+    let php = r#"<?php
+function takes_int(int $x): void {}
+
+function test(): void {
+    $s = "hello";
+    takes_int($s);
+}
+"#;
+    let diags = collect(php);
+    let type_diags: Vec<_> = diags
+        .iter()
+        .filter(|d| {
+            d.code.as_ref().is_some_and(
+                |c| matches!(c, NumberOrString::String(s) if s == "type_mismatch_argument"),
+            )
+        })
+        .collect();
+    assert!(
+        !type_diags.is_empty(),
+        "Expected at least one type error diagnostic"
+    );
+    assert_eq!(
+        type_diags[0].severity,
+        Some(DiagnosticSeverity::WARNING),
+        "Type error in a coercive-mode file should be WARNING severity"
+    );
+}
+
+#[test]
+fn class_mismatch_is_error_without_strict_types() {
+    let php = r#"<?php
+class Apple {}
+class Pear {}
+
+function eat(Apple $fruit): void {}
+
+function test(): void {
+    eat(new Pear());
+}
+"#;
+    let diags = collect(php);
+    let type_diags: Vec<_> = diags
+        .iter()
+        .filter(|d| {
+            d.code.as_ref().is_some_and(
+                |c| matches!(c, NumberOrString::String(s) if s == "type_mismatch_argument"),
+            )
+        })
+        .collect();
+    assert_eq!(type_diags.len(), 1, "Expected one type error diagnostic");
+    assert_eq!(
+        type_diags[0].severity,
+        Some(DiagnosticSeverity::ERROR),
+        "Coercive mode never converts objects, so a class mismatch stays an ERROR"
+    );
+}
+
 // ─── Method calls: flags wrong type to method parameter ─────────────────────
 
 #[test]
@@ -0,0 +1,74 @@
+use crate::common::create_test_backend;
+use tower_lsp::lsp_types::*;
+
+fn range(line: u32, start: u32, end: u32) -> Range {
+    Range {
+        start: Position {
+            line,
+            character: start,
+        },
+        end: Position {
+            line,
+            character: end,
+        },
+    }
+}
+
+#[test]
+fn colors_in_string_literals() {
+    let backend = create_test_backend();
+    let uri = "file:///colors.php";
+    let php = r##"<?php
+$theme = ['primary' => '#3366ff', 'overlay' => "rgba(0, 0, 0, 0.5)"];
+// '#ff0000' in a comment is not a string literal
+echo "#00ff00 {$theme['primary']}";
+$border = 'border: 1px solid #abc;';
+"##;
+    backend.update_ast(uri, php);
+
+    let colors = backend.handle_document_color(uri, php).unwrap();
+    let ranges: Vec<Range> = colors.iter().map(|c| c.range).collect();
+    assert_eq!(
+        ranges,
+        vec![range(1, 24, 31), range(1, 48, 66), range(4, 29, 33)],
+        "interpolated strings and comments should be skipped"
+    );
+
+    let primary = colors[0].color;
+    assert_eq!(primary.red, 0x33 as f32 / 255.0);
+    assert_eq!(primary.green, 0x66 as f32 / 255.0);
+    assert_eq!(primary.blue, 1.0);
+    assert_eq!(primary.alpha, 1.0);
+    assert_eq!(colors[1].color.alpha, 0.5);
+}
+
+#[test]
+fn color_presentation_keeps_existing_notation_first() {
+    let backend = create_test_backend();
+    let php = "<?php\n$a = '#ff0000';\n$b = 'rgb(255, 0, 0)';\n";
+
+    let green = Color {
+        red: 0.0,
+        green: 1.0,
+        blue: 0.0,
+        alpha: 1.0,
+    };
+
+    let hex = backend.handle_color_presentation(php, green, range(1, 6, 13));
+    let labels: Vec<&str> = hex.iter().map(|p| p.label.as_str()).collect();
+    assert_eq!(labels, vec!["#00ff00", "rgb(0, 255, 0)"]);
+    let edit = hex[0].text_edit.as_ref().unwrap();
+    assert_eq!(edit.range, range(1, 6, 13));
+    assert_eq!(edit.new_text, "#00ff00");
+
+    let rgb = backend.handle_color_presentation(
+        php,
+        Color {
+            alpha: 0.25,
+            ..green
+        },
+        range(2, 6, 20),
+    );
+    let labels: Vec<&str> = rgb.iter().map(|p| p.label.as_str()).collect();
+    assert_eq!(labels, vec!["rgba(0, 255, 0, 0.25)", "#00ff0040"]);
+}
@@ -558,3 +558,105 @@ fn double_quoted_include_path() {
         link_targets(&links)
     );
 }
+
+// ─── Use Import and Docblock Links ──────────────────────────────────────────
+
+#[test]
+fn use_import_and_see_links_target_class_file() {
+    let (backend, dir) = crate::common::create_psr4_workspace(
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+        &[(
+            "src/Models/User.php",
+            "<?php\nnamespace App\\Models;\nclass User {}\n",
+        )],
+    );
+
+    let content = "<?php\nnamespace App;\nuse App\\Models\\User;\n/**\n * @see User::find()\n * @link https://example.com/users\n */\nfunction users() {}\n";
+    let uri = format!("file://{}", dir.path().join("src/users.php").display());
+    let links = get_document_links(&backend, &uri, content);
+    let targets = link_targets(&links);
+
+    let user_targets = targets
+        .iter()
+        .filter(|t| t.ends_with("src/Models/User.php"))
+        .count();
+    assert_eq!(
+        user_targets, 2,
+        "Expected the use import and @see to link to User.php, got: {:?}",
+        targets
+    );
+    assert!(
+        targets.contains(&"https://example.com/users".to_string()),
+        "Expected the @link URL, got: {:?}",
+        targets
+    );
+
+    let use_link = links
+        .iter()
+        .find(|l| l.range.start.line == 2)
+        .expect("Expected a link on the use line");
+    assert_eq!(use_link.range.start.character, 4);
+    assert_eq!(use_link.range.end.character, 19);
+}
+
+#[test]
+fn use_import_of_unknown_class_has_no_link() {
+    let backend = create_test_backend();
+    let content = "<?php\nuse App\\Missing\\Thing;\n";
+    let links = get_document_links(&backend, "file:///test.php", content);
+    assert!(
+        links.is_empty(),
+        "Expected no links for an unresolvable import, got: {:?}",
+        link_targets(&links)
+    );
+}
+
+#[test]
+fn bare_see_class_links_via_namespace_and_fqn() {
+    let (backend, dir) = crate::common::create_psr4_workspace(
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+        &[
+            ("src/Mailer.php", "<?php\nnamespace App;\nclass Mailer {}\n"),
+            (
+                "src/Models/User.php",
+                "<?php\nnamespace App\\Models;\nclass User {}\n",
+            ),
+        ],
+    );
+
+    let content = "<?php\nnamespace App;\n/**\n * @see Mailer\n * @see \\App\\Models\\User Related model.\n * @see Missing\n */\nfunction notify() {}\n";
+    let uri = format!("file://{}", dir.path().join("src/notify.php").display());
+    let links = get_document_links(&backend, &uri, content);
+    let targets = link_targets(&links);
+
+    assert_eq!(
+        links.len(),
+        2,
+        "Expected links for the two resolvable @see targets only, got: {:?}",
+        targets
+    );
+
+    let mailer = links
+        .iter()
+        .find(|l| l.range.start.line == 3)
+        .expect("Expected a link for `@see Mailer`");
+    assert!(
+        mailer
+            .target
+            .as_ref()
+            .is_some_and(|t| t.as_str().ends_with("src/Mailer.php"))
+    );
+    assert_eq!(mailer.range.start.character, 8);
+    assert_eq!(mailer.range.end.character, 14);
+
+    let user = links
+        .iter()
+        .find(|l| l.range.start.line == 4)
+        .expect("Expected a link for the fully-qualified `@see`");
+    assert!(
+        user.target
+            .as_ref()
+            .is_some_and(|t| t.as_str().ends_with("src/Models/User.php"))
+    );
+    assert_eq!(user.range.end.character, 24);
+}
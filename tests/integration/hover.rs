@@ -286,6 +286,114 @@ class Consumer {
     );
 }
 
+#[test]
+fn hover_switch_subject_inside_case_shows_narrowed_literal() {
+    let backend = create_test_backend();
+    let uri = "file:///test.php";
+    let content = r#"<?php
+/**
+ * @param 'active'|'inactive'|'banned' $status
+ */
+function describe(string $status): void {
+    switch ($status) {
+        case 'active':
+            echo $status;
+            break;
+        case 'inactive':
+        case 'banned':
+            echo $status;
+            break;
+    }
+}
+"#;
+
+    // Hover on `$status` inside the `'active'` case (line 7)
+    let hover = hover_at(&backend, uri, content, 7, 18).expect("expected hover in first case");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("'active'") && !text.contains("'inactive'"),
+        "inside case 'active', should narrow to 'active': {}",
+        text
+    );
+
+    // Hover on `$status` inside the fall-through group (line 11)
+    let hover = hover_at(&backend, uri, content, 11, 18).expect("expected hover in second case");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("'inactive'") && text.contains("'banned'") && !text.contains("'active'"),
+        "inside the fall-through group, should narrow to 'inactive'|'banned': {}",
+        text
+    );
+}
+
+#[test]
+fn hover_superglobal_shows_string_keyed_array() {
+    let backend = create_test_backend();
+    let uri = "file:///test.php";
+    let content = r#"<?php
+function handle(): void {
+    echo $_GET['page'];
+}
+"#;
+
+    let hover = hover_at(&backend, uri, content, 2, 11).expect("expected hover on $_GET");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("array<string, mixed>"),
+        "superglobal should be typed as array<string, mixed>: {}",
+        text
+    );
+}
+
+#[test]
+fn hover_cli_globals_in_script() {
+    let backend = create_test_backend();
+    let uri = "file:///script.php";
+    let content = r#"<?php
+$script = $argv[0];
+echo $argc;
+"#;
+
+    let hover = hover_at(&backend, uri, content, 1, 3).expect("expected hover on $script");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("string"),
+        "$argv elements should be typed as string: {}",
+        text
+    );
+
+    let hover = hover_at(&backend, uri, content, 2, 7).expect("expected hover on $argc");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("int"),
+        "$argc should be typed as int: {}",
+        text
+    );
+}
+
+#[test]
+fn hover_scalar_array_element_shows_element_type() {
+    let backend = create_test_backend();
+    let uri = "file:///test.php";
+    let content = r#"<?php
+/**
+ * @param array<int, string> $names
+ */
+function first(array $names): void {
+    $name = $names[0];
+    echo $name;
+}
+"#;
+
+    let hover = hover_at(&backend, uri, content, 6, 11).expect("expected hover on $name");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("$name = string"),
+        "element of array<int, string> should be typed as string: {}",
+        text
+    );
+}
+
 #[test]
 fn hover_union_member_access_shows_all_branches() {
     let backend = create_test_backend();
@@ -1270,6 +1378,115 @@ class Service {
     );
 }
 
+#[test]
+fn hover_use_import_and_alias() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": { "App\\": "src/" }
+            }
+        }"#,
+        &[
+            (
+                "src/Models/Product.php",
+                r#"<?php
+namespace App\Models;
+/**
+ * Represents a product in the catalog.
+ */
+class Product {}
+"#,
+            ),
+            (
+                "src/Service.php",
+                r#"<?php
+namespace App;
+use App\Models\Product;
+use App\Models\Product as Item;
+class Service {}
+"#,
+            ),
+        ],
+    );
+
+    let product_uri = format!(
+        "file://{}",
+        _dir.path().join("src/Models/Product.php").display()
+    );
+    let product_content =
+        std::fs::read_to_string(_dir.path().join("src/Models/Product.php")).unwrap();
+    backend.update_ast(&product_uri, &product_content);
+
+    let service_uri = format!("file://{}", _dir.path().join("src/Service.php").display());
+    let service_content = std::fs::read_to_string(_dir.path().join("src/Service.php")).unwrap();
+
+    // Hover on the imported name `Product` (line 2).
+    let hover = hover_at(&backend, &service_uri, &service_content, 2, 18)
+        .expect("expected hover on imported class");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("namespace App\\Models;") && text.contains("Represents a product"),
+        "should show the FQN and summary: {}",
+        text
+    );
+
+    // Hover on the alias `Item` (line 3).
+    let hover =
+        hover_at(&backend, &service_uri, &service_content, 3, 27).expect("expected hover on alias");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("`Item` is an alias of `App\\Models\\Product`"),
+        "should show the alias and FQN: {}",
+        text
+    );
+    assert!(
+        text.contains("Represents a product"),
+        "should include the class summary: {}",
+        text
+    );
+}
+
+#[test]
+fn hover_use_alias_with_mixed_case_as_keyword() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": { "App\\": "src/" }
+            }
+        }"#,
+        &[
+            (
+                "src/Models/Product.php",
+                r#"<?php
+namespace App\Models;
+class Product {}
+"#,
+            ),
+            (
+                "src/Service.php",
+                r#"<?php
+namespace App;
+use App\Models\Product As Item;
+class Service {}
+"#,
+            ),
+        ],
+    );
+
+    let service_uri = format!("file://{}", _dir.path().join("src/Service.php").display());
+    let service_content = std::fs::read_to_string(_dir.path().join("src/Service.php")).unwrap();
+
+    // Hover on the alias `Item` (line 2).
+    let hover =
+        hover_at(&backend, &service_uri, &service_content, 2, 27).expect("expected hover on alias");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("`Item` is an alias of `App\\Models\\Product`"),
+        "should show the alias and FQN: {}",
+        text
+    );
+}
+
 #[test]
 fn hover_cross_file_method() {
     let (backend, _dir) = create_psr4_workspace(
@@ -2287,6 +2504,26 @@ function test() {
     );
 }
 
+#[test]
+fn hover_variable_with_unknown_type_shows_mixed() {
+    let backend = create_test_backend();
+    let uri = "file:///test.php";
+    let content = r#"<?php
+function test() {
+    $x = undefined_function();
+    echo $x;
+}
+"#;
+
+    let hover = hover_at(&backend, uri, content, 3, 10).expect("expected hover on $x");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("$x = mixed"),
+        "unresolvable variable should be shown as mixed: {}",
+        text
+    );
+}
+
 // ─── self / static / parent / $this hover format ────────────────────────────
 
 #[test]
@@ -11104,6 +11341,145 @@ namespace NS2 {
     );
 }
 
+#[test]
+fn hover_variable_from_cross_file_function_return_type() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{ "autoload": { "psr-4": { "App\\": "src/" } } }"#,
+        &[(
+            "src/Report.php",
+            r#"<?php
+namespace App;
+
+class Report {
+    public function summary(): string { return ''; }
+}
+"#,
+        )],
+    );
+
+    // Functions declared in another file: one with a native return
+    // type, one with only a docblock `@return`.
+    backend.update_ast(
+        "file:///helpers.php",
+        r#"<?php
+use App\Report;
+
+function make_report(): Report { return new Report(); }
+
+/** @return Report */
+function latest_report() { return new Report(); }
+"#,
+    );
+
+    let uri = "file:///test.php";
+    let content = r#"<?php
+function run(): void {
+    $native = make_report();
+    $doc = latest_report();
+    $native->summary();
+    $doc->summary();
+}
+"#;
+    for line in [4, 5] {
+        let hover = hover_at(&backend, uri, content, line, 6).expect("expected hover on variable");
+        let text = hover_text(&hover);
+        assert!(
+            text.contains("Report"),
+            "variable on line {} should be typed as Report, got: {}",
+            line,
+            text
+        );
+    }
+}
+
+#[test]
+fn hover_variable_from_inherited_cross_file_method_return_type() {
+    let (backend, _dir) = create_psr4_workspace(
+        r#"{ "autoload": { "psr-4": { "App\\": "src/" } } }"#,
+        &[
+            (
+                "src/Entity.php",
+                r#"<?php
+namespace App;
+
+class Entity {
+    public function getId(): int { return 0; }
+}
+"#,
+            ),
+            (
+                "src/BaseRepository.php",
+                r#"<?php
+namespace App;
+
+abstract class BaseRepository {
+    public function find(int $id): Entity { return new Entity(); }
+}
+"#,
+            ),
+            (
+                "src/Repository.php",
+                r#"<?php
+namespace App;
+
+abstract class Repository extends BaseRepository {}
+"#,
+            ),
+        ],
+    );
+
+    let uri = "file:///test.php";
+    let content = r#"<?php
+use App\Repository;
+
+class UserRepository extends Repository {
+    public function load(): void {
+        $entity = $this->find(1);
+        $entity->getId();
+    }
+}
+"#;
+    let hover = hover_at(&backend, uri, content, 6, 10).expect("expected hover on $entity");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("Entity"),
+        "method inherited from a grandparent in another file should type $entity as Entity, got: {}",
+        text
+    );
+}
+
+#[test]
+fn hover_variable_from_same_class_method_return_type() {
+    let backend = create_test_backend();
+    let uri = "file:///test.php";
+    let content = r#"<?php
+class HelperClass {
+    public function assist(): void {}
+}
+class Controller {
+    private function getHelper(): HelperClass { return new HelperClass(); }
+    private static function makeHelper(): HelperClass { return new HelperClass(); }
+
+    public function handle(): void {
+        $x = $this->getHelper();
+        $y = self::makeHelper();
+        $x->assist();
+        $y->assist();
+    }
+}
+"#;
+    for line in [11, 12] {
+        let hover = hover_at(&backend, uri, content, line, 9).expect("expected hover on variable");
+        let text = hover_text(&hover);
+        assert!(
+            text.contains("HelperClass"),
+            "variable on line {} should be typed as HelperClass, got: {}",
+            line,
+            text
+        );
+    }
+}
+
 #[test]
 fn hover_multi_namespace_property_resolution() {
     let backend = create_test_backend();
@@ -11512,3 +11888,124 @@ function test(): void {
         text
     );
 }
+
+#[test]
+fn hover_class_constant_shows_multiline_value_at_reference() {
+    let backend = create_test_backend();
+    let uri = "file:///test.php";
+    let content = r#"<?php
+class Order {
+    const STATUS_ACTIVE = 1;
+    const LABELS = [
+        self::STATUS_ACTIVE => 'Active',
+    ];
+}
+$label = Order::LABELS[Order::STATUS_ACTIVE];
+"#;
+
+    let hover = hover_at(&backend, uri, content, 7, 33).expect("expected hover");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("class Order {") && text.contains("const STATUS_ACTIVE = 1;"),
+        "should show the owning class and value: {}",
+        text
+    );
+
+    let hover = hover_at(&backend, uri, content, 7, 19).expect("expected hover");
+    let text = hover_text(&hover);
+    assert!(
+        text.contains("const LABELS = [\n        self::STATUS_ACTIVE => 'Active',\n    ];"),
+        "should show the full multi-line value: {}",
+        text
+    );
+}
+
+#[test]
+fn hover_resolves_class_alias_from_editor_settings() {
+    let (backend, dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": { "App\\": "src/" }
+            }
+        }"#,
+        &[(
+            "src/Models/User.php",
+            r#"<?php
+namespace App\Models;
+/**
+ * A registered user.
+ */
+class User {}
+"#,
+        )],
+    );
+
+    let root_uri = Url::from_directory_path(dir.path()).unwrap();
+    backend.set_client_settings(
+        root_uri.as_str(),
+        phpantom_lsp::config::ClientSettings {
+            aliases: std::collections::HashMap::from([(
+                "LegacyUser".to_string(),
+                "App\\Models\\User".to_string(),
+            )]),
+            ..Default::default()
+        },
+    );
+
+    let content = "<?php\n$user = new LegacyUser();\n";
+    let hover = hover_at(&backend, "file:///script.php", content, 1, 15)
+        .expect("expected hover on aliased class");
+    assert!(
+        hover_text(&hover).contains("A registered user."),
+        "alias should resolve to the target class: {}",
+        hover_text(&hover)
+    );
+}
+
+#[test]
+fn hover_class_alias_prefers_the_primary_folder() {
+    let (backend, dir) = create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": { "App\\": "src/" }
+            }
+        }"#,
+        &[
+            (
+                "src/Models/User.php",
+                "<?php\nnamespace App\\Models;\n/**\n * A registered user.\n */\nclass User {}\n",
+            ),
+            (
+                "src/Models/Guest.php",
+                "<?php\nnamespace App\\Models;\n/**\n * An anonymous guest.\n */\nclass Guest {}\n",
+            ),
+        ],
+    );
+    let other = tempfile::tempdir().unwrap();
+    backend
+        .additional_workspace_roots()
+        .write()
+        .push(other.path().to_path_buf());
+
+    let alias_to = |target: &str| phpantom_lsp::config::ClientSettings {
+        aliases: std::collections::HashMap::from([("LegacyUser".to_string(), target.to_string())]),
+        ..Default::default()
+    };
+    backend.set_client_settings(
+        Url::from_directory_path(other.path()).unwrap().as_str(),
+        alias_to("App\\Models\\Guest"),
+    );
+    backend.set_client_settings(
+        Url::from_directory_path(dir.path()).unwrap().as_str(),
+        alias_to("App\\Models\\User"),
+    );
+
+    let content = "<?php\n$user = new LegacyUser();\n";
+    let hover = hover_at(&backend, "file:///script.php", content, 1, 15)
+        .expect("expected hover on aliased class");
+    assert!(
+        hover_text(&hover).contains("A registered user."),
+        "the primary folder's alias should win: {}",
+        hover_text(&hover)
+    );
+}
@@ -568,3 +568,44 @@ function demo() {
     // `$abc` in `echo $abc` starts at col 9, so `abc` is col 10..13.
     assert_range(&ranges[1], 3, 10, 13);
 }
+
+// ─── Heredoc delimiters ─────────────────────────────────────────────────────
+
+#[test]
+fn linked_editing_heredoc_opening_label() {
+    let backend = create_test_backend();
+    let php = "<?php\n$html = <<<EOT\n    <p>{$name}</p>\n    EOT;\n";
+
+    // Cursor inside `EOT` on the opening line.
+    let result = linked_editing_at(&backend, "file:///test.php", php, 1, 12);
+    let linked = result.expect("expected linked editing ranges");
+
+    assert_eq!(linked.ranges.len(), 2);
+    assert_range(&linked.ranges[0], 1, 11, 14);
+    assert_range(&linked.ranges[1], 3, 4, 7);
+    assert!(linked.word_pattern.is_some());
+}
+
+#[test]
+fn linked_editing_nowdoc_closing_label() {
+    let backend = create_test_backend();
+    let php = "<?php\n$sql = <<<'SQL'\nSELECT 1\nSQL;\n";
+
+    // Cursor at the end of the closing `SQL`.
+    let result = linked_editing_at(&backend, "file:///test.php", php, 3, 3);
+    let linked = result.expect("expected linked editing ranges");
+
+    // The opening range excludes the quotes around the nowdoc label.
+    assert_eq!(linked.ranges.len(), 2);
+    assert_range(&linked.ranges[0], 1, 11, 14);
+    assert_range(&linked.ranges[1], 3, 0, 3);
+}
+
+#[test]
+fn linked_editing_heredoc_body_returns_none() {
+    let backend = create_test_backend();
+    let php = "<?php\n$text = <<<EOT\nplain EOT text\nEOT;\n";
+
+    let result = linked_editing_at(&backend, "file:///test.php", php, 2, 7);
+    assert!(result.is_none(), "heredoc body text should not link");
+}
@@ -4,6 +4,7 @@ mod blade;
 pub mod blade_debug;
 pub mod blade_error;
 pub mod blade_regression;
+mod call_hierarchy;
 mod classmap_scanner;
 mod code_action_add_override;
 mod code_action_add_throws;
@@ -34,6 +35,7 @@ mod completion_closure_param_inference;
 mod completion_comments;
 mod completion_cross_file;
 mod completion_deprecated;
+mod completion_doctrine;
 mod completion_enums;
 mod completion_first_class_callable;
 mod completion_foreach_collections;
@@ -63,6 +65,7 @@ mod completion_spread_operator;
 mod completion_strings;
 mod completion_stub_functions;
 mod completion_switch;
+mod completion_symfony;
 mod completion_template_bounds;
 mod completion_ternary;
 mod completion_throws;
@@ -93,6 +96,7 @@ mod diagnostics_type_errors;
 mod diagnostics_undefined_variables;
 mod diagnostics_unknown_members;
 mod docblock_types;
+mod document_color;
 mod document_highlight;
 mod document_links;
 mod document_symbols;
@@ -2327,3 +2327,50 @@ async fn test_legitimate_deprecated_attributes_still_work() {
         "#[\\JetBrains\\PhpStorm\\Deprecated] should trigger deprecation"
     );
 }
+
+// ─── Error tolerance ────────────────────────────────────────────────────────
+
+/// Classes parsed before a syntax error are still extracted, so
+/// completion keeps working for the rest of the file while the user is
+/// mid-edit further down.
+#[tokio::test]
+async fn test_parse_php_keeps_classes_before_syntax_error() {
+    let backend = create_test_backend();
+    let php = concat!(
+        "<?php\n",
+        "class Complete {\n",
+        "    public function ready(): void {}\n",
+        "}\n",
+        "class Broken {\n",
+        "    public function half(\n",
+    );
+
+    let classes = backend.parse_php(php);
+    let complete = classes
+        .iter()
+        .find(|c| c.name == "Complete")
+        .expect("class before the syntax error should be extracted");
+    assert_eq!(complete.methods.len(), 1);
+    assert_eq!(complete.methods[0].name, "ready");
+}
+
+#[tokio::test]
+async fn test_parse_php_keeps_class_with_incomplete_method_body() {
+    let backend = create_test_backend();
+    let php = concat!(
+        "<?php\n",
+        "class Editing {\n",
+        "    public function first(): void {\n",
+        "        $this->\n",
+        "    }\n",
+        "    public function second(): void {}\n",
+        "}\n",
+    );
+
+    let classes = backend.parse_php(php);
+    assert_eq!(classes.len(), 1);
+    assert_eq!(classes[0].name, "Editing");
+    let names: Vec<&str> = classes[0].methods.iter().map(|m| m.name.as_str()).collect();
+    assert!(names.contains(&"first"));
+    assert!(names.contains(&"second"));
+}
@@ -1247,6 +1247,53 @@ class Dashboard {
     assert_no_duplicates(&results, "async_one_file_method_refs");
 }
 
+/// Files in a folder added through `workspaceFolders` are scanned for
+/// references just like files under the primary root.
+#[test]
+fn references_include_files_in_additional_workspace_folders() {
+    let (backend, dir) = crate::common::create_psr4_workspace(
+        r#"{
+            "autoload": {
+                "psr-4": {
+                    "App\\": "src/"
+                }
+            }
+        }"#,
+        &[(
+            "src/Widget.php",
+            "<?php\nnamespace App;\n\nclass Widget {}\n",
+        )],
+    );
+    let lib = tempfile::tempdir().unwrap();
+    std::fs::write(
+        lib.path().join("bootstrap.php"),
+        "<?php\n$widget = new \\App\\Widget();\n",
+    )
+    .unwrap();
+    backend
+        .additional_workspace_roots()
+        .write()
+        .push(lib.path().to_path_buf());
+
+    let widget_path = dir.path().join("src/Widget.php");
+    let widget_uri = tower_lsp::lsp_types::Url::from_file_path(&widget_path)
+        .unwrap()
+        .to_string();
+    let widget_content = std::fs::read_to_string(&widget_path).unwrap();
+    backend.update_ast(&widget_uri, &widget_content);
+
+    let results = backend
+        .find_references(&widget_uri, &widget_content, Position::new(3, 6), false)
+        .expect("should find class references");
+    assert!(
+        results
+            .iter()
+            .any(|loc| loc.uri.path().ends_with("/bootstrap.php")),
+        "expected a reference in the added folder, got {:?}",
+        results
+    );
+}
+
 // ─── Symbol map span dump test ──────────────────────────────────────────────
 
 #[test]
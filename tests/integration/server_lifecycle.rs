@@ -413,3 +413,602 @@ async fn test_did_change_incremental_multiple_edits() {
     assert!(names.contains(&"gamma"));
     assert!(!names.contains(&"alpha"));
 }
+
+#[tokio::test]
+async fn test_initialize_advertises_workspace_commands() {
+    let backend = create_test_backend();
+    let result = backend
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+
+    let commands = result
+        .capabilities
+        .execute_command_provider
+        .expect("execute command provider should be present")
+        .commands;
+    assert!(commands.contains(&"phpantom.reindex".to_string()));
+    assert!(commands.contains(&"phpantom.clearCache".to_string()));
+}
+
+#[tokio::test]
+async fn test_reindex_command_rebuilds_workspace_index() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("Stale.php"), "<?php\nclass Stale {}\n").unwrap();
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(dir.path().to_path_buf(), vec![]);
+
+    backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.reindex".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    assert!(backend.classmap().read().contains_key("Stale"));
+
+    // Simulate code generation replacing files behind the server's back.
+    std::fs::remove_file(dir.path().join("Stale.php")).unwrap();
+    std::fs::write(dir.path().join("Fresh.php"), "<?php\nclass Fresh {}\n").unwrap();
+
+    backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.reindex".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    let classmap = backend.classmap().read();
+    assert!(classmap.contains_key("Fresh"));
+    assert!(!classmap.contains_key("Stale"));
+}
+
+#[tokio::test]
+async fn test_unknown_command_is_rejected() {
+    let backend = create_test_backend();
+    let result = backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.doesNotExist".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_clear_cache_command_rebuilds_workspace_index() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("Cached.php"), "<?php\nclass Cached {}\n").unwrap();
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(dir.path().to_path_buf(), vec![]);
+
+    backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.clearCache".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    assert!(backend.classmap().read().contains_key("Cached"));
+}
+
+#[tokio::test]
+async fn test_stale_indexing_cancel_does_not_affect_next_run() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("Kept.php"), "<?php\nclass Kept {}\n").unwrap();
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(dir.path().to_path_buf(), vec![]);
+
+    backend
+        .work_done_progress_cancel(WorkDoneProgressCancelParams {
+            token: NumberOrString::String("phpantom/indexing".to_string()),
+        })
+        .await;
+
+    backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.reindex".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    assert!(backend.classmap().read().contains_key("Kept"));
+}
+
+fn workspace_folders(paths: &[&std::path::Path]) -> Vec<WorkspaceFolder> {
+    paths
+        .iter()
+        .map(|p| WorkspaceFolder {
+            uri: Url::from_directory_path(p).unwrap(),
+            name: p.file_name().unwrap().to_string_lossy().to_string(),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_initialize_advertises_workspace_folder_support() {
+    let backend = create_test_backend();
+    let result = backend
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+
+    let folders = result
+        .capabilities
+        .workspace
+        .and_then(|w| w.workspace_folders)
+        .expect("workspace folder capability should be present");
+    assert_eq!(folders.supported, Some(true));
+    assert_eq!(folders.change_notifications, Some(OneOf::Left(true)));
+}
+
+#[tokio::test]
+async fn test_initialize_records_every_workspace_folder() {
+    let app = tempfile::tempdir().unwrap();
+    let lib = tempfile::tempdir().unwrap();
+    let backend = create_test_backend();
+
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(workspace_folders(&[app.path(), lib.path()])),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(backend.workspace_root().read().as_deref(), Some(app.path()));
+    assert_eq!(
+        *backend.additional_workspace_roots().read(),
+        vec![lib.path().to_path_buf()]
+    );
+}
+
+#[tokio::test]
+async fn test_workspace_folder_changes_update_the_index() {
+    let app = tempfile::tempdir().unwrap();
+    std::fs::write(app.path().join("App.php"), "<?php\nclass App {}\n").unwrap();
+
+    let lib = tempfile::tempdir().unwrap();
+    std::fs::write(
+        lib.path().join("composer.json"),
+        r#"{"autoload":{"psr-4":{"Lib\\":"src/"}}}"#,
+    )
+    .unwrap();
+    std::fs::create_dir(lib.path().join("src")).unwrap();
+    std::fs::write(
+        lib.path().join("src/Shared.php"),
+        "<?php\nnamespace Lib;\nclass Shared {}\n",
+    )
+    .unwrap();
+
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(app.path().to_path_buf(), vec![]);
+
+    reindex(&backend).await;
+
+    backend
+        .did_change_workspace_folders(DidChangeWorkspaceFoldersParams {
+            event: WorkspaceFoldersChangeEvent {
+                added: workspace_folders(&[lib.path()]),
+                removed: vec![],
+            },
+        })
+        .await;
+    {
+        let classmap = backend.classmap().read();
+        assert!(classmap.contains_key("App"));
+        assert!(classmap.contains_key("Lib\\Shared"));
+    }
+
+    backend
+        .did_change_workspace_folders(DidChangeWorkspaceFoldersParams {
+            event: WorkspaceFoldersChangeEvent {
+                added: vec![],
+                removed: workspace_folders(&[lib.path()]),
+            },
+        })
+        .await;
+    let classmap = backend.classmap().read();
+    assert!(classmap.contains_key("App"));
+    assert!(!classmap.contains_key("Lib\\Shared"));
+    assert!(backend.additional_workspace_roots().read().is_empty());
+}
+
+#[tokio::test]
+async fn test_workspace_folder_changes_leave_other_folders_alone() {
+    let app = tempfile::tempdir().unwrap();
+    std::fs::write(app.path().join("App.php"), "<?php\nclass App {}\n").unwrap();
+    let lib = tempfile::tempdir().unwrap();
+    std::fs::write(
+        lib.path().join("Shared.php"),
+        "<?php\nclass Shared {}\nfunction shared_helper() {}\n",
+    )
+    .unwrap();
+
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(app.path().to_path_buf(), vec![]);
+    reindex(&backend).await;
+
+    // Removed from disk after indexing: a full rescan would lose it.
+    std::fs::remove_file(app.path().join("App.php")).unwrap();
+
+    backend
+        .did_change_workspace_folders(DidChangeWorkspaceFoldersParams {
+            event: WorkspaceFoldersChangeEvent {
+                added: workspace_folders(&[lib.path()]),
+                removed: vec![],
+            },
+        })
+        .await;
+    {
+        let classmap = backend.classmap().read();
+        assert!(classmap.contains_key("App"));
+        assert!(classmap.contains_key("Shared"));
+        assert!(
+            backend
+                .autoload_function_index()
+                .read()
+                .contains_key("shared_helper")
+        );
+    }
+
+    backend
+        .did_change_workspace_folders(DidChangeWorkspaceFoldersParams {
+            event: WorkspaceFoldersChangeEvent {
+                added: vec![],
+                removed: workspace_folders(&[lib.path()]),
+            },
+        })
+        .await;
+    let classmap = backend.classmap().read();
+    assert!(classmap.contains_key("App"));
+    assert!(!classmap.contains_key("Shared"));
+    assert!(
+        !backend
+            .autoload_function_index()
+            .read()
+            .contains_key("shared_helper")
+    );
+}
+
+async fn reindex(backend: &phpantom_lsp::Backend) {
+    backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.reindex".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_include_paths_from_editor_settings_are_indexed() {
+    let app = tempfile::tempdir().unwrap();
+    std::fs::write(app.path().join("App.php"), "<?php\nclass App {}\n").unwrap();
+    let shared = tempfile::tempdir().unwrap();
+    std::fs::write(shared.path().join("Helper.php"), "<?php\nclass Helper {}\n").unwrap();
+
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(app.path().to_path_buf(), vec![]);
+    backend.set_client_settings(
+        Url::from_directory_path(app.path()).unwrap().as_str(),
+        phpantom_lsp::config::ClientSettings {
+            include_paths: vec![shared.path().to_string_lossy().to_string()],
+            ..Default::default()
+        },
+    );
+
+    backend
+        .execute_command(ExecuteCommandParams {
+            command: "phpantom.reindex".to_string(),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+    let classmap = backend.classmap().read();
+    assert!(classmap.contains_key("App"));
+    assert!(classmap.contains_key("Helper"));
+}
+
+#[tokio::test]
+async fn test_removed_folder_drops_its_settings_and_include_paths() {
+    let app = tempfile::tempdir().unwrap();
+    std::fs::write(app.path().join("App.php"), "<?php\nclass App {}\n").unwrap();
+    let lib = tempfile::tempdir().unwrap();
+    let shared = tempfile::tempdir().unwrap();
+    std::fs::write(shared.path().join("Helper.php"), "<?php\nclass Helper {}\n").unwrap();
+
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(app.path().to_path_buf(), vec![]);
+    backend
+        .did_change_workspace_folders(DidChangeWorkspaceFoldersParams {
+            event: WorkspaceFoldersChangeEvent {
+                added: workspace_folders(&[lib.path()]),
+                removed: vec![],
+            },
+        })
+        .await;
+    backend.set_client_settings(
+        Url::from_directory_path(lib.path()).unwrap().as_str(),
+        phpantom_lsp::config::ClientSettings {
+            include_paths: vec![shared.path().to_string_lossy().to_string()],
+            ..Default::default()
+        },
+    );
+    reindex(&backend).await;
+    assert!(backend.classmap().read().contains_key("Helper"));
+
+    backend
+        .did_change_workspace_folders(DidChangeWorkspaceFoldersParams {
+            event: WorkspaceFoldersChangeEvent {
+                added: vec![],
+                removed: workspace_folders(&[lib.path()]),
+            },
+        })
+        .await;
+    let classmap = backend.classmap().read();
+    assert!(classmap.contains_key("App"));
+    assert!(!classmap.contains_key("Helper"));
+}
+
+#[tokio::test]
+async fn test_initialize_prefers_root_uri_over_workspace_folders() {
+    let root = tempfile::tempdir().unwrap();
+    let other = tempfile::tempdir().unwrap();
+    let backend = create_test_backend();
+
+    let params = InitializeParams {
+        root_uri: Some(Url::from_directory_path(root.path()).unwrap()),
+        workspace_folders: Some(workspace_folders(&[other.path(), root.path()])),
+        ..InitializeParams::default()
+    };
+    backend.initialize(params).await.unwrap();
+
+    assert_eq!(
+        backend.workspace_root().read().as_deref(),
+        Some(root.path())
+    );
+    assert_eq!(
+        *backend.additional_workspace_roots().read(),
+        vec![other.path().to_path_buf()]
+    );
+}
+
+#[tokio::test]
+async fn test_initialized_indexes_the_workspace_root() {
+    let root = tempfile::tempdir().unwrap();
+    std::fs::write(root.path().join("Indexed.php"), "<?php\nclass Indexed {}\n").unwrap();
+    let backend = create_test_backend();
+
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(workspace_folders(&[root.path()])),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+    backend.initialized(InitializedParams {}).await;
+
+    assert!(backend.classmap().read().contains_key("Indexed"));
+}
+
+#[tokio::test]
+async fn test_initialize_without_workspace_keeps_preset_root() {
+    let dir = tempfile::tempdir().unwrap();
+    let backend = phpantom_lsp::Backend::new_test_with_workspace(dir.path().to_path_buf(), vec![]);
+
+    backend
+        .initialize(InitializeParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(backend.workspace_root().read().as_deref(), Some(dir.path()));
+}
+
+/// Vendor classes are indexed by name only; the file is parsed the
+/// first time the class is needed.  Editing the file between indexing
+/// and first use proves nothing was parsed up front.
+#[tokio::test]
+async fn test_vendor_classes_are_parsed_on_first_use() {
+    let root = tempfile::tempdir().unwrap();
+    std::fs::write(root.path().join("composer.json"), r#"{"name": "app/app"}"#).unwrap();
+    let pkg_src = root.path().join("vendor/acme/http/src");
+    std::fs::create_dir_all(&pkg_src).unwrap();
+    std::fs::create_dir_all(root.path().join("vendor/composer")).unwrap();
+    std::fs::write(
+        root.path().join("vendor/composer/installed.json"),
+        r#"{"packages": [{"name": "acme/http", "install-path": "../acme/http", "autoload": {"psr-4": {"Acme\\Http\\": "src/"}}}]}"#,
+    )
+    .unwrap();
+    let client_path = pkg_src.join("Client.php");
+    std::fs::write(
+        &client_path,
+        "<?php\nnamespace Acme\\Http;\nclass Client {\n    public static function before(): void {}\n}\n",
+    )
+    .unwrap();
+
+    let backend = create_test_backend();
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(workspace_folders(&[root.path()])),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+    backend.initialized(InitializedParams {}).await;
+
+    assert_eq!(
+        backend.classmap().read().get("Acme\\Http\\Client"),
+        Some(&client_path.canonicalize().unwrap())
+    );
+
+    std::fs::write(
+        &client_path,
+        "<?php\nnamespace Acme\\Http;\nclass Client {\n    public static function after(): void {}\n}\n",
+    )
+    .unwrap();
+
+    let uri = Url::from_file_path(root.path().join("app.php")).unwrap();
+    backend
+        .did_open(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "php".to_string(),
+                version: 1,
+                text: "<?php\n\\Acme\\Http\\Client::\n".to_string(),
+            },
+        })
+        .await;
+    let result = backend
+        .completion(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position::new(1, 19),
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .await
+        .unwrap();
+
+    let labels: Vec<String> = match result {
+        Some(CompletionResponse::Array(items)) => items.into_iter().map(|i| i.label).collect(),
+        Some(CompletionResponse::List(list)) => list.items.into_iter().map(|i| i.label).collect(),
+        None => Vec::new(),
+    };
+    assert!(labels.contains(&"after()".to_string()), "got {labels:?}");
+    assert!(!labels.contains(&"before()".to_string()), "got {labels:?}");
+}
+
+#[tokio::test]
+async fn test_composer_json_change_updates_psr4_mappings() {
+    let root = tempfile::tempdir().unwrap();
+    let composer_json = root.path().join("composer.json");
+    std::fs::write(
+        &composer_json,
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(root.path().join("src")).unwrap();
+    std::fs::create_dir_all(root.path().join("lib")).unwrap();
+    std::fs::write(
+        root.path().join("src/Foo.php"),
+        "<?php\nnamespace App;\nclass Foo {}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.path().join("lib/Bar.php"),
+        "<?php\nnamespace Lib;\nclass Bar {}\n",
+    )
+    .unwrap();
+
+    let backend = create_test_backend();
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(workspace_folders(&[root.path()])),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+    backend.initialized(InitializedParams {}).await;
+    assert!(backend.classmap().read().contains_key("App\\Foo"));
+    assert!(!backend.classmap().read().contains_key("Lib\\Bar"));
+
+    std::fs::write(
+        &composer_json,
+        r#"{"autoload": {"psr-4": {"Lib\\": "lib/"}}}"#,
+    )
+    .unwrap();
+    backend
+        .did_change_watched_files(DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: Url::from_file_path(&composer_json).unwrap(),
+                typ: FileChangeType::CHANGED,
+            }],
+        })
+        .await;
+
+    let prefixes: Vec<String> = backend
+        .psr4_mappings()
+        .read()
+        .iter()
+        .map(|m| m.prefix.clone())
+        .collect();
+    assert_eq!(prefixes, vec!["Lib\\".to_string()]);
+    assert!(backend.classmap().read().contains_key("Lib\\Bar"));
+    assert!(!backend.classmap().read().contains_key("App\\Foo"));
+}
+
+#[tokio::test]
+async fn test_removed_psr4_mapping_evicts_parsed_classes() {
+    let root = tempfile::tempdir().unwrap();
+    let composer_json = root.path().join("composer.json");
+    std::fs::write(
+        &composer_json,
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(root.path().join("src")).unwrap();
+    let foo_path = root.path().join("src/Foo.php");
+    let foo_content = "<?php\nnamespace App;\nclass Foo {}\n";
+    std::fs::write(&foo_path, foo_content).unwrap();
+
+    let backend = create_test_backend();
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(workspace_folders(&[root.path()])),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+    backend.initialized(InitializedParams {}).await;
+    let foo_uri = Url::from_file_path(&foo_path).unwrap().to_string();
+    backend.update_ast(&foo_uri, foo_content);
+    assert!(backend.class_index().read().contains_key("App\\Foo"));
+
+    std::fs::write(&composer_json, r#"{"autoload": {"psr-4": {}}}"#).unwrap();
+    backend
+        .did_change_watched_files(DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: Url::from_file_path(&composer_json).unwrap(),
+                typ: FileChangeType::CHANGED,
+            }],
+        })
+        .await;
+
+    assert!(!backend.classmap().read().contains_key("App\\Foo"));
+    assert!(!backend.class_index().read().contains_key("App\\Foo"));
+}
+
+#[tokio::test]
+async fn test_unrelated_watched_file_change_is_ignored() {
+    let root = tempfile::tempdir().unwrap();
+    std::fs::write(
+        root.path().join("composer.json"),
+        r#"{"autoload": {"psr-4": {"App\\": "src/"}}}"#,
+    )
+    .unwrap();
+
+    let backend = create_test_backend();
+    backend
+        .initialize(InitializeParams {
+            workspace_folders: Some(workspace_folders(&[root.path()])),
+            ..InitializeParams::default()
+        })
+        .await
+        .unwrap();
+    backend.initialized(InitializedParams {}).await;
+
+    std::fs::write(root.path().join("composer.json"), "{}").unwrap();
+    backend
+        .did_change_watched_files(DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: Url::from_file_path(root.path().join("packages/a/composer.json")).unwrap(),
+                typ: FileChangeType::CHANGED,
+            }],
+        })
+        .await;
+
+    assert_eq!(backend.psr4_mappings().read().len(), 1);
+}
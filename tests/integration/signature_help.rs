@@ -617,6 +617,51 @@ async fn parent_method_call() {
     assert_eq!(pl.len(), 1);
 }
 
+#[tokio::test]
+async fn parent_constructor_two_level_chain() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///sig_parent_chain.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Base {\n",
+        "    public function __construct(string $name) {}\n",
+        "}\n",
+        "class Middle extends Base {\n",
+        "    public function __construct(string $name, int $age) {\n",
+        "        parent::__construct();\n",
+        "    }\n",
+        "}\n",
+        "class Leaf extends Middle {\n",
+        "    public function __construct() {\n",
+        "        parent::__construct('x', );\n",
+        "    }\n",
+        "}\n",
+        "class Inherited extends Middle {}\n",
+        "class Grandchild extends Inherited {\n",
+        "    public function __construct() {\n",
+        "        parent::__construct();\n",
+        "    }\n",
+        "}\n",
+    );
+
+    // Middle → Base: only Base's `$name`.
+    let sh = sig_help_at(&backend, &uri, text, 6, 28).await.unwrap();
+    assert_eq!(param_labels(&sh).len(), 1);
+    assert!(sig_label(&sh).contains("string $name"));
+
+    // Leaf → Middle: Middle's own two parameters, second one active.
+    let sh = sig_help_at(&backend, &uri, text, 11, 33).await.unwrap();
+    assert_eq!(param_labels(&sh).len(), 2);
+    assert!(sig_label(&sh).contains("int $age"));
+    assert_eq!(active_param(&sh), 1);
+
+    // Grandchild → Inherited, which has no constructor of its own and
+    // inherits Middle's.
+    let sh = sig_help_at(&backend, &uri, text, 17, 28).await.unwrap();
+    assert_eq!(param_labels(&sh).len(), 2);
+    assert!(sig_label(&sh).contains("int $age"));
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Cursor right after open paren (no typing yet)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1130,6 +1175,29 @@ async fn constructor_no_explicit_ctor() {
     assert_eq!(sig_label(&sh), "(): mixed");
 }
 
+#[tokio::test]
+async fn constructor_promoted_parameters() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///sig_ctor_promoted.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Point {\n",
+        "    public function __construct(\n",
+        "        private readonly float $x,\n",
+        "        private readonly float $y = 0.0,\n",
+        "    ) {}\n",
+        "}\n",
+        "$p = new Point(1.5, );\n",
+    );
+    // "$p = new Point(1.5, );" — cursor after the comma, before `)`
+    let sh = sig_help_at(&backend, &uri, text, 7, 20).await.unwrap();
+    let pl = param_labels(&sh);
+    assert_eq!(pl.len(), 2, "got: {:?}", pl);
+    assert!(sig_label(&sh).contains("float $x"));
+    assert!(sig_label(&sh).contains("float $y"));
+    assert_eq!(active_param(&sh), 1);
+}
+
 #[tokio::test]
 async fn generic_chain_with_new_expression_arg() {
     let backend = create_test_backend();
@@ -1241,6 +1309,28 @@ async fn first_class_callable_invocation() {
     assert_eq!(active_param(&sh), 0);
 }
 
+#[tokio::test]
+async fn typed_closure_parameter_invocation() {
+    let backend = create_test_backend();
+    let uri = Url::parse("file:///sig_typed_closure.php").unwrap();
+    let text = concat!(
+        "<?php\n",
+        "class Foo {}\n",
+        "class Bar {}\n",
+        "/**\n",
+        " * @param \\Closure(Foo, int): Bar $callback\n",
+        " */\n",
+        "function apply(\\Closure $callback): void {\n",
+        "    $callback(new Foo(), );\n",
+        "}\n",
+    );
+    // Line 7: "    $callback(new Foo(), );"
+    // cursor after ", " at char 25
+    let sh = sig_help_at(&backend, &uri, text, 7, 25).await.unwrap();
+    assert_eq!(sig_label(&sh), "(Foo $arg1, int $arg2): Bar");
+    assert_eq!(active_param(&sh), 1);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Default values in parameter labels
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,22 @@
+<?php
+// Test that `Foo::class` is typed as `class-string<Foo>` and feeds
+// conditional return types that test `class-string<T>`.
+
+namespace ClassConstantFetch {
+    class Mailer {}
+
+    /**
+     * @template TClass
+     * @return ($abstract is class-string<TClass> ? TClass : mixed)
+     */
+    function app($abstract = null) {}
+
+    $name = Mailer::class;
+    assertType('class-string<Mailer>', $name);
+
+    $direct = app(Mailer::class);
+    assertType('ClassConstantFetch\Mailer', $direct);
+
+    $forwarded = app($name);
+    assertType('ClassConstantFetch\Mailer', $forwarded);
+}
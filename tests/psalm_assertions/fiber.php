@@ -0,0 +1,19 @@
+<?php
+// Test that the Fiber stub patch infers template params from the callback.
+
+$fiber = new Fiber(function (int $start): string {
+    $resumed = Fiber::suspend($start);
+    return (string) $resumed;
+});
+
+$result = $fiber->getReturn();
+assertType('string', $result);
+
+$started = $fiber->isStarted();
+assertType('bool', $started);
+
+$terminated = $fiber->isTerminated();
+assertType('bool', $terminated);
+
+$current = Fiber::getCurrent();
+assertType('Fiber|null', $current);
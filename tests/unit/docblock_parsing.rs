@@ -497,6 +497,35 @@ fn effective_type_balanced_docblock_unchanged() {
     );
 }
 
+// ── extract_param_raw_type ──────────────────────────────────────────
+
+#[test]
+fn param_raw_type_simple() {
+    let doc = "/** @param list<User> $users */";
+    assert_eq!(
+        extract_param_raw_type(doc, "$users"),
+        Some(PhpType::parse("list<User>"))
+    );
+}
+
+#[test]
+fn param_raw_type_variadic_strips_array_suffix() {
+    let doc = "/** @param User[] ...$users */";
+    assert_eq!(
+        extract_param_raw_type(doc, "$users"),
+        Some(PhpType::parse("User"))
+    );
+}
+
+#[test]
+fn param_raw_type_variadic_element_type() {
+    let doc = "/** @param User ...$users */";
+    assert_eq!(
+        extract_param_raw_type(doc, "$users"),
+        Some(PhpType::parse("User"))
+    );
+}
+
 // ── extract_var_type ────────────────────────────────────────────────
 
 #[test]
@@ -761,6 +790,18 @@ fn override_array_with_generic_scalar_docblock() {
     ));
 }
 
+#[test]
+fn override_string_with_literal_union() {
+    assert!(should_override_type_typed(
+        &PhpType::parse("'active'|'inactive'"),
+        &PhpType::parse("string")
+    ));
+    assert!(!should_override_type_typed(
+        &PhpType::parse("'active'|int"),
+        &PhpType::parse("string")
+    ));
+}
+
 #[test]
 fn no_override_void_with_class() {
     assert!(!should_override_type_typed(
@@ -1529,6 +1570,77 @@ fn deprecated_tag_with_tab_separator() {
     assert!(has_deprecated_tag(doc));
 }
 
+// ─── @internal tag tests ────────────────────────────────────────
+
+#[test]
+fn internal_tag_present() {
+    let doc = concat!("/**\n", " * @internal Not part of the public API.\n", " */",);
+    assert!(has_internal_tag(doc));
+}
+
+#[test]
+fn internal_tag_not_confused_with_psalm_internal_or_text() {
+    let doc = concat!(
+        "/**\n",
+        " * Used for internal bookkeeping.\n",
+        " * @psalm-internal App\\Billing\n",
+        " */",
+    );
+    assert!(!has_internal_tag(doc));
+}
+
+// ─── @global ────────────────────────────────────────────────────────────────
+
+#[test]
+fn global_tag_matches_variable_name() {
+    let doc = concat!(
+        "/**\n",
+        " * @global wpdb $wpdb WordPress database abstraction.\n",
+        " * @global array $wp_filter\n",
+        " */",
+    );
+    assert_eq!(
+        extract_global_type(doc, "$wpdb"),
+        Some(PhpType::Named("wpdb".to_string()))
+    );
+    assert_eq!(extract_global_type(doc, "$post"), None);
+}
+
+#[test]
+fn global_type_in_source_prefers_inline_docblock() {
+    let content = concat!(
+        "<?php\n",
+        "/** @global Cache $store */\n",
+        "function boot() {\n",
+        "    /** @global Database $store */\n",
+        "    global $store;\n",
+        "}\n",
+    );
+    let offset = content.find("global $store").unwrap();
+    assert_eq!(
+        find_global_type_in_source(content, offset, "$store"),
+        Some(PhpType::Named("Database".to_string()))
+    );
+}
+
+#[test]
+fn global_type_in_source_falls_back_to_function_docblock() {
+    let content = concat!(
+        "<?php\n",
+        "/**\n",
+        " * @global Database $db\n",
+        " */\n",
+        "function boot() {\n",
+        "    global $db;\n",
+        "}\n",
+    );
+    let offset = content.find("global $db").unwrap();
+    assert_eq!(
+        find_global_type_in_source(content, offset, "$db"),
+        Some(PhpType::Named("Database".to_string()))
+    );
+}
+
 // ─── find_enclosing_return_type ─────────────────────────────────────────────
 
 #[test]
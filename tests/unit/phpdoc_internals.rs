@@ -398,6 +398,48 @@ fn typing_pos_mixin_empty_type() {
     );
 }
 
+#[test]
+fn typing_pos_method_partial_return_type() {
+    let content = "<?php\n/**\n * @method Coll\n */\nclass Foo {}\n";
+    let pos = Position {
+        line: 2,
+        character: 15,
+    };
+    assert_eq!(
+        detect_docblock_typing_position(content, pos),
+        Some(DocblockTypingContext::Type {
+            partial: "Coll".to_string(),
+            tag: "method".to_string(),
+        })
+    );
+}
+
+#[test]
+fn typing_pos_method_name_after_type() {
+    let content = "<?php\n/**\n * @method string get\n */\nclass Foo {}\n";
+    let pos = Position {
+        line: 2,
+        character: 21,
+    };
+    assert_eq!(detect_docblock_typing_position(content, pos), None);
+}
+
+#[test]
+fn typing_pos_phpstan_var_empty_type() {
+    let content = "<?php\n/**\n * @phpstan-var \n */\n";
+    let pos = Position {
+        line: 2,
+        character: 16,
+    };
+    assert_eq!(
+        detect_docblock_typing_position(content, pos),
+        Some(DocblockTypingContext::Type {
+            partial: String::new(),
+            tag: "phpstan-var".to_string(),
+        })
+    );
+}
+
 #[test]
 fn typing_pos_extends_partial_type() {
     let content = "<?php\n/**\n * @extends Base\n */\nclass Foo extends Bar {}\n";
@@ -0,0 +1,276 @@
+import { execFile } from "child_process";
+import * as fs from "fs";
+import type { IncomingMessage } from "http";
+import * as https from "https";
+import * as path from "path";
+import { promisify } from "util";
+import * as vscode from "vscode";
+import {
+  Executable,
+  LanguageClient,
+  LanguageClientOptions,
+} from "vscode-languageclient/node";
+
+const REPOSITORY = "AJenbo/phpantom_lsp";
+const BINARY_NAME =
+  process.platform === "win32" ? "phpantom_lsp.exe" : "phpantom_lsp";
+
+let client: LanguageClient | undefined;
+
+export async function activate(
+  context: vscode.ExtensionContext,
+): Promise<void> {
+  let command: string;
+  try {
+    command = await resolveServerPath(context);
+  } catch (e) {
+    vscode.window.showErrorMessage(
+      `PHPantom: could not find or download phpantom_lsp: ${errorMessage(e)}`,
+    );
+    return;
+  }
+
+  const serverOptions: Executable = { command };
+  const clientOptions: LanguageClientOptions = {
+    documentSelector: [{ scheme: "file", language: "php" }],
+    // Settings are pulled by the server through `workspace/configuration`;
+    // this only makes the client announce when they change.
+    synchronize: { configurationSection: "phpantom" },
+    outputChannelName: "PHPantom",
+  };
+
+  client = new LanguageClient(
+    "phpantom",
+    "PHPantom",
+    serverOptions,
+    clientOptions,
+  );
+  // Tell the server which client-side commands it may attach to code
+  // lenses.  Without this it sends title-only reference lenses.
+  client.registerFeature({
+    fillClientCapabilities(capabilities) {
+      capabilities.experimental = {
+        ...capabilities.experimental,
+        commands: { commands: ["phpantom.showReferences"] },
+      };
+    },
+    initialize() {},
+    getState() {
+      return { kind: "static" };
+    },
+    clear() {},
+  });
+  await client.start();
+
+  // Target of the server's "N references" code lenses.  The arguments
+  // arrive as plain LSP JSON and must be converted before VS Code's
+  // built-in command accepts them.
+  context.subscriptions.push(
+    vscode.commands.registerCommand(
+      "phpantom.showReferences",
+      (uri: string, position: { line: number; character: number }) => {
+        const converter = client?.protocol2CodeConverter;
+        if (!converter) {
+          return;
+        }
+        return vscode.commands.executeCommand(
+          "editor.action.findReferences",
+          converter.asUri(uri),
+          converter.asPosition(position),
+        );
+      },
+    ),
+  );
+}
+
+export async function deactivate(): Promise<void> {
+  await client?.stop();
+  client = undefined;
+}
+
+/**
+ * Find the server binary: the `phpantom.serverPath` setting first, then
+ * `phpantom_lsp` on PATH, and finally the latest GitHub release
+ * (downloaded into the extension's global storage).
+ */
+async function resolveServerPath(
+  context: vscode.ExtensionContext,
+): Promise<string> {
+  const configured = vscode.workspace
+    .getConfiguration("phpantom")
+    .get<string | null>("serverPath");
+  if (configured) {
+    return configured;
+  }
+
+  const onPath = findOnPath(BINARY_NAME);
+  if (onPath) {
+    return onPath;
+  }
+
+  try {
+    return await downloadLatestRelease(context);
+  } catch (e) {
+    // Offline or rate limited: fall back to an earlier download.
+    const cached = findDownloadedBinary(context);
+    if (cached) {
+      return cached;
+    }
+    throw e;
+  }
+}
+
+function findOnPath(name: string): string | undefined {
+  for (const dir of (process.env.PATH ?? "").split(path.delimiter)) {
+    if (!dir) {
+      continue;
+    }
+    const candidate = path.join(dir, name);
+    if (fs.existsSync(candidate) && fs.statSync(candidate).isFile()) {
+      return candidate;
+    }
+  }
+  return undefined;
+}
+
+/** Rust target triple of the release asset for this machine. */
+function releaseTarget(): string {
+  const arch =
+    process.arch === "x64"
+      ? "x86_64"
+      : process.arch === "arm64"
+        ? "aarch64"
+        : undefined;
+  const os =
+    process.platform === "linux"
+      ? "unknown-linux-gnu"
+      : process.platform === "darwin"
+        ? "apple-darwin"
+        : process.platform === "win32"
+          ? "pc-windows-msvc"
+          : undefined;
+  if (!arch || !os) {
+    throw new Error(
+      `no pre-built binary for ${process.platform}-${process.arch}; build phpantom_lsp from source and set phpantom.serverPath`,
+    );
+  }
+  return `${arch}-${os}`;
+}
+
+interface Release {
+  tag_name: string;
+  assets: { name: string; browser_download_url: string }[];
+}
+
+async function downloadLatestRelease(
+  context: vscode.ExtensionContext,
+): Promise<string> {
+  const release: Release = JSON.parse(
+    await readBody(
+      await get(`https://api.github.com/repos/${REPOSITORY}/releases/latest`),
+    ),
+  );
+
+  const extension = process.platform === "win32" ? "zip" : "tar.gz";
+  const assetName = `phpantom_lsp-${releaseTarget()}.${extension}`;
+  const asset = release.assets.find((a) => a.name === assetName);
+  if (!asset) {
+    throw new Error(
+      `release ${release.tag_name} has no asset named ${assetName}`,
+    );
+  }
+
+  const storage = context.globalStorageUri.fsPath;
+  const versionDir = path.join(storage, release.tag_name);
+  const binary = path.join(versionDir, BINARY_NAME);
+  if (fs.existsSync(binary)) {
+    return binary;
+  }
+
+  await fs.promises.mkdir(versionDir, { recursive: true });
+  const archive = path.join(versionDir, assetName);
+  await vscode.window.withProgress(
+    {
+      location: vscode.ProgressLocation.Notification,
+      title: `PHPantom: downloading ${release.tag_name}`,
+    },
+    async () => {
+      const response = await get(asset.browser_download_url);
+      await new Promise<void>((resolve, reject) => {
+        const file = fs.createWriteStream(archive);
+        response.pipe(file);
+        file.on("finish", () => file.close(() => resolve()));
+        file.on("error", reject);
+        response.on("error", reject);
+      });
+    },
+  );
+
+  // bsdtar ships with macOS and Windows 10+ and handles zip as well.
+  await promisify(execFile)("tar", ["-xf", archive, "-C", versionDir]);
+  await fs.promises.rm(archive, { force: true });
+  if (process.platform !== "win32") {
+    await fs.promises.chmod(binary, 0o755);
+  }
+
+  // Clean up old versions.
+  for (const entry of await fs.promises.readdir(storage)) {
+    if (entry !== release.tag_name) {
+      await fs.promises.rm(path.join(storage, entry), {
+        recursive: true,
+        force: true,
+      });
+    }
+  }
+
+  return binary;
+}
+
+function findDownloadedBinary(
+  context: vscode.ExtensionContext,
+): string | undefined {
+  const storage = context.globalStorageUri.fsPath;
+  if (!fs.existsSync(storage)) {
+    return undefined;
+  }
+  return fs
+    .readdirSync(storage)
+    .map((entry) => path.join(storage, entry, BINARY_NAME))
+    .find((candidate) => fs.existsSync(candidate));
+}
+
+/** HTTPS GET that follows redirects (release assets live on a CDN). */
+function get(url: string): Promise<IncomingMessage> {
+  return new Promise((resolve, reject) => {
+    https
+      .get(url, { headers: { "User-Agent": "phpantom-vscode" } }, (res) => {
+        const status = res.statusCode ?? 0;
+        if (status >= 300 && status < 400 && res.headers.location) {
+          res.resume();
+          resolve(get(res.headers.location));
+          return;
+        }
+        if (status !== 200) {
+          res.resume();
+          reject(new Error(`GET ${url} failed with HTTP ${status}`));
+          return;
+        }
+        resolve(res);
+      })
+      .on("error", reject);
+  });
+}
+
+function readBody(response: IncomingMessage): Promise<string> {
+  return new Promise((resolve, reject) => {
+    let body = "";
+    response.setEncoding("utf8");
+    response.on("data", (chunk: string) => (body += chunk));
+    response.on("end", () => resolve(body));
+    response.on("error", reject);
+  });
+}
+
+function errorMessage(e: unknown): string {
+  return e instanceof Error ? e.message : String(e);
+}
@@ -1,8 +1,31 @@
 use std::fs;
 use zed_extension_api::{self as zed, LanguageServerId, Result};
 
+/// The last release whose binary does not accept `--workspace` and
+/// `--log-level`.
+const LAST_RELEASE_WITHOUT_CLI_FLAGS: (u32, u32, u32) = (0, 7, 0);
+
 struct PHPantomExtension {
     cached_binary_path: Option<String>,
+    /// Whether the cached binary is a downloaded release known to accept
+    /// `--workspace` and `--log-level`.
+    cached_binary_supports_flags: bool,
+}
+
+/// Whether a release tag such as `v0.8.0` is newer than the last release
+/// without the `--workspace` and `--log-level` flags.  Unparseable tags
+/// are treated as unsupported.
+fn release_supports_cli_flags(version: &str) -> bool {
+    let mut parts = version
+        .trim_start_matches('v')
+        .split(|c: char| c == '.' || c == '-')
+        .map(|part| part.parse::<u32>());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => {
+            (major, minor, patch) > LAST_RELEASE_WITHOUT_CLI_FLAGS
+        }
+        _ => false,
+    }
 }
 
 impl PHPantomExtension {
@@ -11,8 +34,10 @@ impl PHPantomExtension {
         language_server_id: &LanguageServerId,
         worktree: &zed::Worktree,
     ) -> Result<String> {
-        // First, check if the user has phpantom_lsp on their PATH
+        // First, check if the user has phpantom_lsp on their PATH.  Its
+        // version is unknown, so it is started without extra flags.
         if let Some(path) = worktree.which("phpantom_lsp") {
+            self.cached_binary_supports_flags = false;
             return Ok(path);
         }
 
@@ -98,6 +123,7 @@ impl PHPantomExtension {
         }
 
         self.cached_binary_path = Some(binary_path.clone());
+        self.cached_binary_supports_flags = release_supports_cli_flags(&release.version);
         Ok(binary_path)
     }
 }
@@ -106,6 +132,7 @@ impl zed::Extension for PHPantomExtension {
     fn new() -> Self {
         Self {
             cached_binary_path: None,
+            cached_binary_supports_flags: false,
         }
     }
 
@@ -116,9 +143,22 @@ impl zed::Extension for PHPantomExtension {
     ) -> Result<zed::Command> {
         let binary_path = self.language_server_binary_path(language_server_id, worktree)?;
 
+        // Older releases exit on unknown arguments, so the flags are only
+        // passed to a downloaded release that is known to accept them.
+        let args = if self.cached_binary_supports_flags {
+            vec![
+                "--workspace".to_string(),
+                worktree.root_path(),
+                "--log-level".to_string(),
+                "info".to_string(),
+            ]
+        } else {
+            Vec::new()
+        };
+
         Ok(zed::Command {
             command: binary_path,
-            args: vec![],
+            args,
             env: Default::default(),
         })
     }